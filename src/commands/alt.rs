@@ -0,0 +1,155 @@
+//! Alt command - Scaffold an alternate implementation of a problem
+//!
+//! Copies the current solution (signature, implementation, and tests) into
+//! a sibling module such as `p0001_two_sum_alt_dp`, so a brute-force and an
+//! optimized version can live side by side with both test suites running
+//! under `cargo test`.
+
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+use colored::Colorize;
+
+use crate::{commands::pick::add_module_declaration, meta::ProblemMeta};
+
+/// Scaffold an alternate implementation module for a downloaded problem
+pub async fn execute(id: u32, name: String) -> Result<()> {
+    if name.is_empty()
+        || name.chars().next().is_some_and(|c| c.is_ascii_digit())
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        anyhow::bail!(
+            "invalid alternate name '{name}': expected a snake_case identifier \
+             (e.g. dp, brute_force)"
+        );
+    }
+
+    let meta = ProblemMeta::load(id)?.ok_or_else(|| {
+        anyhow!("problem {id} is not downloaded; run 'leetcode-cli pick --id {id}' first")
+    })?;
+    if meta.language != "rust" {
+        anyhow::bail!(
+            "alternate scaffolding is only supported for Rust solutions \
+             (problem {id} uses {})",
+            meta.language
+        );
+    }
+
+    let module = meta.module_name();
+    let source = meta.solution_path();
+    if !source.exists() {
+        anyhow::bail!("solution file not found: {}", source.display());
+    }
+
+    let alt_module = format!("{module}_alt_{name}");
+    let alt_path = PathBuf::from("src/solutions").join(format!("{alt_module}.rs"));
+    if alt_path.exists() {
+        anyhow::bail!(
+            "alternate '{name}' already exists: {}",
+            alt_path.display()
+        );
+    }
+
+    let code = std::fs::read_to_string(&source)?;
+    let content = format!(
+        "//! Alternate implementation ({name}) of {}, scaffolded from {}\n\n{code}",
+        meta.title,
+        source.display()
+    );
+    std::fs::write(&alt_path, content)?;
+    add_module_declaration(&alt_module)?;
+
+    println!(
+        "{}",
+        format!("✓ Alternate created: {}", alt_path.display()).green()
+    );
+    println!("  'cargo test {module}' runs the tests of both implementations");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use crate::commands::TestDirGuard;
+
+    fn scaffold_workspace() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("src/solutions")).unwrap();
+        fs::write(
+            temp_dir.path().join("src/solutions/p0001_two_sum.rs"),
+            "impl Solution {}\n\n#[cfg(test)]\nmod tests {}\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("src/solutions/mod.rs"),
+            "pub mod p0001_two_sum;\n",
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_alt_creates_module() {
+        let _guard = TestDirGuard::new(scaffold_workspace());
+        crate::commands::tests::write_test_meta(1, "two-sum");
+
+        super::execute(1, "dp".to_string()).await.unwrap();
+
+        let alt = fs::read_to_string("src/solutions/p0001_two_sum_alt_dp.rs").unwrap();
+        assert!(alt.starts_with("//! Alternate implementation (dp)"));
+        assert!(alt.contains("impl Solution {}"));
+        assert!(alt.contains("#[cfg(test)]"));
+
+        let mod_content = fs::read_to_string("src/solutions/mod.rs").unwrap();
+        assert!(mod_content.contains("pub mod p0001_two_sum_alt_dp;"));
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_alt_rejects_duplicate() {
+        let _guard = TestDirGuard::new(scaffold_workspace());
+        crate::commands::tests::write_test_meta(1, "two-sum");
+
+        super::execute(1, "dp".to_string()).await.unwrap();
+        let result = super::execute(1, "dp".to_string()).await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("already exists"));
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_alt_requires_downloaded_problem() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        let result = super::execute(999, "dp".to_string()).await;
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("not downloaded")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_alt_rejects_invalid_name() {
+        for name in ["", "2fast", "Dp", "my-name", "a b"] {
+            let result = super::execute(1, name.to_string()).await;
+            assert!(result.is_err(), "name '{name}' should be rejected");
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("invalid alternate name")
+            );
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Archive command - move finished problems out of the active workspace
+//!
+//! Moves solution files into `archive/` and drops their module
+//! declarations, so the active crate stays small while the code remains
+//! on disk (and searchable through `grep`). Problems can be archived one
+//! at a time or in bulk with `--solved --older-than 60d`.
+
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    commands::{clean::parse_age, list_local_solutions},
+    meta::ProblemMeta,
+    progress::Progress,
+};
+
+/// Move problems into the archive directory.
+pub async fn execute(id: Option<u32>, solved: bool, older_than: Option<String>) -> Result<()> {
+    if id.is_none() && !solved {
+        anyhow::bail!("specify a problem ID or --solved to select what to archive");
+    }
+    if older_than.is_some() && !solved {
+        anyhow::bail!("--older-than only applies with --solved");
+    }
+    let min_age = match older_than {
+        Some(ref spec) => Some(
+            parse_age(spec)
+                .ok_or_else(|| anyhow::anyhow!("invalid age '{spec}': expected e.g. 60d, 12h"))?,
+        ),
+        None => None,
+    };
+
+    let solutions = list_local_solutions()?;
+    let progress = Progress::load()?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let targets: Vec<_> = solutions
+        .iter()
+        .filter(|solution| match id {
+            Some(id) => solution.id == id,
+            None => {
+                progress.is_solved(solution.id)
+                    && min_age.is_none_or(|min| {
+                        finished_at(&progress, solution.id)
+                            .is_some_and(|at| now.saturating_sub(at) >= min.as_secs())
+                    })
+            }
+        })
+        .collect();
+    if targets.is_empty() {
+        println!("{}", "No solutions matched; nothing to archive.".yellow());
+        return Ok(());
+    }
+
+    let archive_dir = PathBuf::from("archive");
+    std::fs::create_dir_all(&archive_dir)?;
+
+    let mut archived = 0;
+    for solution in targets {
+        let Some(file_name) = solution.path.file_name() else {
+            continue;
+        };
+        let dest = archive_dir.join(file_name);
+        if dest.exists() {
+            println!(
+                "{}",
+                format!("! {} already exists; skipping", dest.display()).yellow()
+            );
+            continue;
+        }
+        std::fs::rename(&solution.path, &dest)?;
+
+        // Drop the module declaration so the active crate still compiles
+        if let Some(module) = solution.path.file_stem().and_then(|s| s.to_str()) {
+            remove_module_declaration(module)?;
+        }
+
+        println!(
+            "  {} p{:04} -> {}",
+            "✓ archived:".green(),
+            solution.id,
+            dest.display()
+        );
+        archived += 1;
+    }
+
+    if archived == 0 {
+        println!("{}", "Nothing archived.".yellow());
+    } else {
+        // Keep index.json in step for external tooling
+        crate::workspace_index::refresh()?;
+        println!(
+            "{}",
+            format!("✓ Archived {archived} problem(s) to archive/").green()
+        );
+        println!("  Run 'leetcode-cli index' to refresh SOLUTIONS.md.");
+    }
+
+    Ok(())
+}
+
+/// When a problem was finished: its recorded solve time, falling back to
+/// the download time for records predating the `solved_at` field.
+fn finished_at(progress: &Progress, id: u32) -> Option<u64> {
+    progress
+        .problems
+        .get(&id)
+        .and_then(|p| p.solved_at)
+        .or_else(|| ProblemMeta::load(id).ok().flatten().map(|m| m.downloaded_at))
+}
+
+/// Remove a problem's declaration from `src/solutions/mod.rs`.
+fn remove_module_declaration(module: &str) -> Result<()> {
+    let path = PathBuf::from("src/solutions/mod.rs");
+    if !path.exists() {
+        return Ok(());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    let updated = strip_module_declaration(&content, module);
+    if updated != content {
+        std::fs::write(&path, updated)?;
+    }
+    Ok(())
+}
+
+/// The mod.rs contents with the given module's declaration removed.
+fn strip_module_declaration(content: &str, module: &str) -> String {
+    let decl = format!("pub mod {module};");
+    let mut updated: String = content
+        .lines()
+        .filter(|line| line.trim() != decl)
+        .map(|line| format!("{line}\n"))
+        .collect();
+    if !content.ends_with('\n') {
+        updated.truncate(updated.trim_end_matches('\n').len());
+    }
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_module_declaration() {
+        let content = "//! Solutions\n\npub mod p0001_two_sum;\npub mod p0002_add_two_numbers;\n";
+        let updated = strip_module_declaration(content, "p0001_two_sum");
+        assert!(!updated.contains("p0001_two_sum"));
+        assert!(updated.contains("pub mod p0002_add_two_numbers;\n"));
+        assert!(updated.starts_with("//! Solutions\n"));
+    }
+
+    #[test]
+    fn test_strip_module_declaration_missing_module() {
+        let content = "pub mod p0001_two_sum;\n";
+        assert_eq!(strip_module_declaration(content, "p0042_other"), content);
+    }
+}
@@ -0,0 +1,212 @@
+//! Badge command - solved-count badge for a profile README
+//!
+//! Renders the local solve counts by difficulty either as a flat SVG badge
+//! or, when the output ends in `.json`, as a shields.io endpoint file.
+//! Accepted submissions regenerate an existing badge automatically, so the
+//! embedded image stays current without re-running the command.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{meta::ProblemMeta, progress::Progress};
+
+/// LeetCode's brand orange, used for the message half of the badge.
+const BADGE_COLOR: &str = "#ffa116";
+/// Default output path when `--output` is not given.
+const DEFAULT_OUTPUT: &str = "stats.svg";
+
+/// Render the solve counts as an SVG badge or shields.io endpoint file.
+pub async fn execute(output: Option<PathBuf>) -> Result<()> {
+    let output = output.unwrap_or_else(|| PathBuf::from(DEFAULT_OUTPUT));
+    write_badge(&output)?;
+    println!("{}", format!("✓ Badge written to {}", output.display()).green());
+    println!("  Embed it with: ![LeetCode](./{})", output.display());
+    Ok(())
+}
+
+/// Regenerate a previously generated badge after an accepted submission.
+/// Having run `badge` once is the opt-in; without a badge file this is a
+/// no-op.
+pub(crate) fn refresh() -> Result<()> {
+    for name in [DEFAULT_OUTPUT, "stats.json"] {
+        let path = Path::new(name);
+        if path.exists() {
+            write_badge(path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Render the badge for the current workspace into `output`, picking the
+/// format from the file extension.
+fn write_badge(output: &Path) -> Result<()> {
+    let counts = solved_counts(&ProblemMeta::load_all()?, &Progress::load()?);
+    let content = if output.extension().is_some_and(|e| e == "json") {
+        shields_json(&counts)
+    } else {
+        render_svg("leetcode", &counts.message())
+    };
+    std::fs::write(output, content)?;
+    Ok(())
+}
+
+/// Solved problems broken down by difficulty. Solved problems without a
+/// metadata file still count toward the total.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct SolvedCounts {
+    pub(crate) easy: u32,
+    pub(crate) medium: u32,
+    pub(crate) hard: u32,
+    pub(crate) total: u32,
+}
+
+impl SolvedCounts {
+    /// The badge message, e.g. "42 solved · 20E 18M 4H".
+    pub(crate) fn message(&self) -> String {
+        format!(
+            "{} solved · {}E {}M {}H",
+            self.total, self.easy, self.medium, self.hard
+        )
+    }
+}
+
+/// Count solved problems per difficulty from the progress DB and the
+/// workspace metadata.
+pub(crate) fn solved_counts(metas: &[ProblemMeta], progress: &Progress) -> SolvedCounts {
+    let mut counts = SolvedCounts::default();
+    for (id, entry) in &progress.problems {
+        if entry.status != crate::progress::SolveStatus::Solved {
+            continue;
+        }
+        counts.total += 1;
+        match metas
+            .iter()
+            .find(|m| m.frontend_id == *id)
+            .map(|m| m.difficulty.as_str())
+        {
+            Some("Easy") => counts.easy += 1,
+            Some("Medium") => counts.medium += 1,
+            Some("Hard") => counts.hard += 1,
+            _ => {}
+        }
+    }
+    counts
+}
+
+/// A shields.io endpoint file, for embedding via their `endpoint` badge.
+pub(crate) fn shields_json(counts: &SolvedCounts) -> String {
+    serde_json::json!({
+        "schemaVersion": 1,
+        "label": "leetcode",
+        "message": counts.message(),
+        "color": BADGE_COLOR,
+    })
+    .to_string()
+        + "\n"
+}
+
+/// A flat shields-style badge: grey label on the left, orange message on
+/// the right. Text width is estimated at 6px per character plus padding,
+/// which is close enough for the DejaVu/Verdana stack badges use.
+pub(crate) fn render_svg(label: &str, message: &str) -> String {
+    let label_width = text_width(label);
+    let message_width = text_width(message);
+    let width = label_width + message_width;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">
+  <rect width="{label_width}" height="20" fill="#555"/>
+  <rect x="{label_width}" width="{message_width}" height="20" fill="{BADGE_COLOR}"/>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_mid}" y="14">{label}</text>
+    <text x="{message_mid}" y="14">{message}</text>
+  </g>
+</svg>
+"##,
+        label_mid = label_width / 2,
+        message_mid = label_width + message_width / 2,
+    )
+}
+
+/// Estimated pixel width of badge text: 6px per character plus padding.
+fn text_width(text: &str) -> u32 {
+    6 * text.chars().count() as u32 + 10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::SolveStatus;
+
+    fn make_meta(id: u32, difficulty: &str) -> ProblemMeta {
+        ProblemMeta {
+            id,
+            frontend_id: id,
+            slug: format!("problem-{id}"),
+            title: format!("Problem {id}"),
+            difficulty: difficulty.to_string(),
+            tags: vec!["Array".to_string()],
+            downloaded_at: 1_700_000_000,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_solved_counts_by_difficulty() {
+        let metas = vec![
+            make_meta(1, "Easy"),
+            make_meta(2, "Medium"),
+            make_meta(3, "Hard"),
+        ];
+        let mut progress = Progress::default();
+        for id in 1..=3 {
+            progress.record(id, "slug", SolveStatus::Solved, "submit");
+        }
+        // Solved without metadata: counted in the total only
+        progress.record(4, "slug", SolveStatus::Solved, "submit");
+        // Attempting doesn't count at all
+        progress.record(5, "slug", SolveStatus::Attempting, "pick");
+
+        let counts = solved_counts(&metas, &progress);
+        assert_eq!(
+            counts,
+            SolvedCounts {
+                easy: 1,
+                medium: 1,
+                hard: 1,
+                total: 4,
+            }
+        );
+        assert_eq!(counts.message(), "4 solved · 1E 1M 1H");
+    }
+
+    #[test]
+    fn test_shields_json_schema() {
+        let counts = SolvedCounts {
+            easy: 2,
+            medium: 1,
+            hard: 0,
+            total: 3,
+        };
+        let parsed: serde_json::Value = serde_json::from_str(&shields_json(&counts)).unwrap();
+        assert_eq!(parsed["schemaVersion"], 1);
+        assert_eq!(parsed["label"], "leetcode");
+        assert_eq!(parsed["message"], "3 solved · 2E 1M 0H");
+        assert_eq!(parsed["color"], BADGE_COLOR);
+    }
+
+    #[test]
+    fn test_render_svg_lays_out_both_halves() {
+        let svg = render_svg("leetcode", "3 solved · 2E 1M 0H");
+        assert!(svg.starts_with("<svg xmlns="));
+        assert!(svg.contains("aria-label=\"leetcode: 3 solved · 2E 1M 0H\""));
+        // Label box plus message box spans the full width
+        let label_width = 6 * 8 + 10;
+        assert!(svg.contains(&format!("<rect width=\"{label_width}\" height=\"20\"")));
+        assert!(svg.contains(&format!("<rect x=\"{label_width}\"")));
+    }
+}
@@ -0,0 +1,394 @@
+//! Bench command - Benchmark a solution and its alternate implementations
+//!
+//! Generates a Criterion harness under `benches/` that runs every
+//! implementation of a problem on the same inputs, wires criterion into the
+//! workspace `Cargo.toml` if needed, runs `cargo bench`, and summarizes the
+//! medians in a comparison table.
+
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+use colored::Colorize;
+
+use crate::meta::ProblemMeta;
+
+/// Benchmark a solution, optionally comparing all alternate implementations
+pub async fn execute(id: u32, compare: bool) -> Result<()> {
+    let meta = ProblemMeta::load(id)?.ok_or_else(|| {
+        anyhow!("problem {id} is not downloaded; run 'leetcode-cli pick --id {id}' first")
+    })?;
+    if meta.language != "rust" {
+        anyhow::bail!(
+            "benchmarks are only supported for Rust solutions (problem {id} uses {})",
+            meta.language
+        );
+    }
+
+    let module = meta.module_name();
+    let mut modules = vec![module.clone()];
+    if compare {
+        let alts = list_alt_modules(&module)?;
+        if alts.is_empty() {
+            anyhow::bail!(
+                "no alternate implementations of {module} found; \
+                 create one with 'leetcode-cli alt {id} --name <name>'"
+            );
+        }
+        modules.extend(alts);
+    }
+
+    let bench_name = format!("{module}_compare");
+    let bench_path = PathBuf::from("benches").join(format!("{bench_name}.rs"));
+    if !bench_path.exists() {
+        let crate_name = workspace_crate_name()?;
+        let method = std::fs::read_to_string(meta.solution_path())
+            .ok()
+            .and_then(|code| solution_method(&code));
+        std::fs::create_dir_all("benches")?;
+        std::fs::write(
+            &bench_path,
+            generate_bench_file(&crate_name, &modules, method.as_ref()),
+        )?;
+        println!(
+            "{}",
+            format!("✓ Benchmark harness created: {}", bench_path.display()).green()
+        );
+        println!("  Edit the inputs to something representative, then rerun to compare");
+    }
+    ensure_bench_setup(&bench_name)?;
+
+    println!("{}", format!("Running cargo bench {bench_name}...").cyan());
+    let output = std::process::Command::new("cargo")
+        .args(["bench", "--bench", &bench_name])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{stdout}");
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        anyhow::bail!("cargo bench failed");
+    }
+
+    let medians = parse_criterion_medians(&stdout);
+    if medians.is_empty() {
+        println!(
+            "{}",
+            "! no benchmark results found in the output".yellow()
+        );
+        return Ok(());
+    }
+    println!();
+    println!("{}", "Comparison:".bold());
+    for line in render_comparison(&medians) {
+        println!("  {line}");
+    }
+
+    Ok(())
+}
+
+/// Alternate implementation modules of `module`, e.g. `p0001_two_sum_alt_dp`.
+fn list_alt_modules(module: &str) -> Result<Vec<String>> {
+    let prefix = format!("{module}_alt_");
+    let mut alts = Vec::new();
+    for entry in std::fs::read_dir("src/solutions")? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "rs") {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            && stem.starts_with(&prefix)
+        {
+            alts.push(stem.to_string());
+        }
+    }
+    alts.sort();
+    Ok(alts)
+}
+
+/// The workspace's crate name as a Rust identifier, from `Cargo.toml`.
+fn workspace_crate_name() -> Result<String> {
+    let content = std::fs::read_to_string("Cargo.toml")
+        .map_err(|_| anyhow!("no Cargo.toml found; run from the workspace root"))?;
+    let manifest: toml::Value = toml::from_str(&content)?;
+    manifest
+        .get("package")
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str())
+        .map(|n| n.replace('-', "_"))
+        .ok_or_else(|| anyhow!("Cargo.toml has no package name"))
+}
+
+/// The name and arity of the first method in the `impl Solution` block.
+fn solution_method(code: &str) -> Option<(String, usize)> {
+    let file = syn::parse_file(code).ok()?;
+    file.items.iter().find_map(|item| {
+        let syn::Item::Impl(imp) = item else {
+            return None;
+        };
+        let syn::Type::Path(path) = imp.self_ty.as_ref() else {
+            return None;
+        };
+        if !path.path.is_ident("Solution") {
+            return None;
+        }
+        imp.items.iter().find_map(|ii| match ii {
+            syn::ImplItem::Fn(f) => {
+                let arity = f
+                    .sig
+                    .inputs
+                    .iter()
+                    .filter(|arg| matches!(arg, syn::FnArg::Typed(_)))
+                    .count();
+                Some((f.sig.ident.to_string(), arity))
+            }
+            _ => None,
+        })
+    })
+}
+
+/// Generate the Criterion harness benchmarking every implementation on the
+/// same inputs. With a known target method, the calls compile out of the
+/// box on `Default::default()` inputs; otherwise the bodies are TODOs.
+fn generate_bench_file(
+    crate_name: &str,
+    modules: &[String],
+    method: Option<&(String, usize)>,
+) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "//! Criterion comparison of every implementation of {}.\n",
+        modules[0]
+    ));
+    out.push_str("//! Generated by 'leetcode-cli bench'; replace the inputs with\n");
+    out.push_str("//! representative ones before trusting the numbers.\n\n");
+    out.push_str("use criterion::{Criterion, black_box, criterion_group, criterion_main};\n");
+    out.push_str(&format!("use {crate_name}::solutions;\n\n"));
+
+    out.push_str("fn bench_compare(c: &mut Criterion) {\n");
+    out.push_str(&format!(
+        "    let mut group = c.benchmark_group(\"{}\");\n",
+        modules[0]
+    ));
+    for module in modules {
+        out.push_str(&format!("    group.bench_function(\"{module}\", |b| {{\n"));
+        match method {
+            Some((name, arity)) => {
+                let args = (0..*arity)
+                    .map(|_| "black_box(Default::default())")
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                out.push_str(&format!(
+                    "        b.iter(|| solutions::{module}::Solution::{name}({args}))\n"
+                ));
+            }
+            None => {
+                out.push_str(&format!(
+                    "        // TODO: call solutions::{module}::Solution with shared inputs\n"
+                ));
+                out.push_str("        b.iter(|| black_box(()))\n");
+            }
+        }
+        out.push_str("    });\n");
+    }
+    out.push_str("    group.finish();\n");
+    out.push_str("}\n\n");
+    out.push_str("criterion_group!(benches, bench_compare);\n");
+    out.push_str("criterion_main!(benches);\n");
+    out
+}
+
+/// Make sure the workspace `Cargo.toml` has criterion as a dev-dependency
+/// and a `[[bench]]` target for the generated harness. Existing sections
+/// are left alone.
+fn ensure_bench_setup(bench_name: &str) -> Result<()> {
+    let path = PathBuf::from("Cargo.toml");
+    let mut content = std::fs::read_to_string(&path)
+        .map_err(|_| anyhow!("no Cargo.toml found; run from the workspace root"))?;
+    let mut changed = false;
+
+    if !content.contains("criterion") {
+        content.push_str("\n[dev-dependencies.criterion]\nversion = \"0.7\"\n");
+        changed = true;
+    }
+    if !content.contains(&format!("name = \"{bench_name}\"")) {
+        content.push_str(&format!(
+            "\n[[bench]]\nname = \"{bench_name}\"\nharness = false\n"
+        ));
+        changed = true;
+    }
+    if changed {
+        std::fs::write(&path, content)?;
+        println!("  Wired criterion and the bench target into Cargo.toml");
+    }
+    Ok(())
+}
+
+/// Extract `(benchmark id, median in nanoseconds)` pairs from Criterion's
+/// `id    time:   [lo mid hi]` result lines.
+fn parse_criterion_medians(output: &str) -> Vec<(String, f64)> {
+    let mut results = Vec::new();
+    // Long benchmark ids are wrapped onto their own line, with the time on
+    // the next one; remember the previous line as a fallback label
+    let mut previous = "";
+    for line in output.lines() {
+        let Some(idx) = line.find("time:") else {
+            if !line.trim().is_empty() {
+                previous = line.trim();
+            }
+            continue;
+        };
+        let label = match line[..idx].trim() {
+            "" if !previous.starts_with("Benchmarking") => previous,
+            label => label,
+        };
+        if label.is_empty() {
+            continue;
+        }
+        let Some(times) = line[idx..]
+            .split_once('[')
+            .and_then(|(_, rest)| rest.split_once(']'))
+            .map(|(inner, _)| inner)
+        else {
+            continue;
+        };
+        let tokens: Vec<&str> = times.split_whitespace().collect();
+        // lo unit mid unit hi unit
+        if tokens.len() != 6 {
+            continue;
+        }
+        let (Ok(median), Some(factor)) = (tokens[2].parse::<f64>(), unit_to_ns(tokens[3])) else {
+            continue;
+        };
+        results.push((label.to_string(), median * factor));
+    }
+    results
+}
+
+/// Nanoseconds per one of Criterion's time units.
+fn unit_to_ns(unit: &str) -> Option<f64> {
+    match unit {
+        "ps" => Some(1e-3),
+        "ns" => Some(1.0),
+        "µs" | "us" => Some(1e3),
+        "ms" => Some(1e6),
+        "s" => Some(1e9),
+        _ => None,
+    }
+}
+
+/// Render the comparison table: one row per implementation with its median
+/// and the ratio to the fastest one.
+fn render_comparison(medians: &[(String, f64)]) -> Vec<String> {
+    let fastest = medians
+        .iter()
+        .map(|(_, m)| *m)
+        .fold(f64::INFINITY, f64::min);
+    let width = medians.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
+    medians
+        .iter()
+        .map(|(name, median)| {
+            format!(
+                "{name:width$}  {:>10}  {:.2}x",
+                format_ns(*median),
+                median / fastest
+            )
+        })
+        .collect()
+}
+
+/// Format a duration in nanoseconds with a readable unit.
+fn format_ns(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2} s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2} ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2} µs", ns / 1e3)
+    } else {
+        format!("{ns:.2} ns")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solution_method() {
+        let code = "impl Solution {\n\
+                    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> { vec![] }\n}\n";
+        assert_eq!(solution_method(code), Some(("two_sum".to_string(), 2)));
+        assert_eq!(solution_method("fn main() {}"), None);
+    }
+
+    #[test]
+    fn test_generate_bench_file_with_method() {
+        let modules = vec![
+            "p0001_two_sum".to_string(),
+            "p0001_two_sum_alt_dp".to_string(),
+        ];
+        let content = generate_bench_file(
+            "my_workspace",
+            &modules,
+            Some(&("two_sum".to_string(), 2)),
+        );
+        assert!(content.contains("use my_workspace::solutions;"));
+        assert!(content.contains(
+            "solutions::p0001_two_sum::Solution::two_sum(black_box(Default::default()), \
+             black_box(Default::default()))"
+        ));
+        assert!(content.contains("group.bench_function(\"p0001_two_sum_alt_dp\""));
+        assert!(content.contains("criterion_main!(benches);"));
+    }
+
+    #[test]
+    fn test_generate_bench_file_without_method() {
+        let modules = vec!["p0001_two_sum".to_string()];
+        let content = generate_bench_file("ws", &modules, None);
+        assert!(content.contains("// TODO: call solutions::p0001_two_sum::Solution"));
+    }
+
+    #[test]
+    fn test_parse_criterion_medians() {
+        let output = "Benchmarking p0001/p0001_two_sum\n\
+                      p0001/p0001_two_sum     time:   [1.2000 µs 1.2500 µs 1.3000 µs]\n\
+                      p0001/p0001_two_sum_alt_dp\n\
+                      \u{20}                       time:   [2.4000 µs 2.5000 µs 2.6000 µs]\n";
+        let medians = parse_criterion_medians(output);
+        assert_eq!(medians.len(), 2);
+        assert_eq!(medians[0].0, "p0001/p0001_two_sum");
+        assert!((medians[0].1 - 1250.0).abs() < 1e-6);
+        // The wrapped second id is picked up from the preceding line
+        assert_eq!(medians[1].0, "p0001/p0001_two_sum_alt_dp");
+        assert!((medians[1].1 - 2500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_unit_to_ns() {
+        assert_eq!(unit_to_ns("ns"), Some(1.0));
+        assert_eq!(unit_to_ns("µs"), Some(1e3));
+        assert_eq!(unit_to_ns("ms"), Some(1e6));
+        assert_eq!(unit_to_ns("lightyears"), None);
+    }
+
+    #[test]
+    fn test_render_comparison() {
+        let medians = vec![
+            ("p0001_two_sum".to_string(), 1250.0),
+            ("p0001_two_sum_alt_dp".to_string(), 2500.0),
+        ];
+        let rows = render_comparison(&medians);
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].contains("1.25 µs"));
+        assert!(rows[0].contains("1.00x"));
+        assert!(rows[1].contains("2.50 µs"));
+        assert!(rows[1].contains("2.00x"));
+    }
+
+    #[test]
+    fn test_format_ns() {
+        assert_eq!(format_ns(512.0), "512.00 ns");
+        assert_eq!(format_ns(1500.0), "1.50 µs");
+        assert_eq!(format_ns(2_500_000.0), "2.50 ms");
+        assert_eq!(format_ns(3e9), "3.00 s");
+    }
+}
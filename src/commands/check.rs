@@ -0,0 +1,34 @@
+//! Check command - Poll the result of an earlier submission
+//!
+//! Pairs with `submit --no-wait`: the upload prints a submission ID, and
+//! this command resumes polling the judge for it later.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{api::LeetCodeClient, commands::print_submission_result};
+
+/// Poll the judge for the result of a submission by ID
+pub async fn execute(client: &LeetCodeClient, submission_id: i64) -> Result<()> {
+    println!(
+        "{}",
+        format!("Checking submission {submission_id}...").cyan()
+    );
+
+    let result = client.check_submission(submission_id).await?;
+    print_submission_result(&result);
+
+    // Accepted: show where the submission landed in the distributions.
+    // Advisory — a failed fetch doesn't taint the verdict
+    if result.status_code == 10
+        && let Err(e) =
+            crate::commands::submit::show_distributions(client, submission_id, &result).await
+    {
+        println!(
+            "{}",
+            format!("! couldn't fetch the runtime distribution: {e}").yellow()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+//! Clean command - Remove build artifacts and report space reclaimed
+//!
+//! Removes the workspace `target/` directory, the configured shared target
+//! directory, and stray `leetcode-cli-*` temp directories, optionally
+//! restricted to artifacts older than a given age.
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+
+/// Clean build artifacts from the workspace
+pub async fn execute(all: bool, older_than: Option<String>) -> Result<()> {
+    let min_age = match older_than {
+        Some(ref spec) => Some(
+            parse_age(spec)
+                .ok_or_else(|| anyhow::anyhow!("invalid age '{spec}': expected e.g. 90d, 12h"))?,
+        ),
+        None => None,
+    };
+
+    let mut candidates: Vec<PathBuf> = vec![PathBuf::from("target")];
+    if let Some(dir) = Config::load()?.target_dir {
+        candidates.push(dir);
+    }
+
+    // Stray temp dirs left behind by interrupted test runs
+    if let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with("leetcode-cli-") {
+                candidates.push(entry.path());
+            }
+        }
+    }
+
+    // Generated artifacts are only removed with --all
+    if all {
+        for generated in ["SOLUTIONS.md", "anki_deck.csv"] {
+            candidates.push(PathBuf::from(generated));
+        }
+    }
+
+    let mut reclaimed: u64 = 0;
+    let mut removed = 0;
+    for path in &candidates {
+        if !path.exists() {
+            continue;
+        }
+        if let Some(min_age) = min_age
+            && !is_older_than(path, min_age)
+        {
+            continue;
+        }
+
+        let size = path_size(path);
+        let result = if path.is_dir() {
+            std::fs::remove_dir_all(path)
+        } else {
+            std::fs::remove_file(path)
+        };
+        match result {
+            Ok(()) => {
+                println!(
+                    "  {} {} ({})",
+                    "✓ removed:".green(),
+                    path.display(),
+                    format_bytes(size)
+                );
+                reclaimed += size;
+                removed += 1;
+            }
+            Err(e) => println!("  {} {}: {e}", "✗ failed:".red(), path.display()),
+        }
+    }
+
+    if removed == 0 {
+        println!("{}", "Nothing to clean.".yellow());
+    } else {
+        println!(
+            "{}",
+            format!("✓ Reclaimed {} from {removed} path(s)", format_bytes(reclaimed)).green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Parse an age specification like "90d", "12h", or "30m" into a duration.
+pub(crate) fn parse_age(spec: &str) -> Option<Duration> {
+    let spec = spec.trim();
+    let (value, unit) = spec.split_at(spec.len().checked_sub(1)?);
+    let value: u64 = value.parse().ok()?;
+    let seconds = match unit {
+        "d" => value * 86400,
+        "h" => value * 3600,
+        "m" => value * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(seconds))
+}
+
+/// Whether a path was last modified longer ago than `min_age`.
+fn is_older_than(path: &Path, min_age: Duration) -> bool {
+    path.metadata()
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|mtime| SystemTime::now().duration_since(mtime).ok())
+        .is_some_and(|age| age >= min_age)
+}
+
+/// Total size of a file or directory tree, in bytes.
+pub(crate) fn path_size(path: &Path) -> u64 {
+    if path.is_file() {
+        return path.metadata().map(|m| m.len()).unwrap_or(0);
+    }
+    let mut total = 0;
+    if let Ok(entries) = std::fs::read_dir(path) {
+        for entry in entries.flatten() {
+            total += path_size(&entry.path());
+        }
+    }
+    total
+}
+
+/// Human-readable byte count, e.g. "1.5 MB".
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("90d"), Some(Duration::from_secs(90 * 86400)));
+        assert_eq!(parse_age("12h"), Some(Duration::from_secs(12 * 3600)));
+        assert_eq!(parse_age("30m"), Some(Duration::from_secs(1800)));
+    }
+
+    #[test]
+    fn test_parse_age_invalid() {
+        assert_eq!(parse_age(""), None);
+        assert_eq!(parse_age("90"), None);
+        assert_eq!(parse_age("d90"), None);
+        assert_eq!(parse_age("90x"), None);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KB");
+        assert_eq!(format_bytes(1536 * 1024), "1.5 MB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn test_path_size_counts_tree() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("a.txt"), vec![0u8; 100]).unwrap();
+        let sub = temp_dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(sub.join("b.txt"), vec![0u8; 50]).unwrap();
+
+        assert_eq!(path_size(temp_dir.path()), 150);
+    }
+
+    #[test]
+    fn test_path_size_missing_path() {
+        assert_eq!(path_size(Path::new("/nonexistent/leetcode-cli-test")), 0);
+    }
+}
@@ -0,0 +1,327 @@
+//! Complexity command - estimate a solution's runtime complexity
+//!
+//! Runs the solution (through the local judge's runner binary) on
+//! generated inputs of increasing size, fits the measured runtimes
+//! against common growth curves (n, n log n, n², ...) by least squares,
+//! and reports the best fit with a confidence note. A handy sanity check
+//! before submitting a suspiciously simple nested loop.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow, bail};
+use colored::Colorize;
+use rand::RngExt;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::find_solution_file,
+    judge::{CaseRun, Limits, Runner},
+    problem::TestConfig,
+};
+
+/// Input sizes to time, largest first dropped once a run hits the budget.
+const SIZES: [usize; 6] = [1024, 4096, 16_384, 65_536, 262_144, 1_048_576];
+/// Timed runs per size; the minimum is kept to shed scheduler noise.
+const TRIALS: usize = 3;
+/// Per-run wall-clock budget; hitting it ends the sweep early.
+const BUDGET: Duration = Duration::from_secs(5);
+
+/// Estimate the complexity of the solution for a problem.
+pub async fn execute(client: &LeetCodeClient, id: u32) -> Result<()> {
+    let solution_file = find_solution_file(id, None)?;
+    if solution_file.extension().is_none_or(|e| e != "rs") {
+        bail!("complexity estimation only supports Rust solutions");
+    }
+
+    let problem = client
+        .get_problem_by_id(id)
+        .await?
+        .ok_or_else(|| anyhow!("problem {id} not found"))?;
+    let detail = client
+        .get_problem_detail(&problem.stat.question_title_slug())
+        .await?;
+    let cfg = detail
+        .parse_metadata()
+        .and_then(|m| m.test_config)
+        .ok_or_else(|| anyhow!("problem metadata has no test config"))?;
+
+    println!(
+        "{}",
+        format!("Estimating complexity for problem {id}...").cyan()
+    );
+    let runner = match Runner::compile(&solution_file, &cfg)? {
+        Ok(runner) => runner,
+        Err(stderr) => bail!("solution does not compile:\n{}", stderr.trim()),
+    };
+
+    let limits = Limits {
+        time: BUDGET,
+        memory_kb: 512 * 1024,
+    };
+    // Spawn and I/O overhead measured at a trivial size, subtracted from
+    // every sample so the fit sees the solution, not the process startup
+    let baseline = time_size(&runner, &cfg, 1, &limits)?
+        .ok_or_else(|| anyhow!("solution failed on a generated input"))?;
+
+    let mut samples = Vec::new();
+    for size in SIZES {
+        match time_size(&runner, &cfg, size, &limits)? {
+            Some(elapsed) => {
+                println!(
+                    "  n={size}: {:.2} ms",
+                    elapsed.as_secs_f64() * 1000.0
+                );
+                let net = elapsed.saturating_sub(baseline);
+                samples.push((size as f64, net.as_secs_f64().max(1e-9)));
+            }
+            None => {
+                println!(
+                    "{}",
+                    format!("  n={size}: over budget or failed, stopping the sweep").yellow()
+                );
+                break;
+            }
+        }
+    }
+    if samples.len() < 3 {
+        bail!("not enough measurable sizes to fit a curve");
+    }
+
+    let scored = fit(&samples);
+    let (best, best_score) = scored[0];
+    let (second, second_score) = scored[1];
+    println!(
+        "{}",
+        format!("✓ Best fit: {best} (residual {:.1}%)", best_score * 100.0).green()
+    );
+    println!(
+        "  next best: {second} (residual {:.1}%)",
+        second_score * 100.0
+    );
+    println!("  {}", confidence_note(best_score, second_score));
+    Ok(())
+}
+
+/// Time the runner on a generated input of the given size, taking the
+/// minimum over [`TRIALS`] runs. `None` means the run went over budget or
+/// the solution failed on the generated input.
+fn time_size(
+    runner: &Runner,
+    cfg: &TestConfig,
+    size: usize,
+    limits: &Limits,
+) -> Result<Option<Duration>> {
+    let input = generated_input(cfg, size)
+        .ok_or_else(|| anyhow!("cannot generate inputs for this signature"))?;
+    let mut best: Option<Duration> = None;
+    for _ in 0..TRIALS {
+        let started = Instant::now();
+        match runner.run_case(&input, limits)? {
+            CaseRun::Finished(output) if output.status.success() => {
+                let elapsed = started.elapsed();
+                best = Some(best.map_or(elapsed, |b| b.min(elapsed)));
+            }
+            _ => return Ok(None),
+        }
+    }
+    Ok(best)
+}
+
+/// One encoded stdin payload for the problem's signature at a given size.
+///
+/// Collection and string arguments grow with the size; scalar arguments
+/// stay small unless the signature has no collections at all, in which
+/// case the integers themselves scale.
+fn generated_input(cfg: &TestConfig, size: usize) -> Option<String> {
+    let has_collection = cfg
+        .args
+        .iter()
+        .any(|arg| is_scalable_type(&arg.arg_type));
+    let mut rng = rand::rng();
+    let mut lines = Vec::new();
+    for arg in &cfg.args {
+        let n = if is_scalable_type(&arg.arg_type) || !has_collection {
+            size
+        } else {
+            1
+        };
+        let value = generated_value(&arg.arg_type, n, &mut rng)?;
+        lines.push(crate::judge::encode_value(&value, &arg.arg_type).ok()?);
+    }
+    Some(lines.join("\n"))
+}
+
+/// Whether inputs of this type meaningfully grow with n.
+fn is_scalable_type(leetcode_type: &str) -> bool {
+    leetcode_type.contains("[]") || leetcode_type.contains("list<") || leetcode_type == "string"
+}
+
+/// A generated value of roughly n elements (or the value n, for scalars).
+fn generated_value(
+    leetcode_type: &str,
+    n: usize,
+    rng: &mut impl RngExt,
+) -> Option<serde_json::Value> {
+    let value = match leetcode_type {
+        "integer" | "long" => serde_json::json!(n as i64),
+        "double" => serde_json::json!(n as f64),
+        "boolean" => serde_json::json!(true),
+        "character" => serde_json::json!("a"),
+        "string" => serde_json::json!(random_string(n, rng)),
+        "integer[]" | "long[]" | "list<integer>" | "list<long>" => {
+            let items: Vec<i64> = (0..n).map(|_| rng.random_range(-1000..1000)).collect();
+            serde_json::json!(items)
+        }
+        "integer[][]" | "list<list<integer>>" => {
+            // An n-cell square matrix, so the total input still scales as n
+            let side = (n as f64).sqrt().max(1.0) as usize;
+            let rows: Vec<Vec<i64>> = (0..side)
+                .map(|_| (0..side).map(|_| rng.random_range(-1000..1000)).collect())
+                .collect();
+            serde_json::json!(rows)
+        }
+        "string[]" | "list<string>" => {
+            let items: Vec<String> = (0..n).map(|_| random_string(8, rng)).collect();
+            serde_json::json!(items)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// A random lowercase ASCII string of the given length.
+fn random_string(len: usize, rng: &mut impl RngExt) -> String {
+    (0..len)
+        .map(|_| rng.random_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+/// A candidate growth curve: its name and its value at n.
+type Model = (&'static str, fn(f64) -> f64);
+
+/// Candidate growth curves by name and value at n.
+fn models() -> Vec<Model> {
+    vec![
+        ("O(1)", |_| 1.0),
+        ("O(log n)", |n| n.ln()),
+        ("O(n)", |n| n),
+        ("O(n log n)", |n| n * n.ln()),
+        ("O(n^2)", |n| n * n),
+        ("O(n^3)", |n| n * n * n),
+    ]
+}
+
+/// Least-squares fit of `t = c·f(n)` through the origin for each model,
+/// returning `(name, residual)` sorted best first. The residual is the
+/// sum of squared errors normalized by the total signal, so 0 is a
+/// perfect fit and 1 is no better than predicting zero.
+fn fit(samples: &[(f64, f64)]) -> Vec<(&'static str, f64)> {
+    let total: f64 = samples.iter().map(|(_, t)| t * t).sum();
+    let mut scored: Vec<(&'static str, f64)> = models()
+        .into_iter()
+        .map(|(name, f)| {
+            let sft: f64 = samples.iter().map(|(n, t)| t * f(*n)).sum();
+            let sff: f64 = samples.iter().map(|(n, _)| f(*n) * f(*n)).sum();
+            let c = if sff > 0.0 { sft / sff } else { 0.0 };
+            let rss: f64 = samples.iter().map(|(n, t)| (t - c * f(*n)).powi(2)).sum();
+            (name, if total > 0.0 { rss / total } else { 1.0 })
+        })
+        .collect();
+    scored.sort_by(|a, b| a.1.total_cmp(&b.1));
+    scored
+}
+
+/// How much to trust the winner, from the best and runner-up residuals.
+fn confidence_note(best: f64, second: f64) -> String {
+    if best < 0.05 && second > 2.0 * best {
+        "Confidence: good fit".to_string()
+    } else if best < 0.05 {
+        "Confidence: weak — the top curves are hard to tell apart at these sizes".to_string()
+    } else {
+        "Confidence: inconclusive (noisy timings); rerun on a quiet machine".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::Argument;
+
+    #[test]
+    fn test_fit_linear() {
+        let samples: Vec<(f64, f64)> = [1024.0, 4096.0, 16384.0, 65536.0]
+            .iter()
+            .map(|&n| (n, 2e-6 * n))
+            .collect();
+        assert_eq!(fit(&samples)[0].0, "O(n)");
+    }
+
+    #[test]
+    fn test_fit_quadratic() {
+        let samples: Vec<(f64, f64)> = [1024.0, 4096.0, 16384.0, 65536.0]
+            .iter()
+            .map(|&n| (n, 3e-9 * n * n))
+            .collect();
+        assert_eq!(fit(&samples)[0].0, "O(n^2)");
+    }
+
+    #[test]
+    fn test_fit_n_log_n() {
+        let samples: Vec<(f64, f64)> = [1024.0, 4096.0, 16384.0, 65536.0, 262144.0]
+            .iter()
+            .map(|&n: &f64| (n, 5e-8 * n * n.ln()))
+            .collect();
+        assert_eq!(fit(&samples)[0].0, "O(n log n)");
+    }
+
+    #[test]
+    fn test_fit_constant() {
+        let samples = vec![(1024.0, 1e-4), (4096.0, 1e-4), (16384.0, 1e-4)];
+        assert_eq!(fit(&samples)[0].0, "O(1)");
+    }
+
+    #[test]
+    fn test_confidence_notes() {
+        assert!(confidence_note(0.001, 0.5).contains("good fit"));
+        assert!(confidence_note(0.01, 0.015).contains("weak"));
+        assert!(confidence_note(0.4, 0.5).contains("inconclusive"));
+    }
+
+    #[test]
+    fn test_generated_value_shapes() {
+        let mut rng = rand::rng();
+        let arr = generated_value("integer[]", 16, &mut rng).unwrap();
+        assert_eq!(arr.as_array().unwrap().len(), 16);
+        let s = generated_value("string", 10, &mut rng).unwrap();
+        assert_eq!(s.as_str().unwrap().len(), 10);
+        let matrix = generated_value("integer[][]", 16, &mut rng).unwrap();
+        assert_eq!(matrix.as_array().unwrap().len(), 4);
+        assert!(generated_value("TreeNode", 4, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_generated_input_scales_collections_only() {
+        let cfg = TestConfig {
+            namespace: "Solution".to_string(),
+            class_name: "Solution".to_string(),
+            method_name: "twoSum".to_string(),
+            return_type: "integer[]".to_string(),
+            args: vec![
+                Argument {
+                    arg_type: "integer[]".to_string(),
+                    name: "nums".to_string(),
+                },
+                Argument {
+                    arg_type: "integer".to_string(),
+                    name: "target".to_string(),
+                },
+            ],
+        };
+        let input = generated_input(&cfg, 32).unwrap();
+        let lines: Vec<&str> = input.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].split(',').count(), 32);
+        // The scalar stays small instead of scaling with n
+        assert_eq!(lines[1], "1");
+    }
+}
@@ -0,0 +1,80 @@
+//! Config command - Maintain the configuration file
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::{CONFIG_VERSION, Config, get_config_path, passphrase_key};
+
+/// Upgrade the config file to the current schema version in place
+pub async fn migrate() -> Result<()> {
+    let path = get_config_path()?;
+    let mut config = Config::load_file()?;
+    let from = config.version;
+
+    if from == CONFIG_VERSION {
+        println!(
+            "{}",
+            format!("✓ {} is already at schema version {CONFIG_VERSION}", path.display()).green()
+        );
+        return Ok(());
+    }
+    if from > CONFIG_VERSION {
+        anyhow::bail!(
+            "config file {} has schema version {from}, newer than this build's \
+             {CONFIG_VERSION}; upgrade leetcode-cli instead",
+            path.display()
+        );
+    }
+
+    config.migrate_schema();
+    config.save()?;
+    println!(
+        "{}",
+        format!(
+            "✓ Migrated {} from schema version {from} to {CONFIG_VERSION}",
+            path.display()
+        )
+        .green()
+        .bold()
+    );
+    Ok(())
+}
+
+/// Encrypt the stored credentials at rest, for users who can't rely on an
+/// OS keychain. The key comes from `LEETCODE_CONFIG_KEY` or a prompt.
+pub async fn encrypt() -> Result<()> {
+    let mut config = Config::load_file()?;
+    if config.session_cookie.is_none() && config.csrf_token.is_none() {
+        anyhow::bail!("no stored credentials to encrypt: run 'leetcode-cli login' first");
+    }
+    if config.has_encrypted_credentials() {
+        println!("{}", "✓ Stored credentials are already encrypted".green());
+        return Ok(());
+    }
+
+    let key = passphrase_key()?;
+    config.encrypt_credentials(&key)?;
+    config.save()?;
+    println!(
+        "{}",
+        "✓ Encrypted stored credentials (set LEETCODE_CONFIG_KEY to skip the prompt)"
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+/// Decrypt the stored credentials back to plaintext in the config file.
+pub async fn decrypt() -> Result<()> {
+    let mut config = Config::load_file()?;
+    if !config.has_encrypted_credentials() {
+        println!("{}", "✓ Stored credentials are not encrypted".green());
+        return Ok(());
+    }
+
+    let key = passphrase_key()?;
+    config.decrypt_credentials(&key)?;
+    config.save()?;
+    println!("{}", "✓ Decrypted stored credentials".green().bold());
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Dashboard command - Goals, streak, and recent progress
+//!
+//! Reads the local progress database and the goal settings in the config
+//! file (`daily_target`, `weekly_medium`) and prints how today and the
+//! current seven-day window are going, plus the solve streak.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{config::Config, meta::ProblemMeta, progress::Progress};
+
+const SECS_PER_WEEK: u64 = 7 * 86_400;
+
+/// Print goal progress and the current solve streak
+pub async fn execute() -> Result<()> {
+    // Goals only; no credentials needed, so skip the passphrase prompt
+    let config = Config::load_file()?;
+    let progress = Progress::load()?;
+    let now = crate::progress::now_ts();
+
+    let solved = progress
+        .problems
+        .values()
+        .filter(|p| p.status == crate::progress::SolveStatus::Solved)
+        .count();
+    println!("Solved: {solved} total");
+    if let Some(line) = mastery_line(&progress) {
+        println!("{line}");
+    }
+
+    let today = progress.solved_today(now);
+    match config.daily_target {
+        Some(target) => println!("{}", goal_line("Today", today, target as usize)),
+        None => println!("Today: {today} solved"),
+    }
+
+    let week_ids = progress.solved_since(now.saturating_sub(SECS_PER_WEEK));
+    let mediums = mediums_in(&week_ids)?;
+    match config.weekly_medium {
+        Some(target) => println!(
+            "{}",
+            goal_line("Mediums this week", mediums, target as usize)
+        ),
+        None => println!("Mediums this week: {mediums} solved"),
+    }
+
+    let streak = progress.streak_days(now);
+    println!("Streak: {streak} {}", if streak == 1 { "day" } else { "days" });
+    if let Some(warning) = streak_warning(&progress, now) {
+        println!("{}", warning.yellow());
+    }
+    Ok(())
+}
+
+/// The first-try-vs-hard-won split over solved problems with attempt
+/// counts, or `None` when no solve has them yet (records predating attempt
+/// tracking don't count). Solved/unsolved hides how hard a solve was; the
+/// attempt count is the better mastery signal.
+fn mastery_line(progress: &Progress) -> Option<String> {
+    let tracked: Vec<_> = progress
+        .problems
+        .iter()
+        .filter(|(_, p)| p.status == crate::progress::SolveStatus::Solved && p.submissions > 0)
+        .map(|(id, _)| *id)
+        .collect();
+    if tracked.is_empty() {
+        return None;
+    }
+    let first_try = tracked
+        .iter()
+        .filter(|id| progress.solved_first_try(**id))
+        .count();
+    let rest = tracked.len() - first_try;
+    let mut line = format!("First try: {first_try}/{}", tracked.len());
+    if rest > 0 {
+        let rest_attempts: u32 = tracked
+            .iter()
+            .filter(|id| !progress.solved_first_try(**id))
+            .map(|id| progress.attempts(*id))
+            .sum();
+        line.push_str(&format!(
+            " (the rest averaged {:.1} attempts)",
+            f64::from(rest_attempts) / rest as f64
+        ));
+    }
+    Some(line)
+}
+
+/// One goal progress line: green once the target is met, yellow until then.
+fn goal_line(label: &str, done: usize, target: usize) -> String {
+    let line = format!("{label}: {done}/{target}");
+    if done >= target {
+        format!("{} ✓", line.green())
+    } else {
+        line.yellow().to_string()
+    }
+}
+
+/// How many of the given problems are medium difficulty, per the workspace
+/// metadata. Problems without a metadata file don't count.
+fn mediums_in(ids: &[u32]) -> Result<usize> {
+    let mut count = 0;
+    for id in ids {
+        if let Some(meta) = ProblemMeta::load(*id)?
+            && meta.difficulty == "Medium"
+        {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// A warning when an active streak has no solve yet today, shown here and
+/// on `pick` so it surfaces before the day slips away.
+pub(crate) fn streak_warning(progress: &Progress, now: u64) -> Option<String> {
+    let streak = progress.streak_days(now);
+    if streak > 0 && progress.solved_today(now) == 0 {
+        Some(format!(
+            "! no solve yet today: your {streak}-day streak ends at midnight UTC"
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::SolveStatus;
+
+    const DAY: u64 = 86_400;
+
+    fn solved_on(timestamps: &[u64]) -> Progress {
+        let mut progress = Progress::default();
+        for (i, ts) in timestamps.iter().enumerate() {
+            let id = i as u32 + 1;
+            progress.record(id, "slug", SolveStatus::Solved, "submit");
+            progress.problems.get_mut(&id).unwrap().solved_at = Some(*ts);
+        }
+        progress
+    }
+
+    #[test]
+    fn test_mastery_line() {
+        // No attempt counts recorded: nothing to report
+        let progress = solved_on(&[0]);
+        assert_eq!(mastery_line(&progress), None);
+
+        let mut progress = Progress::default();
+        progress.record_submission(1, "two-sum", true);
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert_eq!(mastery_line(&progress).unwrap(), "First try: 1/1");
+
+        // Two failed runs and two submissions on the second problem
+        progress.record_test_failure(2, "add-two-numbers");
+        progress.record_test_failure(2, "add-two-numbers");
+        progress.record_submission(2, "add-two-numbers", false);
+        progress.record_submission(2, "add-two-numbers", true);
+        progress.record(2, "add-two-numbers", SolveStatus::Solved, "submit");
+        assert_eq!(
+            mastery_line(&progress).unwrap(),
+            "First try: 1/2 (the rest averaged 4.0 attempts)"
+        );
+
+        // Unsolved problems don't count either way
+        progress.record_submission(3, "longest-substring", false);
+        assert_eq!(
+            mastery_line(&progress).unwrap(),
+            "First try: 1/2 (the rest averaged 4.0 attempts)"
+        );
+    }
+
+    #[test]
+    fn test_goal_line() {
+        colored::control::set_override(false);
+        assert_eq!(goal_line("Today", 2, 2), "Today: 2/2 ✓");
+        assert_eq!(goal_line("Today", 1, 2), "Today: 1/2");
+        colored::control::unset_override();
+    }
+
+    #[test]
+    fn test_streak_warning_fires_only_with_streak_at_risk() {
+        let day = 20_000 * DAY;
+        let now = day + 3600;
+
+        // Streak ending yesterday, nothing today: warn
+        let progress = solved_on(&[day - DAY, day - 2 * DAY]);
+        let warning = streak_warning(&progress, now).unwrap();
+        assert!(warning.contains("2-day streak"));
+
+        // Already solved today: no warning
+        let progress = solved_on(&[day + 100, day - DAY]);
+        assert!(streak_warning(&progress, now).is_none());
+
+        // No streak to lose: no warning
+        assert!(streak_warning(&Progress::default(), now).is_none());
+    }
+}
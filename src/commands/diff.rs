@@ -0,0 +1,313 @@
+//! Diff command - Compare the working solution against an earlier attempt
+//!
+//! Shows a colored unified diff between the current solution file and a
+//! baseline: the most recent accepted snapshot (the default), or the code
+//! of a remote submission fetched by ID.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{api::LeetCodeClient, commands::find_solution_file};
+
+/// How many unchanged lines to show around each change.
+const CONTEXT: usize = 3;
+
+/// Diff the current solution against a snapshot or remote submission
+pub async fn execute(client: &LeetCodeClient, id: u32, against: Option<String>) -> Result<()> {
+    let solution_file = find_solution_file(id, None)?;
+    let current = std::fs::read_to_string(&solution_file)?;
+
+    let against = against.unwrap_or_else(|| "last-accepted".to_string());
+    let (label, baseline) = if against == "last-accepted" {
+        let progress = crate::progress::Progress::load()?;
+        let snapshot = progress
+            .problems
+            .get(&id)
+            .and_then(|p| p.accepted_snapshots.last())
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "no accepted snapshot recorded for problem {id} \
+                     (an accepted 'submit' saves one under accepted/)"
+                )
+            })?;
+        let code = std::fs::read_to_string(&snapshot)?;
+        (snapshot, code)
+    } else if let Ok(submission_id) = against.parse::<i64>() {
+        println!(
+            "{}",
+            format!("Fetching submission {submission_id}...").cyan()
+        );
+        let code = client.get_submission_code(submission_id).await?;
+        (format!("submission {submission_id}"), code)
+    } else {
+        anyhow::bail!(
+            "invalid --against value '{against}' \
+             (expected 'last-accepted' or a submission ID)"
+        );
+    };
+
+    if baseline == current {
+        println!("{}", format!("✓ No differences against {label}").green());
+        return Ok(());
+    }
+
+    println!("{}", format!("--- {label}").bold());
+    println!("{}", format!("+++ {}", solution_file.display()).bold());
+    print_unified_diff(&baseline, &current);
+
+    Ok(())
+}
+
+/// Print a colored unified diff of `old` against `new` (without the
+/// `---`/`+++` header lines).
+pub(crate) fn print_unified_diff(old: &str, new: &str) {
+    for line in render_unified_diff(old, new) {
+        if line.starts_with("@@") {
+            println!("{}", line.cyan());
+        } else if line.starts_with('-') {
+            println!("{}", line.red());
+        } else if line.starts_with('+') {
+            println!("{}", line.green());
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+/// One step of a diff, over lines or word tokens.
+#[derive(Debug, PartialEq)]
+pub(crate) enum DiffOp {
+    Equal(String),
+    Delete(String),
+    Insert(String),
+}
+
+/// Line-level diff of `old` against `new`.
+pub(crate) fn diff_lines(old: &str, new: &str) -> Vec<DiffOp> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    diff_tokens(&a, &b)
+}
+
+/// Diff two token sequences via longest common subsequence. The inputs
+/// (solution files, assertion payloads) are small, so the quadratic table
+/// is fine.
+pub(crate) fn diff_tokens(a: &[&str], b: &[&str]) -> Vec<DiffOp> {
+    // lcs[i][j] = length of the LCS of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::new();
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            ops.push(DiffOp::Equal(a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(DiffOp::Delete(a[i].to_string()));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(b[j].to_string()));
+            j += 1;
+        }
+    }
+    ops.extend(a[i..].iter().map(|l| DiffOp::Delete(l.to_string())));
+    ops.extend(b[j..].iter().map(|l| DiffOp::Insert(l.to_string())));
+    ops
+}
+
+/// Render `old` and `new` with their differing words highlighted (removed
+/// red on the left, added green on the right) — easier to scan than two
+/// raw dumps when the values are long vectors or strings.
+pub(crate) fn word_diff_colored(old: &str, new: &str) -> (String, String) {
+    let a = tokenize(old);
+    let b = tokenize(new);
+    let (mut left, mut right) = (String::new(), String::new());
+    for op in diff_tokens(&a, &b) {
+        match op {
+            DiffOp::Equal(t) => {
+                left.push_str(&t);
+                right.push_str(&t);
+            }
+            DiffOp::Delete(t) => left.push_str(&t.red().bold().to_string()),
+            DiffOp::Insert(t) => right.push_str(&t.green().bold().to_string()),
+        }
+    }
+    (left, right)
+}
+
+/// Split a value into word tokens: runs of alphanumerics (plus `_`, `.`,
+/// and `-`, so identifiers and signed floats stay whole) and single
+/// punctuation or whitespace characters.
+fn tokenize(s: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut word_start: Option<usize> = None;
+    for (i, c) in s.char_indices() {
+        if c.is_alphanumeric() || matches!(c, '_' | '.' | '-') {
+            word_start.get_or_insert(i);
+        } else {
+            if let Some(start) = word_start.take() {
+                tokens.push(&s[start..i]);
+            }
+            tokens.push(&s[i..i + c.len_utf8()]);
+        }
+    }
+    if let Some(start) = word_start {
+        tokens.push(&s[start..]);
+    }
+    tokens
+}
+
+/// Render the body of a unified diff (hunk headers plus `-`/`+`/context
+/// lines, no file header) between two strings.
+fn render_unified_diff(old: &str, new: &str) -> Vec<String> {
+    let ops = diff_lines(old, new);
+    let changed: Vec<bool> = ops
+        .iter()
+        .map(|op| !matches!(op, DiffOp::Equal(_)))
+        .collect();
+    // Keep every change plus CONTEXT unchanged lines on either side; the
+    // dropped runs in between separate the hunks
+    let keep: Vec<bool> = (0..ops.len())
+        .map(|i| {
+            let lo = i.saturating_sub(CONTEXT);
+            let hi = (i + CONTEXT + 1).min(ops.len());
+            changed[lo..hi].contains(&true)
+        })
+        .collect();
+
+    let mut out = Vec::new();
+    let (mut old_no, mut new_no) = (1usize, 1usize);
+    let mut i = 0;
+    while i < ops.len() {
+        if !keep[i] {
+            // Dropped ops are always Equal, so both sides advance
+            old_no += 1;
+            new_no += 1;
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let (hunk_old, hunk_new) = (old_no, new_no);
+        let (mut old_len, mut new_len) = (0usize, 0usize);
+        while i < ops.len() && keep[i] {
+            match &ops[i] {
+                DiffOp::Equal(_) => {
+                    old_len += 1;
+                    new_len += 1;
+                }
+                DiffOp::Delete(_) => old_len += 1,
+                DiffOp::Insert(_) => new_len += 1,
+            }
+            i += 1;
+        }
+        old_no = hunk_old + old_len;
+        new_no = hunk_new + new_len;
+        out.push(format!("@@ -{hunk_old},{old_len} +{hunk_new},{new_len} @@"));
+        for op in &ops[start..i] {
+            out.push(match op {
+                DiffOp::Equal(l) => format!(" {l}"),
+                DiffOp::Delete(l) => format!("-{l}"),
+                DiffOp::Insert(l) => format!("+{l}"),
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines_basic() {
+        let ops = diff_lines("a\nb\nc\n", "a\nx\nc\n");
+        assert_eq!(
+            ops,
+            vec![
+                DiffOp::Equal("a".to_string()),
+                DiffOp::Delete("b".to_string()),
+                DiffOp::Insert("x".to_string()),
+                DiffOp::Equal("c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_pure_insert_and_delete() {
+        assert_eq!(
+            diff_lines("", "a\nb\n"),
+            vec![
+                DiffOp::Insert("a".to_string()),
+                DiffOp::Insert("b".to_string()),
+            ]
+        );
+        assert_eq!(
+            diff_lines("a\nb\n", ""),
+            vec![
+                DiffOp::Delete("a".to_string()),
+                DiffOp::Delete("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_unified_diff_single_hunk() {
+        let lines = render_unified_diff("a\nb\nc\n", "a\nx\nc\n");
+        assert_eq!(lines, vec!["@@ -1,3 +1,3 @@", " a", "-b", "+x", " c"]);
+    }
+
+    #[test]
+    fn test_render_unified_diff_separate_hunks() {
+        // Two changes far enough apart get their own hunks with CONTEXT
+        // unchanged lines around each
+        let old: String = (1..=20).map(|n| format!("line{n}\n")).collect();
+        let new = old
+            .replace("line2\n", "LINE2\n")
+            .replace("line18", "LINE18");
+        let lines = render_unified_diff(&old, &new);
+
+        let headers: Vec<&String> = lines.iter().filter(|l| l.starts_with("@@")).collect();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[0], "@@ -1,5 +1,5 @@");
+        assert_eq!(headers[1], "@@ -15,6 +15,6 @@");
+        assert!(lines.contains(&"-line2".to_string()));
+        assert!(lines.contains(&"+LINE2".to_string()));
+        assert!(lines.contains(&"-line18".to_string()));
+        assert!(lines.contains(&"+LINE18".to_string()));
+        // Lines in the quiet middle are elided
+        assert!(!lines.iter().any(|l| l.contains("line10")));
+    }
+
+    #[test]
+    fn test_render_unified_diff_identical_is_empty() {
+        assert!(render_unified_diff("a\nb\n", "a\nb\n").is_empty());
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("[1, -2.5]"), vec!["[", "1", ",", " ", "-2.5", "]"]);
+        assert_eq!(tokenize("two_sum"), vec!["two_sum"]);
+        assert_eq!(tokenize(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_word_diff_colored() {
+        colored::control::set_override(false);
+        let (left, right) = word_diff_colored("[1, 2, 3]", "[1, 5, 3]");
+        colored::control::unset_override();
+        // With colors forced off, both sides come back verbatim
+        assert_eq!(left, "[1, 2, 3]");
+        assert_eq!(right, "[1, 5, 3]");
+    }
+}
@@ -0,0 +1,67 @@
+//! Doctor command - Diagnose workspace inconsistencies
+//!
+//! Checks the workspace for problems that would otherwise only surface as
+//! confusing errors later: duplicate solution files for the same problem
+//! and metadata entries pointing at missing files.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    commands::{find_duplicate_solutions, list_local_solutions},
+    meta::ProblemMeta,
+};
+
+/// Run workspace diagnostics
+pub async fn execute() -> Result<()> {
+    println!("{}", "Checking workspace...".cyan());
+    let mut issues = 0;
+
+    // Duplicate solution files for the same problem ID
+    for (id, paths) in find_duplicate_solutions()? {
+        issues += 1;
+        println!(
+            "{}",
+            format!("! problem {id} has {} solution files:", paths.len()).yellow()
+        );
+        let canonical = ProblemMeta::load(id)
+            .ok()
+            .flatten()
+            .map(|m| m.solution_path());
+        for path in &paths {
+            let marker = if canonical.as_deref() == Some(path) {
+                " (canonical, keep)"
+            } else {
+                " (rename or remove)"
+            };
+            println!("    {}{}", path.display(), marker);
+        }
+    }
+
+    // Metadata entries whose solution file is gone
+    for solution in list_local_solutions()? {
+        if let Some(meta) = ProblemMeta::load(solution.id)? {
+            let path = meta.solution_path();
+            if !path.exists() {
+                issues += 1;
+                println!(
+                    "{}",
+                    format!(
+                        "! metadata for problem {} points to missing {}",
+                        solution.id,
+                        path.display()
+                    )
+                    .yellow()
+                );
+            }
+        }
+    }
+
+    if issues == 0 {
+        println!("{}", "✓ No issues found".green());
+    } else {
+        println!("{}", format!("Found {issues} issue(s)").yellow().bold());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Exec command - Send raw GraphQL queries through the authenticated client
+//!
+//! A power-user escape hatch for exploring API fields and one-off
+//! scripting: the query comes from a file, variables from repeated
+//! `--var key=value` flags, and the raw JSON response goes to stdout.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{Result, anyhow};
+
+use crate::api::LeetCodeClient;
+
+/// Send the GraphQL query in `query_path` and print the JSON response
+pub async fn execute(client: &LeetCodeClient, query_path: &Path, vars: &[String]) -> Result<()> {
+    let query = std::fs::read_to_string(query_path)
+        .map_err(|e| anyhow!("failed to read query file {}: {e}", query_path.display()))?;
+
+    let mut variables = HashMap::new();
+    for var in vars {
+        let (key, value) = parse_var(var)?;
+        variables.insert(key, value);
+    }
+
+    let response = client.execute_graphql(&query, variables).await?;
+    println!("{}", serde_json::to_string_pretty(&response)?);
+    Ok(())
+}
+
+/// Parse a `--var key=value` spec. Values that parse as JSON are passed
+/// through typed (so `id=42` satisfies an `Int!` variable); anything else
+/// is sent as a string.
+fn parse_var(spec: &str) -> Result<(String, serde_json::Value)> {
+    let (key, value) = spec
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid --var '{spec}': expected key=value"))?;
+    if key.trim().is_empty() {
+        return Err(anyhow!("invalid --var '{spec}': empty variable name"));
+    }
+    let parsed = serde_json::from_str(value)
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+    Ok((key.trim().to_string(), parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_var_string() {
+        let (key, value) = parse_var("titleSlug=two-sum").unwrap();
+        assert_eq!(key, "titleSlug");
+        assert_eq!(value, serde_json::json!("two-sum"));
+    }
+
+    #[test]
+    fn test_parse_var_typed_json() {
+        assert_eq!(parse_var("id=42").unwrap().1, serde_json::json!(42));
+        assert_eq!(parse_var("paid=true").unwrap().1, serde_json::json!(true));
+        assert_eq!(
+            parse_var("tags=[\"array\"]").unwrap().1,
+            serde_json::json!(["array"])
+        );
+    }
+
+    #[test]
+    fn test_parse_var_value_with_equals() {
+        let (key, value) = parse_var("expr=a=b").unwrap();
+        assert_eq!(key, "expr");
+        assert_eq!(value, serde_json::json!("a=b"));
+    }
+
+    #[test]
+    fn test_parse_var_invalid() {
+        assert!(parse_var("no-equals-sign").is_err());
+        assert!(parse_var("=value").is_err());
+    }
+}
@@ -0,0 +1,406 @@
+//! Export command - Export local data for use elsewhere
+//!
+//! Two datasets: "anki" builds a flashcard deck (one card per downloaded
+//! problem, statement on the front, local notes plus complexity comments
+//! on the back), and "progress" dumps the local history DB as CSV or JSON
+//! for analysis in a spreadsheet or notebook.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{LocalSolution, list_local_solutions},
+    meta::ProblemMeta,
+    progress::{ProblemProgress, Progress, SolveStatus},
+};
+
+/// Export local solutions as flashcards or the progress history as data
+pub async fn execute(
+    client: &LeetCodeClient,
+    what: &str,
+    tag: Option<String>,
+    output: Option<PathBuf>,
+    format: &str,
+) -> Result<()> {
+    match what {
+        "anki" => export_anki(client, tag, output).await,
+        "progress" => export_progress(format, output),
+        other => anyhow::bail!("unsupported export '{other}': expected 'anki' or 'progress'"),
+    }
+}
+
+/// Export local solutions to an Anki flashcard deck
+async fn export_anki(
+    client: &LeetCodeClient,
+    tag: Option<String>,
+    output: Option<PathBuf>,
+) -> Result<()> {
+
+    let solutions = list_local_solutions()?;
+    if solutions.is_empty() {
+        println!("{}", "No local solutions found in src/solutions/.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Building Anki deck from {} solutions...", solutions.len()).cyan()
+    );
+
+    let tag_filter = tag.map(|t| t.to_lowercase().replace(' ', "-"));
+    let mut rows = Vec::new();
+
+    for solution in &solutions {
+        let detail = match client.get_problem_detail(&solution.slug).await {
+            Ok(d) => d,
+            Err(_) => continue, // Skip problems we can't fetch details for
+        };
+
+        // Apply tag filter against the problem's topic tags
+        if let Some(ref filter) = tag_filter {
+            let matches = detail
+                .topic_tags
+                .as_ref()
+                .is_some_and(|tags| tags.iter().any(|t| t.slug == *filter));
+            if !matches {
+                continue;
+            }
+        }
+
+        let front = format!(
+            "{} ({})\n\n{}",
+            detail.title,
+            detail.difficulty,
+            detail.clean_content()
+        );
+        let back = build_card_back(solution)?;
+        rows.push(format!("{};{}", csv_field(&front), csv_field(&back)));
+    }
+
+    if rows.is_empty() {
+        println!("{}", "No problems matched the filter.".yellow());
+        return Ok(());
+    }
+
+    let output_path = output.unwrap_or_else(|| PathBuf::from("anki_deck.csv"));
+    std::fs::write(&output_path, rows.join("\n") + "\n")?;
+
+    println!(
+        "{}",
+        format!(
+            "✓ Exported {} cards to {}",
+            rows.len(),
+            output_path.display()
+        )
+        .green()
+    );
+    println!("  Import in Anki with ';' as the field separator.");
+
+    Ok(())
+}
+
+/// Dump the local progress DB as CSV or JSON, joined with the workspace
+/// metadata for difficulty and tags. Works offline.
+fn export_progress(format: &str, output: Option<PathBuf>) -> Result<()> {
+    if format != "csv" && format != "json" {
+        anyhow::bail!("unsupported progress format '{format}': expected 'csv' or 'json'");
+    }
+    let progress = Progress::load()?;
+    if progress.problems.is_empty() {
+        println!("{}", "No progress recorded yet.".yellow());
+        return Ok(());
+    }
+    let rows = progress_rows(&ProblemMeta::load_all()?, &progress);
+
+    let content = match format {
+        "csv" => progress_csv(&rows),
+        _ => serde_json::to_string_pretty(&rows)? + "\n",
+    };
+    let output_path = output.unwrap_or_else(|| PathBuf::from(format!("progress.{format}")));
+    std::fs::write(&output_path, content)?;
+    println!(
+        "{}",
+        format!(
+            "✓ Exported {} problem(s) to {}",
+            rows.len(),
+            output_path.display()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// One problem's history, flattened for external analysis.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct ProgressRow {
+    pub(crate) id: u32,
+    pub(crate) slug: String,
+    /// From the workspace metadata; empty without a metadata file
+    pub(crate) difficulty: String,
+    /// Tag names joined with '|'; empty without a metadata file
+    pub(crate) tags: String,
+    pub(crate) status: String,
+    /// First solve date as YYYY-MM-DD, empty if unsolved
+    pub(crate) solved_at: String,
+    /// Failed local test runs plus submissions
+    pub(crate) attempts: u32,
+    /// Fastest accepted runtime, e.g. "4 ms"
+    pub(crate) best_runtime: String,
+}
+
+/// Join the progress DB with the workspace metadata into flat rows.
+pub(crate) fn progress_rows(metas: &[ProblemMeta], progress: &Progress) -> Vec<ProgressRow> {
+    progress
+        .problems
+        .iter()
+        .map(|(id, entry)| {
+            let meta = metas.iter().find(|m| m.frontend_id == *id);
+            ProgressRow {
+                id: *id,
+                slug: entry.slug.clone(),
+                difficulty: meta.map(|m| m.difficulty.clone()).unwrap_or_default(),
+                tags: meta.map(|m| m.tags.join("|")).unwrap_or_default(),
+                status: match entry.status {
+                    SolveStatus::Solved => "solved".to_string(),
+                    SolveStatus::Attempting => "attempting".to_string(),
+                },
+                solved_at: entry
+                    .solved_at
+                    .map(crate::commands::perf::format_date)
+                    .unwrap_or_default(),
+                attempts: entry.failed_test_runs + entry.submissions,
+                best_runtime: best_runtime(entry).unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+/// The fastest accepted runtime in a problem's perf history.
+fn best_runtime(entry: &ProblemProgress) -> Option<String> {
+    entry
+        .perf_history
+        .iter()
+        .min_by(|a, b| {
+            runtime_ms(&a.runtime)
+                .partial_cmp(&runtime_ms(&b.runtime))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|sample| sample.runtime.clone())
+}
+
+/// The numeric part of a judge runtime like "4 ms"; unparseable runtimes
+/// sort last.
+fn runtime_ms(runtime: &str) -> f64 {
+    runtime
+        .trim()
+        .trim_end_matches("ms")
+        .trim()
+        .parse()
+        .unwrap_or(f64::MAX)
+}
+
+/// Render progress rows as CSV with a header line.
+pub(crate) fn progress_csv(rows: &[ProgressRow]) -> String {
+    let mut csv = String::from("id,slug,difficulty,tags,status,solved_at,attempts,best_runtime\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{}\n",
+            row.id,
+            csv_field(&row.slug),
+            csv_field(&row.difficulty),
+            csv_field(&row.tags),
+            row.status,
+            row.solved_at,
+            row.attempts,
+            csv_field(&row.best_runtime),
+        ));
+    }
+    csv
+}
+
+/// Build the back of a card: notes file contents (if any) plus complexity
+/// comments scraped from the solution source.
+fn build_card_back(solution: &LocalSolution) -> Result<String> {
+    let mut back = String::new();
+
+    // Per-problem notes live next to the workspace in notes/p{id:04}_{slug}.md
+    let notes_path = PathBuf::from("notes").join(format!(
+        "p{:04}_{}.md",
+        solution.id,
+        solution.slug.replace('-', "_")
+    ));
+    if notes_path.exists() {
+        back.push_str(std::fs::read_to_string(&notes_path)?.trim());
+        back.push_str("\n\n");
+    }
+
+    if let Ok(code) = std::fs::read_to_string(&solution.path) {
+        let complexity = extract_complexity_comments(&code);
+        if !complexity.is_empty() {
+            back.push_str(&complexity.join("\n"));
+        }
+    }
+
+    if back.trim().is_empty() {
+        back = "(no notes yet)".to_string();
+    }
+
+    Ok(back.trim().to_string())
+}
+
+/// Extract complexity-related comment lines from solution source code.
+pub(crate) fn extract_complexity_comments(code: &str) -> Vec<String> {
+    code.lines()
+        .filter_map(|line| {
+            let trimmed = line.trim();
+            let comment = trimmed.strip_prefix("//")?.trim_start_matches('/').trim();
+            let lower = comment.to_lowercase();
+            if lower.contains("time complexity") || lower.contains("space complexity") {
+                Some(comment.to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Quote a CSV field, escaping embedded quotes by doubling them.
+pub(crate) fn csv_field(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_plain() {
+        assert_eq!(csv_field("hello"), "\"hello\"");
+    }
+
+    #[test]
+    fn test_csv_field_escapes_quotes() {
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_csv_field_preserves_newlines() {
+        assert_eq!(csv_field("line1\nline2"), "\"line1\nline2\"");
+    }
+
+    #[test]
+    fn test_extract_complexity_comments() {
+        let code = r#"
+// Time Complexity: O(n)
+/// Space Complexity: O(1)
+fn solve() {
+    // just a regular comment
+}
+"#;
+        let comments = extract_complexity_comments(code);
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0], "Time Complexity: O(n)");
+        assert_eq!(comments[1], "Space Complexity: O(1)");
+    }
+
+    #[test]
+    fn test_extract_complexity_comments_none() {
+        let code = "fn solve() {}\n// nothing relevant here\n";
+        assert!(extract_complexity_comments(code).is_empty());
+    }
+
+    #[test]
+    fn test_extract_complexity_comments_case_insensitive() {
+        let code = "// time complexity: O(log n)\n";
+        let comments = extract_complexity_comments(code);
+        assert_eq!(comments, vec!["time complexity: O(log n)"]);
+    }
+
+    fn make_meta(id: u32) -> ProblemMeta {
+        ProblemMeta {
+            id,
+            frontend_id: id,
+            slug: format!("problem-{id}"),
+            title: format!("Problem {id}"),
+            difficulty: "Medium".to_string(),
+            tags: vec!["Array".to_string(), "Hash Table".to_string()],
+            downloaded_at: 1_700_000_000,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_progress_rows_joins_metadata() {
+        let mut progress = Progress::default();
+        progress.record(1, "problem-1", SolveStatus::Solved, "submit");
+        progress.problems.get_mut(&1).unwrap().solved_at = Some(1_700_000_000);
+        progress.record_submission(1, "problem-1", true);
+        progress.record_test_failure(2, "problem-2");
+
+        let rows = progress_rows(&[make_meta(1)], &progress);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].id, 1);
+        assert_eq!(rows[0].difficulty, "Medium");
+        assert_eq!(rows[0].tags, "Array|Hash Table");
+        assert_eq!(rows[0].status, "solved");
+        assert_eq!(rows[0].solved_at, "2023-11-14");
+        assert_eq!(rows[0].attempts, 1);
+        // No metadata file: difficulty and tags stay empty
+        assert_eq!(rows[1].difficulty, "");
+        assert_eq!(rows[1].status, "attempting");
+        assert_eq!(rows[1].solved_at, "");
+        assert_eq!(rows[1].attempts, 1);
+    }
+
+    #[test]
+    fn test_best_runtime_picks_fastest() {
+        let mut progress = Progress::default();
+        progress.record(1, "problem-1", SolveStatus::Solved, "submit");
+        for runtime in ["12 ms", "4 ms", "8 ms"] {
+            progress.record_perf(
+                1,
+                crate::progress::PerfSample {
+                    at: 1_700_000_000,
+                    runtime: runtime.to_string(),
+                    memory: "2.1 MB".to_string(),
+                    runtime_percentile: None,
+                    memory_percentile: None,
+                },
+            );
+        }
+        let rows = progress_rows(&[], &progress);
+        assert_eq!(rows[0].best_runtime, "4 ms");
+    }
+
+    #[test]
+    fn test_progress_csv_header_and_rows() {
+        let row = ProgressRow {
+            id: 1,
+            slug: "two-sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: "Array|Hash Table".to_string(),
+            status: "solved".to_string(),
+            solved_at: "2023-11-14".to_string(),
+            attempts: 2,
+            best_runtime: "4 ms".to_string(),
+        };
+        let csv = progress_csv(&[row]);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "id,slug,difficulty,tags,status,solved_at,attempts,best_runtime"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "1,\"two-sum\",\"Easy\",\"Array|Hash Table\",solved,2023-11-14,2,\"4 ms\""
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
@@ -0,0 +1,164 @@
+//! Export-example command - package a solved problem as a curated example
+//!
+//! Converts a solved workspace problem into a standalone crate under
+//! `examples/` — cleaned code with its tests, complexity comments, and a
+//! README — in the shape used for contributing solutions back upstream.
+//! The export refuses to run until the complexity comments are filled in
+//! and the problem's tests pass.
+
+use std::{path::PathBuf, process::Command};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{export::extract_complexity_comments, find_solution_file},
+    meta::ProblemMeta,
+};
+
+/// Export a solved problem as a standalone example crate.
+pub async fn execute(id: u32) -> Result<()> {
+    let meta = match ProblemMeta::load(id)? {
+        Some(meta) => meta,
+        None => anyhow::bail!(
+            "no metadata for problem {id}: run 'leetcode-cli migrate' to index \
+             pre-existing solutions"
+        ),
+    };
+    if meta.language != "rust" {
+        anyhow::bail!(
+            "export-example only supports Rust solutions (problem {id} uses {})",
+            meta.language
+        );
+    }
+
+    let solution_file = find_solution_file(id, None)?;
+    let code = std::fs::read_to_string(&solution_file)?;
+
+    // Curated examples document their complexity; refuse half-done exports
+    let complexity = extract_complexity_comments(&code);
+    if complexity.is_empty() {
+        anyhow::bail!(
+            "no complexity comments found in {}: add '// Time Complexity: ...' and \
+             '// Space Complexity: ...' before exporting",
+            solution_file.display()
+        );
+    }
+
+    // The example ships with its tests, so they had better pass
+    println!("{}", format!("Running tests for problem {id}...").cyan());
+    let status = Command::new("cargo")
+        .args(["test", &format!("{}::", meta.module_name())])
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("tests failed; fix the solution before exporting it as an example");
+    }
+
+    // Inline `mod helpers;`-style declarations so the example is one file
+    let cleaned = LeetCodeClient::inline_local_modules(&code, &solution_file);
+
+    let package_name = meta.module_name();
+    let example_dir = PathBuf::from("examples").join(&package_name);
+    std::fs::create_dir_all(example_dir.join("src"))?;
+    std::fs::write(
+        example_dir.join("Cargo.toml"),
+        example_cargo_toml(&package_name),
+    )?;
+    std::fs::write(example_dir.join("src").join("lib.rs"), cleaned)?;
+    std::fs::write(
+        example_dir.join("README.md"),
+        example_readme(&meta, &complexity),
+    )?;
+
+    println!(
+        "{}",
+        format!("✓ Exported example to {}", example_dir.display()).green()
+    );
+    println!("  Verify with 'cargo test' inside the example before opening a PR.");
+
+    Ok(())
+}
+
+/// The standalone `Cargo.toml` for an exported example crate.
+fn example_cargo_toml(package_name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{package_name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+"#
+    )
+}
+
+/// The example's README: identity, link, tags, and the complexity lines
+/// scraped from the solution source.
+fn example_readme(meta: &ProblemMeta, complexity: &[String]) -> String {
+    let mut readme = format!(
+        "# {}. {}\n\n- Difficulty: {}\n- URL: https://leetcode.com/problems/{}/\n",
+        meta.frontend_id, meta.title, meta.difficulty, meta.slug
+    );
+    if !meta.tags.is_empty() {
+        readme.push_str(&format!("- Tags: {}\n", meta.tags.join(", ")));
+    }
+    readme.push('\n');
+    for line in complexity {
+        readme.push_str(&format!("- {line}\n"));
+    }
+    readme
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_meta() -> ProblemMeta {
+        ProblemMeta {
+            id: 1,
+            frontend_id: 1,
+            slug: "two-sum".to_string(),
+            title: "Two Sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: vec!["array".to_string(), "hash-table".to_string()],
+            downloaded_at: 0,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_example_cargo_toml() {
+        let toml = example_cargo_toml("p0001_two_sum");
+        assert!(toml.contains("name = \"p0001_two_sum\""));
+        assert!(toml.contains("edition = \"2021\""));
+    }
+
+    #[test]
+    fn test_example_readme_includes_identity_and_complexity() {
+        let readme = example_readme(
+            &make_meta(),
+            &[
+                "Time Complexity: O(n)".to_string(),
+                "Space Complexity: O(n)".to_string(),
+            ],
+        );
+        assert!(readme.starts_with("# 1. Two Sum\n"));
+        assert!(readme.contains("- Difficulty: Easy\n"));
+        assert!(readme.contains("- URL: https://leetcode.com/problems/two-sum/\n"));
+        assert!(readme.contains("- Tags: array, hash-table\n"));
+        assert!(readme.contains("- Time Complexity: O(n)\n"));
+        assert!(readme.contains("- Space Complexity: O(n)\n"));
+    }
+
+    #[test]
+    fn test_example_readme_without_tags() {
+        let mut meta = make_meta();
+        meta.tags.clear();
+        let readme = example_readme(&meta, &["Time Complexity: O(1)".to_string()]);
+        assert!(!readme.contains("- Tags:"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Fastest command - sample the fastest accepted solutions
+//!
+//! Reads the runtime distribution of the latest accepted Rust submission,
+//! pulls sample code at the fastest runtimes where the API permits it,
+//! saves the samples under `reference/`, and prints a diff summary of each
+//! against the working solution — a quick read on what the top of the
+//! distribution does differently.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{diff::DiffOp, find_solution_file},
+    meta::ProblemMeta,
+};
+
+/// How many of the fastest runtimes to sample.
+const SAMPLES: usize = 3;
+
+/// Fetch the fastest sample solutions and compare them with mine.
+pub async fn execute(client: &LeetCodeClient, id: u32) -> Result<()> {
+    let meta = match ProblemMeta::load(id)? {
+        Some(meta) => meta,
+        None => anyhow::bail!(
+            "no metadata for problem {id}: run 'leetcode-cli migrate' to index \
+             pre-existing solutions"
+        ),
+    };
+    if meta.language != "rust" {
+        anyhow::bail!(
+            "fastest only supports Rust solutions (problem {id} uses {})",
+            meta.language
+        );
+    }
+    let mine = std::fs::read_to_string(find_solution_file(id, None)?)?;
+
+    // The distribution hangs off a submission, so an accepted one is the
+    // ticket in
+    let submission_id = client
+        .get_latest_accepted_submission(&meta.slug, "rust")
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "no accepted Rust submission found for problem {id}: \
+                 submit one first to unlock the runtime distribution"
+            )
+        })?;
+    println!(
+        "{}",
+        format!("Reading runtime distribution of submission {submission_id}...").cyan()
+    );
+    let distribution = client.get_runtime_distribution(submission_id).await?;
+    if distribution.is_empty() {
+        anyhow::bail!("the judge reported an empty runtime distribution for problem {id}");
+    }
+
+    let reference_dir = PathBuf::from("reference").join(meta.module_name());
+    std::fs::create_dir_all(&reference_dir)?;
+
+    let mut saved = 0;
+    for (runtime_ms, percent) in distribution.into_iter().take(SAMPLES) {
+        let Some(code) = client
+            .get_sample_solution(meta.id, "rust", runtime_ms)
+            .await?
+        else {
+            println!(
+                "{}",
+                format!("! no sample available at {runtime_ms} ms").yellow()
+            );
+            continue;
+        };
+        let path = reference_dir.join(format!("{runtime_ms}ms.rs"));
+        std::fs::write(&path, &code)?;
+        saved += 1;
+        let (added, removed) = diff_counts(&mine, &code);
+        println!(
+            "  {} {} ({percent:.1}% of submissions) — {}",
+            format!("{runtime_ms} ms:").bold(),
+            path.display(),
+            summary_label(added, removed)
+        );
+    }
+    if saved == 0 {
+        anyhow::bail!(
+            "the API permitted no samples for problem {id}; \
+             not every problem exposes distribution code"
+        );
+    }
+    println!(
+        "{}",
+        format!("✓ Saved {saved} sample(s) under {}", reference_dir.display()).green()
+    );
+    println!("  Full diff: diff {} <sample>", "reference/...".dimmed());
+    Ok(())
+}
+
+/// Lines a sample adds and removes relative to my solution.
+pub(crate) fn diff_counts(mine: &str, sample: &str) -> (usize, usize) {
+    let mut added = 0;
+    let mut removed = 0;
+    for op in crate::commands::diff::diff_lines(mine, sample) {
+        match op {
+            DiffOp::Insert(_) => added += 1,
+            DiffOp::Delete(_) => removed += 1,
+            DiffOp::Equal(_) => {}
+        }
+    }
+    (added, removed)
+}
+
+/// One-line diff summary, e.g. "+12 -8 lines vs mine".
+pub(crate) fn summary_label(added: usize, removed: usize) -> String {
+    if added == 0 && removed == 0 {
+        "identical to mine".to_string()
+    } else {
+        format!("+{added} -{removed} lines vs mine")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_counts() {
+        assert_eq!(diff_counts("a\nb\nc\n", "a\nx\nc\n"), (1, 1));
+        assert_eq!(diff_counts("a\n", "a\nb\nc\n"), (2, 0));
+        assert_eq!(diff_counts("a\nb\n", "a\nb\n"), (0, 0));
+    }
+
+    #[test]
+    fn test_summary_label() {
+        assert_eq!(summary_label(0, 0), "identical to mine");
+        assert_eq!(summary_label(12, 8), "+12 -8 lines vs mine");
+    }
+}
@@ -0,0 +1,155 @@
+//! Grep command - Search local solutions for a pattern
+//!
+//! Case-insensitive substring search across all downloaded solution files
+//! (and optionally the notes directory), printing problem ID/title and the
+//! matching lines. Useful for "where did I implement a segment tree before?"
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{commands::list_local_solutions, meta::ProblemMeta};
+
+/// Search local solutions (and optionally notes) for a pattern
+pub async fn execute(pattern: String, include_notes: bool) -> Result<()> {
+    let solutions = list_local_solutions()?;
+    if solutions.is_empty() && !std::path::Path::new("archive").exists() {
+        println!("{}", "No local solutions found in src/solutions/.".yellow());
+        return Ok(());
+    }
+
+    let mut total_matches = 0;
+    for solution in &solutions {
+        let Ok(content) = std::fs::read_to_string(&solution.path) else {
+            continue;
+        };
+        let matches = search_lines(&content, &pattern);
+        if matches.is_empty() {
+            continue;
+        }
+
+        let title = ProblemMeta::load(solution.id)
+            .ok()
+            .flatten()
+            .map(|m| m.title)
+            .unwrap_or_else(|| solution.slug.replace('-', " "));
+        println!(
+            "\n{} {} ({})",
+            format!("p{:04}", solution.id).bold().cyan(),
+            title.bold(),
+            solution.path.display()
+        );
+        for (line_number, line) in &matches {
+            println!("  {:>4}: {}", line_number, line.trim());
+        }
+        total_matches += matches.len();
+    }
+
+    // Archived solutions stay searchable even though they're out of the
+    // active workspace
+    if let Ok(entries) = std::fs::read_dir("archive") {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some((id, _)) = crate::commands::parse_solution_file_name(name) else {
+                continue;
+            };
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let matches = search_lines(&content, &pattern);
+            if matches.is_empty() {
+                continue;
+            }
+            println!(
+                "\n{} {} ({})",
+                format!("p{id:04}").bold().cyan(),
+                "archived".dimmed(),
+                path.display()
+            );
+            for (line_number, line) in &matches {
+                println!("  {:>4}: {}", line_number, line.trim());
+            }
+            total_matches += matches.len();
+        }
+    }
+
+    if include_notes && let Ok(entries) = std::fs::read_dir("notes") {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_none_or(|e| e != "md") {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let matches = search_lines(&content, &pattern);
+            if matches.is_empty() {
+                continue;
+            }
+            println!("\n{} {}", "notes".bold().cyan(), path.display());
+            for (line_number, line) in &matches {
+                println!("  {:>4}: {}", line_number, line.trim());
+            }
+            total_matches += matches.len();
+        }
+    }
+
+    if total_matches == 0 {
+        println!("{}", format!("No matches for '{pattern}'.").yellow());
+    } else {
+        println!(
+            "\n{}",
+            format!("✓ {total_matches} matching line(s)").green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Case-insensitive substring search, returning 1-based line numbers with
+/// their lines.
+pub(crate) fn search_lines(content: &str, pattern: &str) -> Vec<(usize, String)> {
+    let needle = pattern.to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.to_lowercase().contains(&needle))
+        .map(|(i, line)| (i + 1, line.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_lines_case_insensitive() {
+        let content = "fn binary_search() {}\n// Binary Search helper\nlet x = 1;";
+        let matches = search_lines(content, "binary search");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, 2);
+    }
+
+    #[test]
+    fn test_search_lines_substring() {
+        let content = "let mut segment_tree = vec![0; 4 * n];";
+        let matches = search_lines(content, "segment_tree");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, 1);
+    }
+
+    #[test]
+    fn test_search_lines_no_match() {
+        assert!(search_lines("fn main() {}", "dijkstra").is_empty());
+    }
+
+    #[test]
+    fn test_search_lines_empty_pattern() {
+        assert!(search_lines("anything", "").is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Import command - Bring an existing ad-hoc solutions folder into the workspace
+//!
+//! Walks a directory of Rust files and heuristically matches each file to a
+//! LeetCode problem (by ID in the filename, by slug, or by the solution
+//! function name), copies matches into `src/solutions/` with the standard
+//! layout, and marks them as solved in the local progress database.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::pick::add_module_declaration,
+    problem::Problem,
+    progress::{Progress, SolveStatus},
+};
+
+/// Import solutions from an external directory
+pub async fn execute(client: &LeetCodeClient, dir: PathBuf) -> Result<()> {
+    if !dir.is_dir() {
+        anyhow::bail!("not a directory: {}", dir.display());
+    }
+
+    let problems = client.get_all_problems().await?;
+    let mut files = Vec::new();
+    collect_rust_files(&dir, &mut files)?;
+
+    if files.is_empty() {
+        println!("{}", "No .rs files found to import.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Scanning {} files in {}...", files.len(), dir.display()).cyan()
+    );
+
+    let mut progress = Progress::load()?;
+    let mut imported = 0;
+    let mut skipped = 0;
+
+    for file in &files {
+        let name = file
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let content = std::fs::read_to_string(file).unwrap_or_default();
+
+        let Some(problem) = match_problem(&name, &content, &problems) else {
+            println!("  {} {}", "? unmatched:".yellow(), file.display());
+            skipped += 1;
+            continue;
+        };
+
+        let id = problem.stat.frontend_question_id;
+        let module_name = format!(
+            "p{:04}_{}",
+            id,
+            problem.stat.question_title_slug().replace('-', "_")
+        );
+        let target = PathBuf::from("src/solutions").join(format!("{module_name}.rs"));
+
+        if target.exists() {
+            println!(
+                "  {} {} (already in workspace)",
+                "- skipped:".yellow(),
+                name
+            );
+            skipped += 1;
+        } else {
+            std::fs::create_dir_all("src/solutions")?;
+            std::fs::copy(file, &target)?;
+            add_module_declaration(&module_name)?;
+            println!(
+                "  {} {} -> {}",
+                "✓ imported:".green(),
+                name,
+                target.display()
+            );
+            imported += 1;
+        }
+
+        progress.record(
+            id,
+            &problem.stat.question_title_slug(),
+            SolveStatus::Solved,
+            "import",
+        );
+    }
+
+    progress.save()?;
+    println!(
+        "{}",
+        format!("✓ Imported {imported} solution(s), skipped {skipped}").green()
+    );
+
+    Ok(())
+}
+
+/// Recursively collect .rs files under a directory.
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            // Skip hidden directories and build artifacts
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.starts_with('.') && name != "target" {
+                collect_rust_files(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Heuristically match a file to a problem, trying in order:
+/// 1. a numeric problem ID embedded in the filename,
+/// 2. the problem slug embedded in the filename,
+/// 3. the solution function name matching a slug.
+pub(crate) fn match_problem<'a>(
+    file_name: &str,
+    content: &str,
+    problems: &'a [Problem],
+) -> Option<&'a Problem> {
+    if let Some(id) = extract_id_from_file_name(file_name)
+        && let Some(problem) = problems.iter().find(|p| p.stat.frontend_question_id == id)
+    {
+        return Some(problem);
+    }
+
+    let normalized_name = file_name
+        .trim_end_matches(".rs")
+        .to_lowercase()
+        .replace(['-', ' '], "_");
+    if let Some(problem) = problems.iter().find(|p| {
+        let slug = p.stat.question_title_slug().replace('-', "_");
+        !slug.is_empty() && normalized_name.contains(&slug)
+    }) {
+        return Some(problem);
+    }
+
+    if let Some(fn_name) = extract_solution_fn_name(content) {
+        return problems
+            .iter()
+            .find(|p| p.stat.question_title_slug().replace('-', "_") == fn_name);
+    }
+
+    None
+}
+
+/// Extract a problem ID from a filename like `0001_two_sum.rs`, `p42.rs`,
+/// or `problem-121.rs`.
+pub(crate) fn extract_id_from_file_name(file_name: &str) -> Option<u32> {
+    let stem = file_name.trim_end_matches(".rs");
+    let digits: String = stem
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Extract the first `pub fn` name inside an `impl Solution` block.
+pub(crate) fn extract_solution_fn_name(content: &str) -> Option<String> {
+    let mut in_solution = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.contains("impl Solution") {
+            in_solution = true;
+        }
+        if in_solution
+            && let Some(rest) = trimmed.strip_prefix("pub fn ")
+        {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            if !name.is_empty() {
+                return Some(name);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::{Difficulty, Stat};
+
+    fn make_problem(id: u32, slug: &str) -> Problem {
+        Problem {
+            stat: Stat {
+                question_id: id,
+                question__article__live: None,
+                question__article__slug: None,
+                question__title: None,
+                question__title_slug: slug.to_string(),
+                question__hide: false,
+                total_acs: 0,
+                total_submitted: 1,
+                frontend_question_id: id,
+                is_new_question: false,
+            },
+            difficulty: Difficulty { level: 1 },
+            paid_only: false,
+            is_favor: false,
+            frequency: 0,
+            progress: 0,
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_id_from_file_name() {
+        assert_eq!(extract_id_from_file_name("0001_two_sum.rs"), Some(1));
+        assert_eq!(extract_id_from_file_name("p42.rs"), Some(42));
+        assert_eq!(extract_id_from_file_name("problem-121.rs"), Some(121));
+        assert_eq!(extract_id_from_file_name("two_sum.rs"), None);
+    }
+
+    #[test]
+    fn test_extract_solution_fn_name() {
+        let code = "impl Solution {\n    pub fn two_sum(nums: Vec<i32>) -> Vec<i32> {\n    }\n}";
+        assert_eq!(extract_solution_fn_name(code), Some("two_sum".to_string()));
+    }
+
+    #[test]
+    fn test_extract_solution_fn_name_no_impl() {
+        let code = "pub fn helper() {}\n";
+        assert_eq!(extract_solution_fn_name(code), None);
+    }
+
+    #[test]
+    fn test_match_problem_by_id() {
+        let problems = vec![make_problem(1, "two-sum"), make_problem(9, "palindrome-number")];
+        let matched = match_problem("0009_solution.rs", "", &problems).unwrap();
+        assert_eq!(matched.stat.frontend_question_id, 9);
+    }
+
+    #[test]
+    fn test_match_problem_by_slug() {
+        let problems = vec![make_problem(1, "two-sum")];
+        let matched = match_problem("my-two-sum-attempt.rs", "", &problems).unwrap();
+        assert_eq!(matched.stat.frontend_question_id, 1);
+    }
+
+    #[test]
+    fn test_match_problem_by_fn_name() {
+        let problems = vec![make_problem(7, "reverse-integer")];
+        let code = "impl Solution {\n    pub fn reverse_integer(x: i32) -> i32 { 0 }\n}";
+        let matched = match_problem("untitled.rs", code, &problems).unwrap();
+        assert_eq!(matched.stat.frontend_question_id, 7);
+    }
+
+    #[test]
+    fn test_match_problem_unmatched() {
+        let problems = vec![make_problem(1, "two-sum")];
+        assert!(match_problem("scratch.rs", "fn main() {}", &problems).is_none());
+    }
+}
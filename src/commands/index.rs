@@ -0,0 +1,195 @@
+//! Index command - Generate a solutions index from the local workspace
+//!
+//! Scans `src/solutions/` and writes a `SOLUTIONS.md` table with title,
+//! difficulty, topic tags, solve status, and complexity notes for every
+//! downloaded problem, linking back to each solution file.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{export::extract_complexity_comments, list_local_solutions},
+    problem::DifficultyLevel,
+};
+
+const INDEX_FILE: &str = "SOLUTIONS.md";
+
+/// One row of the generated solutions index.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct IndexEntry {
+    pub id: u32,
+    pub title: String,
+    pub difficulty: String,
+    pub tags: Vec<String>,
+    pub status: String,
+    pub complexity: String,
+    pub path: String,
+}
+
+/// Generate the solutions index
+pub async fn execute(client: &LeetCodeClient) -> Result<()> {
+    let solutions = list_local_solutions()?;
+    if solutions.is_empty() {
+        println!("{}", "No local solutions found in src/solutions/.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Indexing {} solutions...", solutions.len()).cyan()
+    );
+
+    let mut entries = Vec::new();
+    for solution in &solutions {
+        let code = std::fs::read_to_string(&solution.path).unwrap_or_default();
+        let (doc_title, doc_difficulty) = extract_doc_header(&code);
+
+        let mut entry = IndexEntry {
+            id: solution.id,
+            title: doc_title.unwrap_or_else(|| solution.slug.replace('-', " ")),
+            difficulty: doc_difficulty.unwrap_or_default(),
+            complexity: extract_complexity_comments(&code).join("; "),
+            path: solution.path.display().to_string(),
+            ..Default::default()
+        };
+
+        // Enrich from the problem list cache (status, difficulty fallback)
+        if let Ok(Some(problem)) = client.get_problem_by_id(solution.id).await {
+            entry.status = match problem.status.as_deref() {
+                Some("ac") => "✓ Solved".to_string(),
+                Some("notac") => "~ Trying".to_string(),
+                _ => "○ New".to_string(),
+            };
+            if entry.difficulty.is_empty() {
+                entry.difficulty = DifficultyLevel::try_from(problem.difficulty.level)
+                    .map(|d| d.name().to_string())
+                    .unwrap_or_default();
+            }
+        }
+
+        // Topic tags require a detail fetch; tolerate failures (e.g. offline)
+        if let Ok(detail) = client.get_problem_detail(&solution.slug).await
+            && let Some(tags) = detail.topic_tags
+        {
+            entry.tags = tags.into_iter().map(|t| t.name).collect();
+        }
+
+        entries.push(entry);
+    }
+
+    std::fs::write(INDEX_FILE, render_index(&entries))?;
+    println!(
+        "{}",
+        format!("✓ Wrote index of {} problems to {INDEX_FILE}", entries.len()).green()
+    );
+
+    // Surface duplicate solution files alongside the index
+    let duplicates = crate::commands::find_duplicate_solutions()?;
+    if !duplicates.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "! {} problem(s) have duplicate solution files; run 'leetcode-cli doctor'",
+                duplicates.len()
+            )
+            .yellow()
+        );
+    }
+
+    Ok(())
+}
+
+/// Extract the problem title and difficulty from the doc-comment header the
+/// template writes at the top of each solution file.
+pub(crate) fn extract_doc_header(code: &str) -> (Option<String>, Option<String>) {
+    let mut title = None;
+    let mut difficulty = None;
+    for line in code.lines() {
+        let trimmed = line.trim().trim_start_matches("///").trim();
+        if let Some(t) = trimmed.strip_prefix("Problem:") {
+            title.get_or_insert_with(|| t.trim().to_string());
+        } else if let Some(d) = trimmed.strip_prefix("Difficulty:") {
+            difficulty.get_or_insert_with(|| d.trim().to_string());
+        }
+        if title.is_some() && difficulty.is_some() {
+            break;
+        }
+    }
+    (title, difficulty)
+}
+
+/// Render the index entries as a Markdown document with a summary table.
+pub(crate) fn render_index(entries: &[IndexEntry]) -> String {
+    let mut out = String::from("# Solutions Index\n\n");
+    out.push_str(&format!("{} problems downloaded.\n\n", entries.len()));
+    out.push_str("| ID | Title | Difficulty | Tags | Status | Complexity |\n");
+    out.push_str("|---:|-------|------------|------|--------|------------|\n");
+    for entry in entries {
+        out.push_str(&format!(
+            "| {} | [{}]({}) | {} | {} | {} | {} |\n",
+            entry.id,
+            entry.title,
+            entry.path,
+            entry.difficulty,
+            entry.tags.join(", "),
+            entry.status,
+            entry.complexity
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_doc_header() {
+        let code = "/// Problem: Two Sum\n/// Difficulty: Easy\n/// URL: ...\npub struct Solution;\n";
+        let (title, difficulty) = extract_doc_header(code);
+        assert_eq!(title, Some("Two Sum".to_string()));
+        assert_eq!(difficulty, Some("Easy".to_string()));
+    }
+
+    #[test]
+    fn test_extract_doc_header_missing() {
+        let code = "pub struct Solution;\n";
+        let (title, difficulty) = extract_doc_header(code);
+        assert!(title.is_none());
+        assert!(difficulty.is_none());
+    }
+
+    #[test]
+    fn test_extract_doc_header_first_occurrence_wins() {
+        let code = "/// Problem: First\n/// Difficulty: Easy\n/// Problem: Second\n";
+        let (title, _) = extract_doc_header(code);
+        assert_eq!(title, Some("First".to_string()));
+    }
+
+    #[test]
+    fn test_render_index_table() {
+        let entries = vec![IndexEntry {
+            id: 1,
+            title: "Two Sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: vec!["Array".to_string(), "Hash Table".to_string()],
+            status: "✓ Solved".to_string(),
+            complexity: "Time Complexity: O(n)".to_string(),
+            path: "src/solutions/p0001_two_sum.rs".to_string(),
+        }];
+        let rendered = render_index(&entries);
+        assert!(rendered.contains("# Solutions Index"));
+        assert!(rendered.contains("1 problems downloaded."));
+        assert!(rendered.contains("[Two Sum](src/solutions/p0001_two_sum.rs)"));
+        assert!(rendered.contains("Array, Hash Table"));
+        assert!(rendered.contains("✓ Solved"));
+    }
+
+    #[test]
+    fn test_render_index_empty() {
+        let rendered = render_index(&[]);
+        assert!(rendered.contains("0 problems downloaded."));
+        assert!(rendered.contains("| ID |"));
+    }
+}
@@ -0,0 +1,150 @@
+//! Info command - one-screen overview of a problem's local and remote state
+//!
+//! Combines what's on disk (solution path, last modified, latest local test
+//! run, notes) with what LeetCode knows (submission status, acceptance
+//! rate), so resuming work on a problem doesn't take three commands.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{find_solution_file, perf::format_date},
+    meta::ProblemMeta,
+    progress::{Progress, SolveStatus},
+};
+
+/// Show local and remote status for one problem.
+pub async fn execute(client: &LeetCodeClient, id: u32) -> Result<()> {
+    let meta = ProblemMeta::load(id)?;
+
+    println!("\n{}", "═".repeat(60).cyan());
+    match meta {
+        Some(ref meta) => println!(
+            "{} {}. {} ({})",
+            "Problem".bold(),
+            id,
+            meta.title.bold(),
+            meta.difficulty
+        ),
+        None => println!("{} {}", "Problem".bold(), id),
+    }
+    println!("{}", "═".repeat(60).cyan());
+
+    println!("{}", "Local".bold().cyan());
+    match find_solution_file(id, None) {
+        Ok(path) => {
+            println!("  {} {}", "Solution:".bold(), path.display());
+            if let Ok(modified) = path.metadata().and_then(|m| m.modified())
+                && let Ok(since_epoch) = modified.duration_since(std::time::UNIX_EPOCH)
+            {
+                println!(
+                    "  {} {}",
+                    "Modified:".bold(),
+                    format_date(since_epoch.as_secs())
+                );
+            }
+        }
+        Err(_) => println!("  {} not downloaded", "Solution:".bold()),
+    }
+
+    let progress = Progress::load()?;
+    match progress.problems.get(&id) {
+        Some(record) => {
+            let status = match record.status {
+                SolveStatus::Solved => "solved".green(),
+                SolveStatus::Attempting => "attempting".yellow(),
+            };
+            println!("  {} {}", "Status:".bold(), status);
+            if let Some(solved_at) = record.solved_at {
+                println!("  {} {}", "Solved on:".bold(), format_date(solved_at));
+            }
+            if let Some(wall_ms) = record.last_test_wall_ms {
+                let rss = record
+                    .last_test_peak_rss_kb
+                    .map(|kb| format!(", peak RSS {}", super::clean::format_bytes(kb * 1024)))
+                    .unwrap_or_default();
+                println!("  {} {wall_ms} ms{rss}", "Last test run:".bold());
+            }
+            if let Some(sample) = record.perf_history.last() {
+                println!(
+                    "  {} {} / {} on {}",
+                    "Last verdict:".bold(),
+                    sample.runtime,
+                    sample.memory,
+                    format_date(sample.at)
+                );
+            }
+        }
+        None => println!("  {} not started", "Status:".bold()),
+    }
+
+    if let Some(ref meta) = meta {
+        let notes = notes_path(id, &meta.slug);
+        if notes.exists() {
+            println!("  {} {}", "Notes:".bold(), notes.display());
+        } else {
+            println!("  {} none", "Notes:".bold());
+        }
+    }
+
+    println!("\n{}", "Remote".bold().cyan());
+    match client.get_problem_by_id(id).await {
+        Ok(Some(problem)) => {
+            println!(
+                "  {} {}",
+                "Submission status:".bold(),
+                remote_status_label(problem.status.as_deref())
+            );
+            if problem.stat.total_submitted > 0 {
+                println!(
+                    "  {} {:.1}%",
+                    "Acceptance rate:".bold(),
+                    problem.stat.total_acs as f64 / problem.stat.total_submitted as f64 * 100.0
+                );
+            }
+        }
+        Ok(None) => println!("  {}", "problem not found in the problem list".yellow()),
+        Err(e) => println!("  {}", format!("! remote status unavailable: {e:#}").yellow()),
+    }
+
+    Ok(())
+}
+
+/// Where a problem's notes file lives, as written by `writeup`/`export`.
+fn notes_path(id: u32, slug: &str) -> PathBuf {
+    PathBuf::from("notes").join(format!("p{:04}_{}.md", id, slug.replace('-', "_")))
+}
+
+/// Human label for LeetCode's per-problem submission status field.
+fn remote_status_label(status: Option<&str>) -> String {
+    match status {
+        Some("ac") => "accepted".green().to_string(),
+        Some("notac") => "attempted".yellow().to_string(),
+        _ => "not submitted".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notes_path() {
+        assert_eq!(
+            notes_path(1, "two-sum"),
+            PathBuf::from("notes/p0001_two_sum.md")
+        );
+    }
+
+    #[test]
+    fn test_remote_status_label() {
+        colored::control::set_override(false);
+        assert_eq!(remote_status_label(Some("ac")), "accepted");
+        assert_eq!(remote_status_label(Some("notac")), "attempted");
+        assert_eq!(remote_status_label(None), "not submitted");
+        colored::control::unset_override();
+    }
+}
@@ -0,0 +1,347 @@
+//! Interview command - timed mock interview session
+//!
+//! Picks one hidden problem per requested difficulty, reveals each title
+//! only when its round starts, splits the session length evenly across the
+//! remaining rounds, and prints a post-session report with the time spent
+//! and the recorded verdict per problem. While a session is live a marker
+//! file at the workspace root blocks hint and editorial reveals elsewhere
+//! in the CLI — no peeking mid-interview.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{LeetCodeClient, PickStrategy, ProblemFilter},
+    commands::{find_solution_file, pick::download_problem, send_notification},
+    problem::{DifficultyLevel, Problem},
+    progress::Progress,
+};
+
+const SESSION_FILE: &str = ".interview.json";
+/// How often to re-roll before giving up on finding an unseen problem.
+const PICK_ATTEMPTS: u32 = 20;
+
+/// Run a mock interview: hidden picks, a hard clock, and a report.
+pub async fn execute(
+    client: &LeetCodeClient,
+    length: Option<String>,
+    difficulty: String,
+) -> Result<()> {
+    let total = match length {
+        Some(ref spec) => crate::commands::solve::parse_timebox(spec)
+            .ok_or_else(|| anyhow::anyhow!("invalid length '{spec}': expected e.g. 45m, 1h"))?,
+        None => Duration::from_secs(45 * 60),
+    };
+    let levels = parse_difficulties(&difficulty)?;
+
+    // Pick everything up front so the clock never waits on the network,
+    // but keep the titles hidden until each round starts
+    let progress = Progress::load()?;
+    let mut seen: HashSet<u32> = crate::meta::ProblemMeta::load_all()?
+        .iter()
+        .map(|m| m.frontend_id)
+        .chain(progress.problems.keys().copied())
+        .collect();
+    let mut problems = Vec::new();
+    for level in &levels {
+        let problem = pick_unseen(client, *level, &seen).await?;
+        seen.insert(problem.stat.frontend_question_id);
+        problems.push(problem);
+    }
+    println!(
+        "{}",
+        format!(
+            "✓ {} problem(s) selected — titles stay hidden until each round starts",
+            problems.len()
+        )
+        .green()
+    );
+    println!(
+        "{}",
+        format!(
+            "⏱ Interview started: {} on the clock. Hints and the editorial \
+             are disabled until it ends.",
+            crate::commands::solve::format_duration(total)
+        )
+        .bold()
+        .cyan()
+    );
+
+    let started = Instant::now();
+    begin(crate::progress::now_ts() + total.as_secs())?;
+    let before = progress;
+    let mut rounds = Vec::new();
+    for (i, problem) in problems.iter().enumerate() {
+        let remaining = total.saturating_sub(started.elapsed());
+        if remaining.is_zero() {
+            rounds.push((problem, Duration::ZERO, true));
+            continue;
+        }
+        // Split what's left evenly across the remaining rounds
+        let budget = remaining / (problems.len() - i) as u32;
+        println!(
+            "\n{} {} [{}] — {} budgeted",
+            format!("Round {}/{}:", i + 1, problems.len()).bold(),
+            problem.stat.question_title(),
+            level_label(problem.difficulty.level),
+            crate::commands::solve::format_duration(budget)
+        );
+
+        // Download the problem if there is no local solution file yet
+        let id = problem.stat.frontend_question_id;
+        let solution_file = match find_solution_file(id, None) {
+            Ok(file) => file,
+            Err(_) => {
+                download_problem(client, problem).await?;
+                find_solution_file(id, None)?
+            }
+        };
+        let editor = crate::config::Config::load()?.get_editor();
+        std::process::Command::new(&editor)
+            .arg(&solution_file)
+            .spawn()?;
+
+        println!("  Test and submit from another terminal; press Enter here when done.");
+        // Persist the round so `resume` can restore the clock if the
+        // terminal dies mid-interview
+        crate::session::Session::start(
+            "interview",
+            id,
+            crate::progress::now_ts() + budget.as_secs(),
+        )
+        .save()?;
+        let round_start = Instant::now();
+        let timed_out = tokio::select! {
+            _ = tokio::time::sleep(budget) => true,
+            _ = wait_for_enter() => false,
+        };
+        if timed_out {
+            println!("{}", "⏰ Time's up for this round.".yellow().bold());
+            send_notification(
+                "leetcode-cli",
+                &format!("Interview round {} is over", i + 1),
+            );
+        }
+        rounds.push((problem, round_start.elapsed(), timed_out));
+    }
+    end()?;
+    crate::session::Session::clear()?;
+
+    // The verdicts come from whatever the other terminal recorded
+    let after = Progress::load()?;
+    println!("\n{}", "Interview report".bold());
+    for (problem, spent, timed_out) in &rounds {
+        let id = problem.stat.frontend_question_id;
+        println!(
+            "  {:>5} {} [{}] — {} — {}",
+            id,
+            problem.stat.question_title(),
+            level_label(problem.difficulty.level),
+            if *timed_out && spent.is_zero() {
+                "skipped (clock ran out)".to_string()
+            } else {
+                crate::commands::solve::format_duration(*spent)
+            },
+            verdict(&before, &after, id)
+        );
+    }
+    println!(
+        "Total: {} of {}",
+        crate::commands::solve::format_duration(started.elapsed().min(total)),
+        crate::commands::solve::format_duration(total)
+    );
+    Ok(())
+}
+
+/// Block until the user presses Enter.
+async fn wait_for_enter() {
+    use tokio::io::AsyncBufReadExt;
+    let mut line = String::new();
+    let _ = tokio::io::BufReader::new(tokio::io::stdin())
+        .read_line(&mut line)
+        .await;
+}
+
+/// A random free problem at the given difficulty that the workspace has
+/// never seen.
+async fn pick_unseen(
+    client: &LeetCodeClient,
+    level: DifficultyLevel,
+    seen: &HashSet<u32>,
+) -> Result<Problem> {
+    let name = level.name().to_lowercase();
+    let filter = ProblemFilter::new()
+        .difficulty(Some(&name))
+        .paid(Some(false));
+    for _ in 0..PICK_ATTEMPTS {
+        if let Some(problem) = client
+            .get_random_problem_filtered(&filter, None, PickStrategy::Uniform)
+            .await?
+            && !seen.contains(&problem.stat.frontend_question_id)
+        {
+            return Ok(problem);
+        }
+    }
+    anyhow::bail!(
+        "couldn't find an unseen free {} problem to interview with",
+        level.name()
+    )
+}
+
+/// The comma-separated difficulty list, e.g. "medium,medium" — one entry
+/// per interview round.
+pub(crate) fn parse_difficulties(spec: &str) -> Result<Vec<DifficultyLevel>> {
+    let levels: Vec<DifficultyLevel> = spec
+        .split(',')
+        .map(|part| {
+            part.trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("unknown difficulty '{}'", part.trim()))
+        })
+        .collect::<Result<_>>()?;
+    if levels.is_empty() {
+        anyhow::bail!("at least one difficulty is required, e.g. --difficulty medium,medium");
+    }
+    Ok(levels)
+}
+
+/// The verdict for one round, read from the attempt counters the test and
+/// submit commands recorded during the session.
+pub(crate) fn verdict(before: &Progress, after: &Progress, id: u32) -> String {
+    if after.is_solved(id) && !before.is_solved(id) {
+        return "solved ✓".green().to_string();
+    }
+    let attempts = after.attempts(id).saturating_sub(before.attempts(id));
+    if attempts > 0 {
+        format!(
+            "attempted ({attempts} {})",
+            if attempts == 1 { "attempt" } else { "attempts" }
+        )
+        .yellow()
+        .to_string()
+    } else {
+        "no attempt recorded".to_string()
+    }
+}
+
+/// The display name of a numeric difficulty level.
+fn level_label(level: i32) -> &'static str {
+    match DifficultyLevel::try_from(level) {
+        Ok(level) => level.name(),
+        Err(_) => "Unknown",
+    }
+}
+
+/// The live-session marker, so other commands know hints are off-limits.
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionMarker {
+    /// Unix timestamp the session ends at; a stale marker from a crashed
+    /// session stops blocking hints once this passes.
+    deadline: u64,
+}
+
+fn session_path(root: &Path) -> PathBuf {
+    root.join(SESSION_FILE)
+}
+
+/// Mark an interview session as live until `deadline`.
+fn begin(deadline: u64) -> Result<()> {
+    begin_in(Path::new(""), deadline)
+}
+
+fn begin_in(root: &Path, deadline: u64) -> Result<()> {
+    let marker = SessionMarker { deadline };
+    std::fs::write(session_path(root), serde_json::to_string(&marker)?)?;
+    Ok(())
+}
+
+/// Clear the session marker.
+fn end() -> Result<()> {
+    end_in(Path::new(""))
+}
+
+fn end_in(root: &Path) -> Result<()> {
+    let path = session_path(root);
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Whether hint and editorial reveals are blocked by a live interview
+/// session in the current directory.
+pub(crate) fn hints_blocked() -> bool {
+    hints_blocked_in(Path::new(""), crate::progress::now_ts())
+}
+
+fn hints_blocked_in(root: &Path, now: u64) -> bool {
+    let Ok(content) = std::fs::read_to_string(session_path(root)) else {
+        return false;
+    };
+    serde_json::from_str::<SessionMarker>(&content).is_ok_and(|marker| marker.deadline > now)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::progress::SolveStatus;
+
+    #[test]
+    fn test_parse_difficulties() {
+        assert_eq!(
+            parse_difficulties("medium,medium").unwrap(),
+            vec![DifficultyLevel::Medium, DifficultyLevel::Medium]
+        );
+        assert_eq!(
+            parse_difficulties(" Easy , hard ").unwrap(),
+            vec![DifficultyLevel::Easy, DifficultyLevel::Hard]
+        );
+        assert!(parse_difficulties("medium,extreme").is_err());
+        assert!(parse_difficulties("").is_err());
+    }
+
+    #[test]
+    fn test_verdict_from_progress_delta() {
+        colored::control::set_override(false);
+        let mut before = Progress::default();
+        before.record_submission(3, "slug-3", false);
+
+        let mut after = before.clone();
+        after.record_submission(1, "slug-1", true);
+        after.record(1, "slug-1", SolveStatus::Solved, "submit");
+        after.record_test_failure(2, "slug-2");
+        after.record_test_failure(2, "slug-2");
+
+        assert_eq!(verdict(&before, &after, 1), "solved ✓");
+        assert_eq!(verdict(&before, &after, 2), "attempted (2 attempts)");
+        // Attempts from before the session don't count
+        assert_eq!(verdict(&before, &after, 3), "no attempt recorded");
+        assert_eq!(verdict(&before, &after, 99), "no attempt recorded");
+        colored::control::unset_override();
+    }
+
+    #[test]
+    fn test_session_marker_lifecycle() {
+        let dir = TempDir::new().unwrap();
+        assert!(!hints_blocked_in(dir.path(), 100));
+
+        begin_in(dir.path(), 200).unwrap();
+        assert!(hints_blocked_in(dir.path(), 100));
+        // A stale marker stops blocking once the deadline passes
+        assert!(!hints_blocked_in(dir.path(), 201));
+
+        end_in(dir.path()).unwrap();
+        assert!(!hints_blocked_in(dir.path(), 100));
+        // Ending twice is fine
+        end_in(dir.path()).unwrap();
+    }
+}
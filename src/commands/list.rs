@@ -3,75 +3,261 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::{api::LeetCodeClient, problem::DifficultyLevel};
+use crate::{
+    api::{LeetCodeClient, ProblemFilter},
+    meta::ProblemMeta,
+    problem::{DifficultyLevel, Problem},
+    table::{Cell, Table},
+};
+
+/// A column of the `list` table, selectable with `--columns` or the
+/// `list_columns` config key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListColumn {
+    Id,
+    Title,
+    Difficulty,
+    Acceptance,
+    Frequency,
+    Tags,
+    Paid,
+    Status,
+}
+
+impl ListColumn {
+    const DEFAULT: &[Self] = &[Self::Id, Self::Title, Self::Difficulty, Self::Status];
+
+    fn header(self) -> &'static str {
+        match self {
+            Self::Id => "ID",
+            Self::Title => "Title",
+            Self::Difficulty => "Difficulty",
+            Self::Acceptance => "Acceptance",
+            Self::Frequency => "Frequency",
+            Self::Tags => "Tags",
+            Self::Paid => "Paid",
+            Self::Status => "Status",
+        }
+    }
+
+    fn parse(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "id" => Ok(Self::Id),
+            "title" => Ok(Self::Title),
+            "difficulty" => Ok(Self::Difficulty),
+            "acceptance" => Ok(Self::Acceptance),
+            "frequency" => Ok(Self::Frequency),
+            "tags" => Ok(Self::Tags),
+            "paid" => Ok(Self::Paid),
+            "status" => Ok(Self::Status),
+            other => anyhow::bail!(
+                "unknown column '{other}': expected id, title, difficulty, acceptance, \
+                 frequency, tags, paid, or status"
+            ),
+        }
+    }
+}
+
+/// Parse a comma-separated column spec like "id,title,acceptance".
+pub(crate) fn parse_columns(spec: &str) -> Result<Vec<ListColumn>> {
+    let columns: Vec<ListColumn> = spec
+        .split(',')
+        .filter(|s| !s.trim().is_empty())
+        .map(ListColumn::parse)
+        .collect::<Result<_>>()?;
+    if columns.is_empty() {
+        anyhow::bail!("empty column spec");
+    }
+    Ok(columns)
+}
+
+fn render_cell(column: ListColumn, problem: &Problem) -> Cell {
+    match column {
+        ListColumn::Id => Cell::new(problem.stat.frontend_question_id.to_string()),
+        ListColumn::Title => {
+            // Mark premium-only problems with a lock
+            let lock = if problem.paid_only { " 🔒" } else { "" };
+            Cell::new(format!("{}{lock}", problem.stat.question_title()))
+        }
+        ListColumn::Difficulty => match DifficultyLevel::try_from(problem.difficulty.level) {
+            Ok(DifficultyLevel::Easy) => Cell::painted("Easy", "Easy".green().to_string()),
+            Ok(DifficultyLevel::Medium) => Cell::painted("Medium", "Medium".yellow().to_string()),
+            Ok(DifficultyLevel::Hard) => Cell::painted("Hard", "Hard".red().to_string()),
+            Err(_) => Cell::new("Unknown"),
+        },
+        ListColumn::Acceptance => Cell::new(format_acceptance(
+            problem.stat.total_acs,
+            problem.stat.total_submitted,
+        )),
+        ListColumn::Frequency => Cell::new(problem.frequency.to_string()),
+        ListColumn::Tags => {
+            // Tags aren't part of the problem list endpoint; show what the
+            // local metadata has for downloaded problems
+            let tags = ProblemMeta::load(problem.stat.frontend_question_id)
+                .ok()
+                .flatten()
+                .map(|meta| meta.tags.join(","))
+                .unwrap_or_default();
+            Cell::new(tags)
+        }
+        ListColumn::Paid => {
+            if problem.paid_only {
+                Cell::new("Yes")
+            } else {
+                Cell::new("")
+            }
+        }
+        ListColumn::Status => {
+            if problem.status == Some("ac".to_string()) {
+                Cell::painted("✓ Solved", "✓ Solved".green().to_string())
+            } else if problem.status == Some("notac".to_string()) {
+                Cell::painted("~ Trying", "~ Trying".yellow().to_string())
+            } else {
+                Cell::new("○ New")
+            }
+        }
+    }
+}
 
-/// List all problems
+/// Acceptance rate as a percentage, or "-" when nothing was submitted.
+fn format_acceptance(total_acs: i64, total_submitted: i64) -> String {
+    if total_submitted <= 0 {
+        return "-".to_string();
+    }
+    format!("{:.1}%", total_acs as f64 / total_submitted as f64 * 100.0)
+}
+
+/// List all problems matching a filter, or multi-select and download them
 pub async fn execute(
     client: &LeetCodeClient,
-    difficulty: Option<String>,
-    status: Option<String>,
+    filter: &ProblemFilter,
+    columns: Option<&str>,
+    tag: Option<&str>,
+    select: bool,
+    limit: Option<usize>,
 ) -> Result<()> {
     println!("{}", "Fetching problem list...".cyan());
 
-    let problems = client.get_all_problems().await?;
+    // CLI flag, then the configured default, then the built-in set
+    let columns = match columns
+        .map(str::to_string)
+        .or(crate::config::Config::load()?.list_columns)
+    {
+        Some(spec) => parse_columns(&spec)?,
+        None => ListColumn::DEFAULT.to_vec(),
+    };
+
+    // With a row limit and no tag/select (which need the full set anyway),
+    // pull GraphQL pages lazily instead of the whole problem list
+    let paged;
+    let problems;
+    let mut matching: Vec<&Problem>;
+    if let Some(n) = limit
+        && tag.is_none()
+        && !select
+    {
+        paged = fetch_until(client, filter, n).await?;
+        matching = paged.iter().collect();
+    } else {
+        problems = client.get_all_problems().await?;
+        matching = problems.iter().filter(|p| filter.matches(p)).collect();
+        if let Some(tag) = tag {
+            matching = client.filter_problems_by_tag(&matching, tag).await?;
+        }
+        if let Some(n) = limit {
+            matching.truncate(n);
+        }
+    }
 
-    println!(
-        "\n{:<6} {:<50} {:<10} {:<10}",
-        "ID", "Title", "Difficulty", "Status"
-    );
-    println!("{}", "-".repeat(80));
-
-    for problem in problems.iter() {
-        let diff_str = match DifficultyLevel::try_from(problem.difficulty.level) {
-            Ok(DifficultyLevel::Easy) => "Easy".green(),
-            Ok(DifficultyLevel::Medium) => "Medium".yellow(),
-            Ok(DifficultyLevel::Hard) => "Hard".red(),
-            Err(_) => "Unknown".normal(),
-        };
+    if select {
+        return select_and_download(client, &matching).await;
+    }
 
-        let status_str = if problem.status == Some("ac".to_string()) {
-            "✓ Solved".green()
-        } else if problem.status == Some("notac".to_string()) {
-            "~ Trying".yellow()
-        } else {
-            "○ New".normal()
-        };
+    println!();
+    let headers: Vec<&str> = columns.iter().map(|c| c.header()).collect();
+    let mut table = Table::new(&headers);
+    if let Some(flex) = columns.iter().position(|c| *c == ListColumn::Title) {
+        table = table.flexible(flex);
+    }
 
-        if let Some(ref diff_filter) = difficulty
-            && let Ok(level) = diff_filter.parse::<DifficultyLevel>()
-            && problem.difficulty.level != level.level()
-        {
-            continue;
-        }
-        if let Some(ref status_filter) = status {
-            let should_show = match status_filter.to_lowercase().as_str() {
-                "solved" => problem.status == Some("ac".to_string()),
-                "attempting" => problem.status == Some("notac".to_string()),
-                "unsolved" => problem.status.is_none(),
-                _ => true,
-            };
-            if !should_show {
-                continue;
-            }
+    for problem in matching {
+        table.add_row(columns.iter().map(|c| render_cell(*c, problem)).collect());
+    }
+
+    table.print();
+
+    Ok(())
+}
+
+/// Fetch problem-set pages until `limit` rows match the filter (or the set
+/// is exhausted), so small listings never deserialize the full catalogue.
+async fn fetch_until(
+    client: &LeetCodeClient,
+    filter: &ProblemFilter,
+    limit: usize,
+) -> Result<Vec<Problem>> {
+    const PAGE: u64 = 100;
+    let mut matching = Vec::new();
+    let mut skip = 0;
+    loop {
+        let (page, total) = client.get_problems_page(skip, PAGE).await?;
+        let fetched = page.len() as u64;
+        matching.extend(page.into_iter().filter(|p| filter.matches(p)));
+        skip += fetched;
+        if matching.len() >= limit || fetched == 0 || skip >= total {
+            matching.truncate(limit);
+            return Ok(matching);
         }
+    }
+}
 
+/// Present a checkbox picker over the filtered problems and download every
+/// selection. Paid-only problems are left out — they can't be downloaded.
+async fn select_and_download(client: &LeetCodeClient, problems: &[&Problem]) -> Result<()> {
+    let free: Vec<&Problem> = problems.iter().filter(|p| !p.paid_only).copied().collect();
+    if free.is_empty() {
         println!(
-            "{:<6} {:<50} {:<10} {:<10}",
-            problem.stat.frontend_question_id,
-            problem
-                .stat
-                .question_title()
-                .chars()
-                .take(48)
-                .collect::<String>(),
-            diff_str,
-            status_str
+            "{}",
+            "No downloadable problems matched the filter.".yellow()
         );
+        return Ok(());
+    }
+
+    let labels: Vec<String> = free.iter().map(|p| select_label(p)).collect();
+    let picked = dialoguer::MultiSelect::new()
+        .with_prompt("Select problems to download (space toggles, enter confirms)")
+        .items(&labels)
+        .interact()?;
+    if picked.is_empty() {
+        println!("{}", "Nothing selected.".yellow());
+        return Ok(());
     }
 
+    for index in &picked {
+        crate::commands::pick::download_problem(client, free[*index]).await?;
+    }
+    println!(
+        "{}",
+        format!("✓ Downloaded {} problem(s)", picked.len()).green()
+    );
     Ok(())
 }
 
+/// The label of one checkbox entry, e.g. "1. Two Sum (Easy)".
+fn select_label(problem: &Problem) -> String {
+    let difficulty = match DifficultyLevel::try_from(problem.difficulty.level) {
+        Ok(DifficultyLevel::Easy) => "Easy",
+        Ok(DifficultyLevel::Medium) => "Medium",
+        Ok(DifficultyLevel::Hard) => "Hard",
+        Err(_) => "Unknown",
+    };
+    format!(
+        "{}. {} ({difficulty})",
+        problem.stat.frontend_question_id,
+        problem.stat.question_title()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +286,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_columns() {
+        let columns = parse_columns("id, title,acceptance").unwrap();
+        assert_eq!(
+            columns,
+            vec![ListColumn::Id, ListColumn::Title, ListColumn::Acceptance]
+        );
+        assert!(parse_columns("id,bogus").is_err());
+        assert!(parse_columns("").is_err());
+    }
+
+    #[test]
+    fn test_format_acceptance() {
+        assert_eq!(format_acceptance(1000, 2000), "50.0%");
+        assert_eq!(format_acceptance(541, 1000), "54.1%");
+        assert_eq!(format_acceptance(0, 0), "-");
+    }
+
+    #[test]
+    fn test_render_cell_acceptance_and_paid() {
+        let mut problem = create_test_problem(1, "Two Sum", 1, Some("ac"));
+        problem.paid_only = true;
+        let table_cell = |col| {
+            let mut t = Table::new(&["x"]);
+            t.add_row(vec![render_cell(col, &problem)]);
+            t.render(200)
+        };
+        assert!(table_cell(ListColumn::Acceptance).contains("50.0%"));
+        assert!(table_cell(ListColumn::Paid).contains("Yes"));
+        assert!(table_cell(ListColumn::Title).contains("🔒"));
+    }
+
     #[test]
     fn test_difficulty_level_try_from() {
         assert_eq!(DifficultyLevel::try_from(1).unwrap(), DifficultyLevel::Easy);
@@ -262,7 +480,7 @@ mod tests {
             .unwrap();
 
         // Test execute without filters
-        let result = execute(&client, None, None).await;
+        let result = execute(&client, &ProblemFilter::new(), None, None, false, None).await;
         assert!(result.is_ok());
     }
 
@@ -340,7 +558,8 @@ mod tests {
             .unwrap();
 
         // Test with difficulty filter
-        let result = execute(&client, Some("easy".to_string()), None).await;
+        let filter = ProblemFilter::new().difficulty(Some("easy"));
+        let result = execute(&client, &filter, None, None, false, None).await;
         assert!(result.is_ok());
     }
 
@@ -438,13 +657,10 @@ mod tests {
             .unwrap();
 
         // Test with different status filters
-        let result_solved = execute(&client, None, Some("solved".to_string())).await;
-        assert!(result_solved.is_ok());
-
-        let result_attempting = execute(&client, None, Some("attempting".to_string())).await;
-        assert!(result_attempting.is_ok());
-
-        let result_unsolved = execute(&client, None, Some("unsolved".to_string())).await;
-        assert!(result_unsolved.is_ok());
+        for status in ["solved", "attempting", "unsolved"] {
+            let filter = ProblemFilter::new().status(Some(status));
+            let result = execute(&client, &filter, None, None, false, None).await;
+            assert!(result.is_ok());
+        }
     }
 }
@@ -0,0 +1,195 @@
+//! List-mgmt command - Maintain named problem lists
+//!
+//! Creates, shows, and deletes the named lists consumed by `pick --list`
+//! and `list --list`. Lists can be given inline with `--ids` or imported
+//! from a file or URL containing IDs in any text format.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    lists::{ProblemLists, parse_ids, parse_list_source},
+    progress::Progress,
+};
+
+/// Create or replace a named list from inline IDs and/or an imported source
+pub async fn create(name: &str, ids: &[u32], from: Option<&str>) -> Result<()> {
+    let mut all_ids = ids.to_vec();
+    if let Some(source) = from {
+        all_ids.extend(parse_ids(&fetch_source(source).await?));
+    }
+    if all_ids.is_empty() {
+        anyhow::bail!("no problem IDs given: use --ids and/or --from");
+    }
+
+    let mut lists = ProblemLists::load()?;
+    let replaced = lists.lists.contains_key(name);
+    lists.set(name, all_ids);
+    let count = lists.get(name)?.len();
+    lists.save()?;
+
+    let verb = if replaced { "Replaced" } else { "Created" };
+    println!(
+        "{}",
+        format!("✓ {verb} list '{name}' with {count} problems").green()
+    );
+    Ok(())
+}
+
+/// Import a curated problem set from a URL or file in one of the common
+/// published formats (JSON array of IDs or objects, markdown, plain text)
+pub async fn import(name: &str, from: &str) -> Result<()> {
+    let ids = parse_list_source(&fetch_source(from).await?);
+    if ids.is_empty() {
+        anyhow::bail!("no problem IDs found in {from}");
+    }
+
+    let mut lists = ProblemLists::load()?;
+    lists.set(name, ids);
+    let count = lists.get(name)?.len();
+    lists.save()?;
+
+    println!(
+        "{}",
+        format!("✓ Imported list '{name}' with {count} problems from {from}").green()
+    );
+    Ok(())
+}
+
+/// How many of a list's problems are solved, per the progress database.
+fn solved_count(ids: &[u32], progress: &Progress) -> usize {
+    ids.iter().filter(|id| progress.is_solved(**id)).count()
+}
+
+/// Print the names, sizes, and completion of all lists
+pub async fn list() -> Result<()> {
+    let lists = ProblemLists::load()?;
+    if lists.lists.is_empty() {
+        println!("No lists defined yet; create one with 'leetcode-cli list-mgmt create'");
+        return Ok(());
+    }
+    let progress = Progress::load()?;
+    for (name, ids) in &lists.lists {
+        println!(
+            "{} ({}/{} complete)",
+            name.bold(),
+            solved_count(ids, &progress),
+            ids.len()
+        );
+    }
+    Ok(())
+}
+
+/// Print the IDs and completion of one list
+pub async fn show(name: &str) -> Result<()> {
+    let lists = ProblemLists::load()?;
+    let ids = lists.get(name)?;
+    let progress = Progress::load()?;
+    println!(
+        "{} ({}/{} complete)",
+        name.bold(),
+        solved_count(ids, &progress),
+        ids.len()
+    );
+    println!(
+        "{}",
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(())
+}
+
+/// Delete a named list
+pub async fn delete(name: &str) -> Result<()> {
+    let mut lists = ProblemLists::load()?;
+    if !lists.remove(name) {
+        anyhow::bail!("no list named '{name}'");
+    }
+    lists.save()?;
+    println!("{}", format!("✓ Deleted list '{name}'").green());
+    Ok(())
+}
+
+/// Read an import source: a URL is fetched, anything else is a file path.
+async fn fetch_source(source: &str) -> Result<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let response = reqwest::get(source).await?;
+        if !response.status().is_success() {
+            anyhow::bail!("failed to fetch {source}: HTTP {}", response.status());
+        }
+        Ok(response.text().await?)
+    } else {
+        std::fs::read_to_string(source)
+            .map_err(|e| anyhow::anyhow!("failed to read {source}: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::commands::TestDirGuard;
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_create_show_delete_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        create("blind75", &[217, 1, 53], None).await.unwrap();
+        show("blind75").await.unwrap();
+        list().await.unwrap();
+        delete("blind75").await.unwrap();
+        assert!(delete("blind75").await.is_err());
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_create_from_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        std::fs::write("ids.txt", "1\n53\n217\n").unwrap();
+        create("imported", &[], Some("ids.txt")).await.unwrap();
+
+        let lists = ProblemLists::load().unwrap();
+        assert_eq!(lists.get("imported").unwrap(), &[1, 53, 217]);
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_import_json_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        std::fs::write(
+            "set.json",
+            r#"[{"frontendQuestionId": "1"}, {"frontendQuestionId": "53"}]"#,
+        )
+        .unwrap();
+        import("neetcode", "set.json").await.unwrap();
+
+        let lists = ProblemLists::load().unwrap();
+        assert_eq!(lists.get("neetcode").unwrap(), &[1, 53]);
+    }
+
+    #[test]
+    fn test_solved_count() {
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", crate::progress::SolveStatus::Solved, "submit");
+        assert_eq!(solved_count(&[1, 53, 217], &progress), 1);
+        assert_eq!(solved_count(&[], &progress), 0);
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_create_requires_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        assert!(create("empty", &[], None).await.is_err());
+    }
+}
@@ -1,33 +1,177 @@
 //! Login command - Save LeetCode credentials
+//!
+//! An interactive wizard rather than two bare prompts: pasted cookies are
+//! masked, accidentally copied `LEETCODE_SESSION=`/quote wrappers are
+//! trimmed off, and the credentials are validated against LeetCode before
+//! anything is written to the config file.
 
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::{commands::prompt_input, config::Config};
+use crate::config::Config;
 
 /// Login to LeetCode
 pub async fn execute(session: Option<String>, csrf: Option<String>) -> Result<()> {
-    let mut config = Config::load()?;
+    // The raw file, so login doesn't prompt for a passphrase just to
+    // replace encrypted credentials it would overwrite anyway
+    let mut config = Config::load_file()?;
 
-    if let Some(s) = session {
-        config.session_cookie = Some(s);
-    } else {
-        config.session_cookie = Some(prompt_input("Please enter your LeetCode session cookie:")?);
+    let session = match session {
+        Some(s) => sanitize_credential(&s),
+        None => sanitize_credential(&prompt_masked("Paste your LeetCode session cookie:")?),
+    };
+    if session.is_empty() {
+        anyhow::bail!("session cookie cannot be empty");
     }
+    config.session_cookie = Some(session);
 
     if let Some(c) = csrf {
+        let c = sanitize_credential(&c);
+        if c.is_empty() {
+            anyhow::bail!("CSRF token cannot be empty");
+        }
         config.csrf_token = Some(c);
     } else {
-        config.csrf_token = Some(prompt_input("Please enter your CSRF token:")?);
+        // LeetCode rotates the csrftoken cookie on every response, so one
+        // request with the session cookie yields a matching token
+        let endpoint = config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| "https://leetcode.com".to_string());
+        let session = config.session_cookie.as_deref().unwrap_or_default();
+        println!("{}", "Deriving CSRF token from the session...".cyan());
+        match fetch_csrf_token(&endpoint, session).await {
+            Ok(token) => {
+                println!("{}", "✓ CSRF token derived automatically".green());
+                config.csrf_token = Some(token);
+            }
+            Err(e) => {
+                println!(
+                    "{}",
+                    format!("! could not derive the CSRF token ({e})").yellow()
+                );
+                let token = sanitize_credential(&prompt_masked("Paste your CSRF token:")?);
+                if token.is_empty() {
+                    anyhow::bail!("CSRF token cannot be empty");
+                }
+                config.csrf_token = Some(token);
+            }
+        }
     }
 
+    println!("{}", "Validating credentials...".cyan());
+    let username = whoami(
+        config
+            .endpoint
+            .as_deref()
+            .unwrap_or("https://leetcode.com"),
+        config.session_cookie.as_deref().unwrap_or_default(),
+        config.csrf_token.as_deref().unwrap_or_default(),
+    )
+    .await?;
+
     config.save()?;
-    println!("{}", "✓ Login credentials saved successfully!".green());
+    println!(
+        "{}",
+        format!("✓ Logged in as {username}; credentials saved").green()
+    );
     println!("{}", "You can now submit solutions to LeetCode.".green());
 
     Ok(())
 }
 
+/// Prompt for a credential without echoing it back to the terminal.
+fn prompt_masked(label: &str) -> Result<String> {
+    Ok(rpassword::prompt_password(format!("{label} "))?)
+}
+
+/// Strip the wrappers people copy along with a cookie value: surrounding
+/// whitespace and quotes, a `name=` prefix, and a trailing `;`.
+fn sanitize_credential(raw: &str) -> String {
+    let mut value = raw.trim();
+    value = value.trim_matches(|c| c == '"' || c == '\'');
+    for prefix in ["LEETCODE_SESSION=", "csrftoken="] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            value = rest;
+        }
+    }
+    value.trim_end_matches(';').trim().to_string()
+}
+
+/// Check the credentials against LeetCode and return the account's username.
+async fn whoami(endpoint: &str, session: &str, csrf: &str) -> Result<String> {
+    let client = reqwest::Client::builder()
+        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+        .build()?;
+    let query = serde_json::json!({
+        "query": "query globalData { userStatus { username isSignedIn } }",
+        "variables": {}
+    });
+    let response = client
+        .post(format!("{endpoint}/graphql"))
+        .header(
+            reqwest::header::COOKIE,
+            format!("LEETCODE_SESSION={session}; csrftoken={csrf}"),
+        )
+        .header("x-csrftoken", csrf)
+        .header(reqwest::header::REFERER, format!("{endpoint}/"))
+        .json(&query)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("credential check failed: HTTP {}", response.status());
+    }
+    let body: serde_json::Value = response.json().await?;
+    let status = &body["data"]["userStatus"];
+    if status["isSignedIn"].as_bool() != Some(true) {
+        anyhow::bail!("LeetCode rejected the credentials; the session cookie may have expired");
+    }
+    status["username"]
+        .as_str()
+        .filter(|u| !u.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("signed in, but LeetCode returned no username"))
+}
+
+/// Fetch a fresh `csrftoken` cookie by making one request with the session
+/// cookie attached.
+async fn fetch_csrf_token(endpoint: &str, session: &str) -> Result<String> {
+    let client = reqwest::Client::builder()
+        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+        .build()?;
+    let response = client
+        .get(endpoint)
+        .header(
+            reqwest::header::COOKIE,
+            format!("LEETCODE_SESSION={session}"),
+        )
+        .send()
+        .await?;
+
+    let set_cookies: Vec<&str> = response
+        .headers()
+        .get_all(reqwest::header::SET_COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+    parse_csrf_cookie(set_cookies)
+        .ok_or_else(|| anyhow::anyhow!("no csrftoken cookie in the response from {endpoint}"))
+}
+
+/// Extract the `csrftoken` value from a list of `Set-Cookie` header values.
+fn parse_csrf_cookie<'a>(set_cookies: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    for cookie in set_cookies {
+        if let Some(rest) = cookie.trim_start().strip_prefix("csrftoken=") {
+            let value = rest.split(';').next().unwrap_or_default().trim();
+            if !value.is_empty() {
+                return Some(value.to_string());
+            }
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,6 +290,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sanitize_credential_plain() {
+        assert_eq!(sanitize_credential("abc123"), "abc123");
+    }
+
+    #[test]
+    fn test_sanitize_credential_copied_pair() {
+        assert_eq!(sanitize_credential("LEETCODE_SESSION=abc123;"), "abc123");
+        assert_eq!(sanitize_credential("csrftoken=xyz789"), "xyz789");
+    }
+
+    #[test]
+    fn test_sanitize_credential_quotes_and_whitespace() {
+        assert_eq!(sanitize_credential("  \"abc123\"  "), "abc123");
+        assert_eq!(sanitize_credential("'LEETCODE_SESSION=abc123'"), "abc123");
+    }
+
+    #[test]
+    fn test_sanitize_credential_empty() {
+        assert_eq!(sanitize_credential("  \"\" "), "");
+    }
+
+    #[test]
+    fn test_parse_csrf_cookie_with_attributes() {
+        let cookies = ["csrftoken=abc123; Max-Age=31449600; Path=/; Secure"];
+        assert_eq!(parse_csrf_cookie(cookies), Some("abc123".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csrf_cookie_among_others() {
+        let cookies = [
+            "LEETCODE_SESSION=sess; Path=/; HttpOnly",
+            "csrftoken=xyz789; Path=/",
+        ];
+        assert_eq!(parse_csrf_cookie(cookies), Some("xyz789".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csrf_cookie_missing() {
+        let cookies = ["LEETCODE_SESSION=sess; Path=/"];
+        assert_eq!(parse_csrf_cookie(cookies), None);
+    }
+
+    #[test]
+    fn test_parse_csrf_cookie_empty_value() {
+        let cookies = ["csrftoken=; Max-Age=0; Path=/"];
+        assert_eq!(parse_csrf_cookie(cookies), None);
+    }
+
     #[test]
     fn test_csrf_token_formats() {
         // Test various CSRF token formats
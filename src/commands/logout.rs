@@ -0,0 +1,44 @@
+//! Logout command - Scrub stored LeetCode credentials
+//!
+//! The reverse of `login`: removes the session cookie and CSRF token from
+//! the config file and confirms the scrub, instead of leaving users to
+//! edit the TOML by hand. Works on encrypted credentials too, since
+//! removing them needs no passphrase.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::{Config, get_config_path};
+
+/// Remove the stored session cookie and CSRF token
+pub async fn execute() -> Result<()> {
+    let mut config = Config::load_file()?;
+    if config.session_cookie.is_none() && config.csrf_token.is_none() {
+        println!("{}", "✓ No stored credentials; already logged out".green());
+        return Ok(());
+    }
+
+    config.session_cookie = None;
+    config.csrf_token = None;
+    config.save()?;
+
+    // Confirm the scrub actually reached the file
+    let reloaded = Config::load_file()?;
+    if reloaded.session_cookie.is_some() || reloaded.csrf_token.is_some() {
+        anyhow::bail!(
+            "failed to scrub credentials from {}",
+            get_config_path()?.display()
+        );
+    }
+
+    println!(
+        "{}",
+        format!(
+            "✓ Logged out: credentials removed from {}",
+            get_config_path()?.display()
+        )
+        .green()
+        .bold()
+    );
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Migrate command - Backfill metadata for legacy solution files
+//!
+//! Solutions downloaded before per-problem metadata existed have no entry
+//! under `src/solutions/meta/`. This command re-fetches their details and
+//! writes the metadata and statement snapshot, so path resolution no longer
+//! needs the legacy file-name prefix scanning.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{api::LeetCodeClient, commands::list_local_solutions, meta::ProblemMeta};
+
+/// Migrate legacy solution files to the metadata-backed layout
+pub async fn execute(client: &LeetCodeClient) -> Result<()> {
+    let solutions = list_local_solutions()?;
+    if solutions.is_empty() {
+        println!("{}", "No local solutions found in src/solutions/.".yellow());
+        return Ok(());
+    }
+
+    let mut migrated = 0;
+    let mut failed = 0;
+    for solution in &solutions {
+        if ProblemMeta::load(solution.id)?.is_some() {
+            continue; // Already migrated
+        }
+
+        let detail = match client.get_problem_detail(&solution.slug).await {
+            Ok(d) => d,
+            Err(e) => {
+                println!(
+                    "  {} p{:04} {}: {e}",
+                    "✗ failed:".red(),
+                    solution.id,
+                    solution.slug
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        let limits = crate::judge::Limits::for_language(
+            "rust",
+            detail
+                .difficulty
+                .parse::<crate::problem::DifficultyLevel>()
+                .map(crate::problem::DifficultyLevel::level)
+                .unwrap_or(2),
+        );
+        let meta = ProblemMeta {
+            id: detail.question_id.parse().unwrap_or(0),
+            frontend_id: solution.id,
+            slug: solution.slug.clone(),
+            title: detail.title.clone(),
+            difficulty: detail.difficulty.clone(),
+            tags: detail
+                .topic_tags
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|t| t.name)
+                .collect(),
+            downloaded_at: ProblemMeta::now(),
+            language: "rust".to_string(),
+            module: solution
+                .path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string()),
+            time_limit_ms: Some(limits.time.as_millis() as u64),
+            memory_limit_mb: Some(limits.memory_kb / 1024),
+        };
+        meta.save()?;
+        ProblemMeta::save_description(solution.id, &detail.clean_content())?;
+
+        println!(
+            "  {} p{:04} {}",
+            "✓ migrated:".green(),
+            solution.id,
+            solution.slug
+        );
+        migrated += 1;
+    }
+
+    if migrated == 0 && failed == 0 {
+        println!("{}", "All solutions already have metadata.".green());
+    } else {
+        println!(
+            "{}",
+            format!("✓ Migrated {migrated} solution(s), {failed} failed").green()
+        );
+    }
+
+    Ok(())
+}
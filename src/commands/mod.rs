@@ -2,14 +2,54 @@
 //!
 //! Each submodule handles a specific CLI subcommand.
 
+pub mod alt;
+pub mod archive;
+pub mod badge;
+pub mod bench;
+pub mod check;
+pub mod clean;
+pub mod complexity;
+pub mod config;
+pub mod dashboard;
+pub mod diff;
+pub mod doctor;
+pub mod exec;
+pub mod export;
+pub mod export_example;
+pub mod fastest;
+pub mod grep;
+pub mod import;
+pub mod index;
+pub mod info;
+pub mod interview;
 pub mod list;
+pub mod list_mgmt;
 pub mod login;
+pub mod logout;
+pub mod migrate;
+pub mod path;
+pub mod perf;
 pub mod pick;
+pub mod queue;
+pub mod rate;
+pub mod recommend;
+pub mod redo;
+pub mod remind;
+pub mod resume;
+pub mod serve;
+pub mod share;
 pub mod show;
+pub mod solve;
+pub mod submission;
 pub mod submit;
+pub mod sync;
 pub mod test;
+pub mod update;
+pub mod verify;
+pub mod whatsnew;
+pub mod writeup;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use colored::Colorize;
@@ -75,6 +115,57 @@ pub fn print_problem_summary(problem: &Problem) {
     );
 }
 
+/// Warn when the remote statement differs from the local snapshot — edited
+/// constraints invalidate local assumptions — and offer a Markdown diff.
+/// No-op for problems downloaded before snapshots existed.
+pub(crate) fn check_statement_drift(id: u32, remote_markdown: &str) -> Result<()> {
+    let Some(local) = crate::meta::ProblemMeta::load_description(id)? else {
+        return Ok(());
+    };
+    if local == remote_markdown {
+        return Ok(());
+    }
+    let (added, removed) = update::diff_summary(&local, remote_markdown);
+    println!(
+        "{}",
+        format!(
+            "! statement changed since download (+{added}/-{removed} lines); \
+             constraints or examples may differ from your local copy"
+        )
+        .yellow()
+    );
+    if prompt_confirm("Show a diff of the statement? [Y/n]")? {
+        diff::print_unified_diff(&local, remote_markdown);
+    }
+    Ok(())
+}
+
+/// Send a desktop notification, if a notifier is available.
+///
+/// Uses `notify-send` on Linux and `osascript` on macOS. Failure to notify
+/// is not fatal; the terminal message is the source of truth.
+pub(crate) fn send_notification(summary: &str, body: &str) {
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(format!(
+            "display notification \"{}\" with title \"{}\"",
+            body.replace('"', "'"),
+            summary.replace('"', "'")
+        ))
+        .status();
+
+    #[cfg(not(target_os = "macos"))]
+    let result = std::process::Command::new("notify-send")
+        .arg(summary)
+        .arg(body)
+        .status();
+
+    if result.is_err() {
+        println!("{}", "(desktop notification unavailable)".yellow());
+    }
+}
+
 /// Print the result of a submission
 pub fn print_submission_result(result: &SubmissionResult) {
     match result.status_code {
@@ -146,29 +237,95 @@ pub fn print_submission_result(result: &SubmissionResult) {
     }
 }
 
+/// A solution file found in the local workspace.
+#[derive(Debug, Clone)]
+pub struct LocalSolution {
+    pub id: u32,
+    pub slug: String,
+    pub path: PathBuf,
+}
+
+/// Parse a solution file name like `p0001_two_sum.rs` into its problem ID
+/// and slug (`two-sum`). Returns `None` if the name doesn't match the layout.
+pub(crate) fn parse_solution_file_name(name: &str) -> Option<(u32, String)> {
+    let stem = name.strip_suffix(".rs")?.strip_prefix('p')?;
+    let (id_part, slug_part) = stem.split_once('_')?;
+    let id: u32 = id_part.parse().ok()?;
+    Some((id, slug_part.replace('_', "-")))
+}
+
+/// Scan `src/solutions/` for downloaded solution files, sorted by problem ID.
+pub fn list_local_solutions() -> Result<Vec<LocalSolution>> {
+    list_local_solutions_in(Path::new(""))
+}
+
+/// Scan the workspace rooted at `root` for downloaded solution files,
+/// sorted by problem ID.
+pub fn list_local_solutions_in(root: &Path) -> Result<Vec<LocalSolution>> {
+    let mut solutions = Vec::new();
+    let solutions_dir = root.join("src/solutions");
+    if solutions_dir.exists() {
+        for entry in std::fs::read_dir(&solutions_dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some((id, slug)) = parse_solution_file_name(&name) {
+                solutions.push(LocalSolution {
+                    id,
+                    slug,
+                    path: entry.path(),
+                });
+            }
+        }
+    }
+    solutions.sort_by_key(|s| s.id);
+    Ok(solutions)
+}
+
+/// Find problem IDs that have more than one solution file in the workspace.
+///
+/// Returns each duplicated ID with all of its file paths, sorted by ID.
+pub fn find_duplicate_solutions() -> Result<Vec<(u32, Vec<PathBuf>)>> {
+    find_duplicate_solutions_in(Path::new(""))
+}
+
+/// Find duplicated solution files in the workspace rooted at `root`.
+pub fn find_duplicate_solutions_in(root: &Path) -> Result<Vec<(u32, Vec<PathBuf>)>> {
+    let mut by_id: std::collections::BTreeMap<u32, Vec<PathBuf>> = std::collections::BTreeMap::new();
+    for solution in list_local_solutions_in(root)? {
+        by_id.entry(solution.id).or_default().push(solution.path);
+    }
+    Ok(by_id.into_iter().filter(|(_, paths)| paths.len() > 1).collect())
+}
+
 /// Find the solution file for a problem
 ///
-/// Looks for the problem file in `src/solutions/p{id}_{slug}.rs`
+/// Resolves the path through the per-problem metadata written at download
+/// time. Workspaces from before metadata existed need a one-time
+/// `leetcode-cli migrate`.
 pub fn find_solution_file(id: u32, file: Option<PathBuf>) -> Result<PathBuf> {
+    find_solution_file_in(Path::new(""), id, file)
+}
+
+/// Find the solution file for a problem in the workspace rooted at `root`.
+pub fn find_solution_file_in(root: &Path, id: u32, file: Option<PathBuf>) -> Result<PathBuf> {
     if let Some(f) = file {
         return Ok(f);
     }
 
-    // Look in src/solutions/ directory for p{id}_*.rs files
-    let problems_dir = PathBuf::from("src/solutions");
-    if problems_dir.exists() {
-        let prefix = format!("p{:04}_", id);
-        for entry in std::fs::read_dir(&problems_dir)? {
-            let entry = entry?;
-            let name = entry.file_name().to_string_lossy().to_string();
-            if name.starts_with(&prefix) && name.ends_with(".rs") {
-                return Ok(entry.path());
-            }
+    if let Some(meta) = crate::meta::ProblemMeta::load_from(root, id)? {
+        let path = root.join(meta.solution_path());
+        if path.exists() {
+            return Ok(path);
         }
+        anyhow::bail!(
+            "solution file not found for problem {id}: metadata points to missing {}",
+            path.display()
+        );
     }
 
     anyhow::bail!(
-        "solution file not found for problem {id}: expected src/solutions/p{id:04}_{{slug}}.rs"
+        "solution file not found for problem {id}: no metadata; run 'leetcode-cli migrate' \
+         to index pre-existing solutions"
     )
 }
 
@@ -177,6 +334,11 @@ pub fn find_solution_file(id: u32, file: Option<PathBuf>) -> Result<PathBuf> {
 /// This is useful for tests that need to run in a specific directory without
 /// affecting the global state. The original directory is restored when the guard
 /// is dropped, even if the test panics.
+///
+/// Changing the current directory is process-wide, so tests using this guard
+/// must also be `#[serial_test::serial]`. Prefer the `_in`/`_from` helper
+/// variants that take an explicit root; this guard is for exercising command
+/// entry points that still resolve against the current directory.
 #[cfg(test)]
 pub struct TestDirGuard {
     _temp_dir: TempDir,
@@ -212,6 +374,113 @@ mod tests {
 
     use super::*;
 
+    /// Write a minimal metadata file for a problem, as `download` would.
+    pub(crate) fn write_test_meta(id: u32, slug: &str) {
+        write_test_meta_in(Path::new(""), id, slug);
+    }
+
+    /// Write a minimal metadata file under `root`, as `download` would.
+    pub(crate) fn write_test_meta_in(root: &Path, id: u32, slug: &str) {
+        crate::meta::ProblemMeta {
+            id,
+            frontend_id: id,
+            slug: slug.to_string(),
+            title: slug.replace('-', " "),
+            difficulty: "Easy".to_string(),
+            tags: Vec::new(),
+            downloaded_at: 0,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+        .save_to(root)
+        .unwrap();
+    }
+
+    #[test]
+    fn test_parse_solution_file_name() {
+        assert_eq!(
+            parse_solution_file_name("p0001_two_sum.rs"),
+            Some((1, "two-sum".to_string()))
+        );
+        assert_eq!(
+            parse_solution_file_name("p3753_total_waviness_of_numbers_in_range_ii.rs"),
+            Some((3753, "total-waviness-of-numbers-in-range-ii".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_solution_file_name_rejects_other_files() {
+        assert_eq!(parse_solution_file_name("mod.rs"), None);
+        assert_eq!(parse_solution_file_name("p0001_two_sum.txt"), None);
+        assert_eq!(parse_solution_file_name("pabcd_two_sum.rs"), None);
+        assert_eq!(parse_solution_file_name("0001_two_sum.rs"), None);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_check_statement_drift_silent_without_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        // No snapshot: nothing to compare against
+        check_statement_drift(1, "anything").unwrap();
+
+        // Identical snapshot: no warning, no prompt
+        crate::meta::ProblemMeta::save_description(1, "Given an array...").unwrap();
+        check_statement_drift(1, "Given an array...").unwrap();
+    }
+
+    #[test]
+    fn test_list_local_solutions_sorted() {
+        let temp_dir = TempDir::new().unwrap();
+        let solutions_dir = temp_dir.path().join("src/solutions");
+        std::fs::create_dir_all(&solutions_dir).unwrap();
+        std::fs::write(solutions_dir.join("p0009_palindrome_number.rs"), "").unwrap();
+        std::fs::write(solutions_dir.join("p0001_two_sum.rs"), "").unwrap();
+        std::fs::write(solutions_dir.join("mod.rs"), "").unwrap();
+
+        let solutions = list_local_solutions_in(temp_dir.path()).unwrap();
+        assert_eq!(solutions.len(), 2);
+        assert_eq!(solutions[0].id, 1);
+        assert_eq!(solutions[0].slug, "two-sum");
+        assert_eq!(solutions[1].id, 9);
+    }
+
+    #[test]
+    fn test_find_duplicate_solutions() {
+        let temp_dir = TempDir::new().unwrap();
+        let solutions_dir = temp_dir.path().join("src/solutions");
+        std::fs::create_dir_all(&solutions_dir).unwrap();
+        std::fs::write(solutions_dir.join("p0001_two_sum.rs"), "").unwrap();
+        std::fs::write(solutions_dir.join("p0001_two_sum_redo.rs"), "").unwrap();
+        std::fs::write(solutions_dir.join("p0002_add_two_numbers.rs"), "").unwrap();
+
+        let duplicates = find_duplicate_solutions_in(temp_dir.path()).unwrap();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].0, 1);
+        assert_eq!(duplicates[0].1.len(), 2);
+    }
+
+    #[test]
+    fn test_find_duplicate_solutions_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let solutions_dir = temp_dir.path().join("src/solutions");
+        std::fs::create_dir_all(&solutions_dir).unwrap();
+        std::fs::write(solutions_dir.join("p0001_two_sum.rs"), "").unwrap();
+
+        assert!(find_duplicate_solutions_in(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_local_solutions_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let solutions = list_local_solutions_in(temp_dir.path()).unwrap();
+        assert!(solutions.is_empty());
+    }
+
     #[test]
     fn test_find_solution_file_with_explicit_path() {
         let temp_dir = TempDir::new().unwrap();
@@ -225,19 +494,17 @@ mod tests {
 
     #[test]
     fn test_find_solution_file_not_found() {
-        // Create a temp directory without the solutions directory
+        // A temp directory without the solutions directory
         let temp_dir = TempDir::new().unwrap();
-        let _guard = TestDirGuard::new(temp_dir);
 
-        let result = find_solution_file(999, None);
+        let result = find_solution_file_in(temp_dir.path(), 999, None);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("solution file not found"));
     }
 
     #[test]
-    #[serial_test::serial]
-    fn test_find_solution_file_in_solutions_dir() {
+    fn test_find_solution_file_via_metadata() {
         let temp_dir = TempDir::new().unwrap();
 
         // Create solutions directory structure
@@ -246,16 +513,15 @@ mod tests {
         let problem_file = solutions_dir.join("p0001_two_sum.rs");
         std::fs::write(&problem_file, "pub struct Solution;").unwrap();
 
-        let _guard = TestDirGuard::new(temp_dir);
+        write_test_meta_in(temp_dir.path(), 1, "two-sum");
 
-        let result = find_solution_file(1, None);
+        let result = find_solution_file_in(temp_dir.path(), 1, None);
         assert!(result.is_ok());
         let found_path = result.unwrap();
         assert!(found_path.to_string_lossy().contains("p0001_two_sum.rs"));
     }
 
     #[test]
-    #[serial_test::serial]
     fn test_find_solution_file_multiple_ids() {
         let temp_dir = TempDir::new().unwrap();
 
@@ -273,19 +539,36 @@ mod tests {
         )
         .unwrap();
 
-        let _guard = TestDirGuard::new(temp_dir);
+        write_test_meta_in(temp_dir.path(), 1, "two-sum");
+        write_test_meta_in(temp_dir.path(), 2, "add-two-numbers");
 
         // Should find problem 1
-        let result1 = find_solution_file(1, None);
+        let result1 = find_solution_file_in(temp_dir.path(), 1, None);
         assert!(result1.is_ok());
         assert!(result1.unwrap().to_string_lossy().contains("p0001"));
 
         // Should find problem 2
-        let result2 = find_solution_file(2, None);
+        let result2 = find_solution_file_in(temp_dir.path(), 2, None);
         assert!(result2.is_ok());
         assert!(result2.unwrap().to_string_lossy().contains("p0002"));
     }
 
+    #[test]
+    fn test_find_solution_file_without_metadata_suggests_migrate() {
+        let temp_dir = TempDir::new().unwrap();
+        let solutions_dir = temp_dir.path().join("src/solutions");
+        std::fs::create_dir_all(&solutions_dir).unwrap();
+        std::fs::write(
+            solutions_dir.join("p0001_two_sum.rs"),
+            "pub struct Solution;",
+        )
+        .unwrap();
+
+        let result = find_solution_file_in(temp_dir.path(), 1, None);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("migrate"));
+    }
+
     #[test]
     fn test_print_problem_summary() {
         use crate::problem::{Difficulty, Stat};
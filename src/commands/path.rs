@@ -0,0 +1,114 @@
+//! Path command - Print the absolute path of a solution file
+//!
+//! Resolves a problem by ID or slug and prints where its solution lives,
+//! intended for shell helpers like
+//! `lce() { $EDITOR "$(leetcode-cli path "$1")"; }`. With `--create` the
+//! problem is downloaded first if it is not in the workspace yet.
+
+use anyhow::Result;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{list_local_solutions, pick::download_problem},
+    meta::ProblemMeta,
+};
+
+/// Print the absolute solution path for a problem ID or slug
+pub async fn execute(client: &LeetCodeClient, reference: String, create: bool) -> Result<()> {
+    let id = match resolve_reference(&reference)? {
+        Some(id) => id,
+        None => resolve_remote(client, &reference).await?,
+    };
+
+    let path = match ProblemMeta::load(id)? {
+        Some(meta) => meta.solution_path(),
+        None if create => {
+            let problem = client
+                .get_problem_by_id(id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("problem {} not found", id))?;
+            download_problem(client, &problem).await?;
+            ProblemMeta::load(id)?
+                .ok_or_else(|| anyhow::anyhow!("download did not produce metadata for problem {id}"))?
+                .solution_path()
+        }
+        None => anyhow::bail!(
+            "no solution for problem {id}; re-run with --create to download it"
+        ),
+    };
+
+    if !path.exists() && !create {
+        anyhow::bail!(
+            "metadata for problem {} points at missing file {}; run 'leetcode-cli doctor'",
+            id,
+            path.display()
+        );
+    }
+
+    // Print only the path so the output is directly usable in shell substitution
+    println!("{}", std::path::absolute(&path)?.display());
+    Ok(())
+}
+
+/// Resolve a CLI reference against the local workspace: a numeric string is a
+/// problem ID, anything else is matched against local solution slugs.
+pub(crate) fn resolve_reference(reference: &str) -> Result<Option<u32>> {
+    if let Ok(id) = reference.parse::<u32>() {
+        return Ok(Some(id));
+    }
+    let slug = reference.to_lowercase().replace([' ', '_'], "-");
+    Ok(list_local_solutions()?
+        .into_iter()
+        .find(|s| s.slug == slug)
+        .map(|s| s.id))
+}
+
+/// Fall back to the problem list for slugs not in the local workspace.
+async fn resolve_remote(client: &LeetCodeClient, reference: &str) -> Result<u32> {
+    let slug = reference.to_lowercase().replace([' ', '_'], "-");
+    let problems = client.get_all_problems().await?;
+    problems
+        .iter()
+        .find(|p| p.stat.question_title_slug() == slug)
+        .map(|p| p.stat.frontend_question_id)
+        .ok_or_else(|| anyhow::anyhow!("no problem matches '{reference}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use super::*;
+    use crate::commands::TestDirGuard;
+
+    #[test]
+    #[serial]
+    fn test_resolve_reference_numeric() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+        assert_eq!(resolve_reference("42").unwrap(), Some(42));
+    }
+
+    #[test]
+    #[serial]
+    fn test_resolve_reference_slug() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+        std::fs::create_dir_all("src/solutions").unwrap();
+        std::fs::write("src/solutions/p0001_two_sum.rs", "").unwrap();
+
+        assert_eq!(resolve_reference("two-sum").unwrap(), Some(1));
+        // Underscores and case are normalized to the slug form
+        assert_eq!(resolve_reference("Two_Sum").unwrap(), Some(1));
+    }
+
+    #[test]
+    #[serial]
+    fn test_resolve_reference_unknown_slug() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+        std::fs::create_dir_all("src/solutions").unwrap();
+
+        assert_eq!(resolve_reference("no-such-problem").unwrap(), None);
+    }
+}
@@ -0,0 +1,181 @@
+//! Perf command - Judge runtime/memory figures across attempts
+//!
+//! Shows the runtime and memory percentiles of every accepted submission
+//! of one problem, or a workspace-wide summary of how many solutions sit
+//! in the top 10% runtime. The figures come from the perf history that
+//! `submit` appends to the progress database on every accept.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    progress::{PerfSample, Progress},
+    table::{Cell, Table},
+};
+
+/// Show the perf history of one problem, or a workspace-wide summary
+pub async fn execute(id: Option<u32>) -> Result<()> {
+    let progress = Progress::load()?;
+    match id {
+        Some(id) => show_problem(&progress, id),
+        None => show_summary(&progress),
+    }
+}
+
+/// Print one problem's accepted submissions, oldest first.
+fn show_problem(progress: &Progress, id: u32) -> Result<()> {
+    let entry = progress
+        .problems
+        .get(&id)
+        .ok_or_else(|| crate::error::CliError::ProblemNotFound(format!("ID {id}")))?;
+    if entry.perf_history.is_empty() {
+        println!(
+            "No accepted submissions recorded for problem {id} yet; \
+             figures are collected by 'leetcode-cli submit'"
+        );
+        return Ok(());
+    }
+
+    println!("{}", format!("{id}. {}", entry.slug).bold());
+    let mut table = Table::new(&["#", "Date", "Runtime", "Beats", "Memory", "Beats"]);
+    for (i, sample) in entry.perf_history.iter().enumerate() {
+        table.add_row(vec![
+            Cell::new((i + 1).to_string()),
+            Cell::new(format_date(sample.at)),
+            Cell::new(&sample.runtime),
+            percentile_cell(sample.runtime_percentile),
+            Cell::new(&sample.memory),
+            percentile_cell(sample.memory_percentile),
+        ]);
+    }
+    table.print();
+    Ok(())
+}
+
+/// Print how many solutions' latest accepted runs are in the top 10%
+/// runtime, and which ones.
+fn show_summary(progress: &Progress) -> Result<()> {
+    let latest: Vec<(u32, &PerfSample)> = progress
+        .problems
+        .iter()
+        .filter_map(|(id, p)| Some((*id, p.perf_history.last()?)))
+        .collect();
+    if latest.is_empty() {
+        println!(
+            "No accepted submissions recorded yet; \
+             figures are collected by 'leetcode-cli submit'"
+        );
+        return Ok(());
+    }
+
+    let top: Vec<u32> = latest
+        .iter()
+        .filter(|(_, s)| s.runtime_percentile.is_some_and(|p| p >= 90.0))
+        .map(|(id, _)| *id)
+        .collect();
+    println!(
+        "{} of {} tracked solutions are in the top 10% runtime",
+        top.len().to_string().bold(),
+        latest.len()
+    );
+    if !top.is_empty() {
+        println!(
+            "  {}",
+            top.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// A percentile as a table cell, colored by how good it is.
+fn percentile_cell(percentile: Option<f64>) -> Cell {
+    match percentile {
+        Some(p) => {
+            let plain = format!("{p:.1}%");
+            let painted = if p >= 90.0 {
+                plain.green().to_string()
+            } else if p >= 50.0 {
+                plain.normal().to_string()
+            } else {
+                plain.yellow().to_string()
+            };
+            Cell::painted(plain, painted)
+        }
+        None => Cell::new("-"),
+    }
+}
+
+/// A Unix timestamp as a "YYYY-MM-DD" UTC date, via the standard
+/// civil-from-days conversion.
+pub(crate) fn format_date(ts: u64) -> String {
+    let days = (ts / 86_400) as i64;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let year = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { year + 1 } else { year };
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_700_000_000), "2023-11-14");
+        assert_eq!(format_date(951_868_800), "2000-03-01");
+    }
+
+    #[test]
+    fn test_percentile_cell() {
+        colored::control::set_override(false);
+        let rendered = |p| {
+            let mut t = Table::new(&["x"]);
+            t.add_row(vec![percentile_cell(p)]);
+            t.render(200)
+        };
+        assert!(rendered(Some(95.25)).contains("95.2%"));
+        assert!(rendered(Some(42.0)).contains("42.0%"));
+        assert!(rendered(None).contains('-'));
+        colored::control::unset_override();
+    }
+
+    #[test]
+    fn test_show_summary_counts_latest_sample() {
+        use crate::progress::SolveStatus;
+
+        let sample = |p: Option<f64>| PerfSample {
+            at: 1_700_000_000,
+            runtime: "4 ms".to_string(),
+            memory: "2.1 MB".to_string(),
+            runtime_percentile: p,
+            memory_percentile: None,
+        };
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        progress.record_perf(1, sample(Some(50.0)));
+        progress.record_perf(1, sample(Some(95.0))); // latest counts
+        progress.record(2, "add-two-numbers", SolveStatus::Solved, "submit");
+        progress.record_perf(2, sample(Some(89.9)));
+        progress.record(3, "no-perf", SolveStatus::Solved, "import");
+
+        let top: Vec<u32> = progress
+            .problems
+            .iter()
+            .filter_map(|(id, p)| Some((*id, p.perf_history.last()?)))
+            .filter(|(_, s)| s.runtime_percentile.is_some_and(|p| p >= 90.0))
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(top, vec![1]);
+    }
+}
@@ -6,26 +6,62 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::{
-    api::LeetCodeClient,
+    api::{LeetCodeClient, PickStrategy, ProblemFilter},
     commands::{print_problem_summary, prompt_confirm},
+    meta::ProblemMeta,
     problem::Problem,
     template::CodeTemplate,
 };
 
 /// Pick a random problem or specific problem by ID
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     client: &LeetCodeClient,
     id: Option<u32>,
-    difficulty: Option<String>,
+    filter: ProblemFilter,
     tag: Option<String>,
+    count: Option<usize>,
+    strategy: Option<String>,
+    lang: Option<String>,
+    with_proptest: bool,
 ) -> Result<()> {
+    // A nudge before the session starts, while there's still time to act
+    let progress = crate::progress::Progress::load()?;
+    if let Some(warning) =
+        crate::commands::dashboard::streak_warning(&progress, crate::progress::now_ts())
+    {
+        println!("{}", warning.yellow());
+    }
+
     println!("{}", "Fetching problems...".cyan());
 
+    let strategy = match strategy {
+        Some(ref name) => name.parse::<PickStrategy>()?,
+        None => PickStrategy::default(),
+    };
+    let lang = lang.as_deref();
+    if let Some(l) = lang
+        && !matches!(l, "rust" | "typescript" | "javascript" | "python" | "cpp" | "go")
+    {
+        anyhow::bail!(
+            "unsupported language '{l}': expected rust, typescript, javascript, python, cpp, \
+             or go"
+        );
+    }
+
+    // Batch mode: pick N problems and queue them as a practice session
+    if let Some(n) = count {
+        if id.is_some() {
+            anyhow::bail!("--count cannot be combined with --id");
+        }
+        return pick_batch(client, &filter, tag.as_deref(), n, strategy, lang, with_proptest).await;
+    }
+
     let problem = if let Some(problem_id) = id {
         client.get_problem_by_id(problem_id).await?
     } else {
         client
-            .get_random_problem(difficulty.as_deref(), tag.as_deref())
+            .get_random_problem_filtered(&filter, tag.as_deref(), strategy)
             .await?
     };
 
@@ -34,7 +70,7 @@ pub async fn execute(
 
         // Ask if user wants to download
         if prompt_confirm("\nDownload this problem? [Y/n]")? {
-            download_problem(client, &p).await?;
+            download_problem_with_lang(client, &p, lang, with_proptest).await?;
         }
     } else {
         println!("{}", "No problem found matching the criteria.".red());
@@ -43,6 +79,92 @@ pub async fn execute(
     Ok(())
 }
 
+/// Pick `count` distinct problems, download them, and record them as the
+/// practice queue. Problems already downloaded or solved are not repeated.
+async fn pick_batch(
+    client: &LeetCodeClient,
+    filter: &ProblemFilter,
+    tag: Option<&str>,
+    count: usize,
+    strategy: PickStrategy,
+    lang: Option<&str>,
+    with_proptest: bool,
+) -> Result<()> {
+    if count == 0 {
+        anyhow::bail!("--count must be at least 1");
+    }
+
+    let progress = crate::progress::Progress::load()?;
+    let local_ids: std::collections::HashSet<u32> = crate::commands::list_local_solutions()?
+        .into_iter()
+        .map(|s| s.id)
+        .collect();
+
+    let mut queue = crate::queue::PracticeQueue::load()?;
+    let mut picked = Vec::new();
+    // Each draw filters again, so cap the attempts in case the filtered pool
+    // is smaller than the requested count
+    let mut attempts = 0;
+    while picked.len() < count && attempts < count * 10 {
+        attempts += 1;
+        let Some(problem) = client
+            .get_random_problem_filtered(filter, tag, strategy)
+            .await?
+        else {
+            break;
+        };
+        let id = problem.stat.frontend_question_id;
+        if progress.is_solved(id)
+            || local_ids.contains(&id)
+            || picked.iter().any(|p: &Problem| p.stat.frontend_question_id == id)
+            || queue.items.iter().any(|item| item.id == id)
+        {
+            continue;
+        }
+        picked.push(problem);
+    }
+
+    if picked.is_empty() {
+        println!("{}", "No unseen problems match the criteria.".red());
+        return Ok(());
+    }
+    if picked.len() < count {
+        println!(
+            "{}",
+            format!(
+                "! only {} unseen problem(s) match the criteria (wanted {count})",
+                picked.len()
+            )
+            .yellow()
+        );
+    }
+
+    for problem in &picked {
+        print_problem_summary(problem);
+        download_problem_with_lang(client, problem, lang, with_proptest).await?;
+        queue.push(
+            problem.stat.frontend_question_id,
+            &problem.stat.question_title_slug(),
+            &problem.stat.question_title(),
+        );
+    }
+
+    if queue.created == 0 {
+        queue.created = ProblemMeta::now();
+    }
+    queue.save()?;
+    println!(
+        "{}",
+        format!(
+            "✓ Queued {} problems; run 'leetcode-cli queue' to see them",
+            picked.len()
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
 /// Sanitize a string to be safe for use in a file/directory name.
 /// Removes path separators and other potentially dangerous characters.
 fn sanitize_file_name(name: &str) -> String {
@@ -51,8 +173,33 @@ fn sanitize_file_name(name: &str) -> String {
         .collect()
 }
 
+/// Render a solution module name from the configured template.
+///
+/// Supported placeholders: `{id}`, `{id4}` (zero-padded), `{slug}`
+/// (underscored), `{difficulty}` (lowercase). The result is sanitized into
+/// a valid Rust module identifier.
+pub(crate) fn render_module_name(template: &str, id: u32, slug: &str, difficulty: &str) -> String {
+    let rendered = template
+        .replace("{id4}", &format!("{id:04}"))
+        .replace("{id}", &id.to_string())
+        .replace("{slug}", &slug.replace('-', "_"))
+        .replace("{difficulty}", &difficulty.to_lowercase());
+
+    let mut name: String = rendered
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    // Module names can't start with a digit
+    if name.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        name.insert(0, 'p');
+    }
+    name
+}
+
 /// Add a module declaration to src/solutions/mod.rs if it doesn't exist
-fn add_module_declaration(module_name: &str) -> Result<()> {
+pub(crate) fn add_module_declaration(module_name: &str) -> Result<()> {
     let mod_path = PathBuf::from("src/solutions/mod.rs");
 
     // Create solutions directory if it doesn't exist
@@ -79,31 +226,285 @@ fn add_module_declaration(module_name: &str) -> Result<()> {
     Ok(())
 }
 
-/// Download problem to local workspace
-async fn download_problem(client: &LeetCodeClient, problem: &Problem) -> Result<()> {
+/// Make sure the workspace `Cargo.toml` lists proptest as a dev-dependency
+/// so the generated `proptest!` block compiles.
+fn ensure_proptest_dev_dependency() -> Result<()> {
+    let path = PathBuf::from("Cargo.toml");
+    if !path.exists() {
+        println!(
+            "{}",
+            "! no Cargo.toml found; add proptest as a dev-dependency manually".yellow()
+        );
+        return Ok(());
+    }
+    let mut content = std::fs::read_to_string(&path)?;
+    if !content.contains("proptest") {
+        content.push_str("\n[dev-dependencies.proptest]\nversion = \"1\"\n");
+        std::fs::write(&path, content)?;
+        println!("  Added proptest as a dev-dependency in Cargo.toml");
+    }
+    Ok(())
+}
+
+/// Make sure the workspace `Cargo.toml` lists the extra crates configured
+/// in `[template] dependencies`, so generated files relying on them compile.
+fn ensure_template_dependencies(template: &crate::config::TemplateConfig) -> Result<()> {
+    let specs = template.dependency_specs();
+    if specs.is_empty() {
+        return Ok(());
+    }
+    let path = PathBuf::from("Cargo.toml");
+    if !path.exists() {
+        println!(
+            "{}",
+            "! no Cargo.toml found; add the configured template dependencies manually".yellow()
+        );
+        return Ok(());
+    }
+    let mut content = std::fs::read_to_string(&path)?;
+    let mut added = Vec::new();
+    for (name, version) in specs {
+        if !content.contains(&name) {
+            content.push_str(&format!(
+                "\n[dependencies.{name}]\nversion = \"{version}\"\n"
+            ));
+            added.push(name);
+        }
+    }
+    if !added.is_empty() {
+        std::fs::write(&path, content)?;
+        println!(
+            "  Added configured dependencies in Cargo.toml: {}",
+            added.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Write the configured `clippy.toml` at the workspace root, so every
+/// downloaded problem follows the same style rules. An existing file with
+/// different contents is left alone, with a warning.
+fn write_clippy_toml(template: &crate::config::TemplateConfig) -> Result<()> {
+    let Some(ref contents) = template.clippy_toml else {
+        return Ok(());
+    };
+    let path = PathBuf::from("clippy.toml");
+    let contents = if contents.ends_with('\n') {
+        contents.clone()
+    } else {
+        format!("{contents}\n")
+    };
+    if path.exists() {
+        if std::fs::read_to_string(&path)? != contents {
+            println!(
+                "{}",
+                "! clippy.toml already exists and differs from template.clippy_toml; \
+                 leaving it alone"
+                    .yellow()
+            );
+        }
+        return Ok(());
+    }
+    std::fs::write(&path, contents)?;
+    println!("  Wrote clippy.toml from template.clippy_toml");
+    Ok(())
+}
+
+/// Pin `rust-toolchain.toml` at the workspace root to the version LeetCode's
+/// judge runs, so local builds catch std APIs the judge doesn't have yet. An
+/// existing pin to a different channel is left alone, with a warning.
+pub(crate) fn write_toolchain_file(version: &str) -> Result<()> {
+    let path = PathBuf::from("rust-toolchain.toml");
+    let content = format!(
+        "# Rust version LeetCode's judge runs (Config.leetcode_rust_version)\n\
+         [toolchain]\nchannel = \"{version}\"\n"
+    );
+    if path.exists() {
+        let existing = std::fs::read_to_string(&path)?;
+        if existing != content {
+            println!(
+                "{}",
+                format!(
+                    "! rust-toolchain.toml already exists and does not pin {version}; \
+                     leaving it alone"
+                )
+                .yellow()
+            );
+        }
+        return Ok(());
+    }
+    std::fs::write(&path, content)?;
+    Ok(())
+}
+
+/// Download problem to local workspace with the default language (Rust)
+pub(crate) async fn download_problem(client: &LeetCodeClient, problem: &Problem) -> Result<()> {
+    download_problem_with_lang(client, problem, None, false).await
+}
+
+/// Download problem to local workspace, generating a workspace for the
+/// requested language. Database and shell problems keep their dedicated
+/// workspaces regardless of the requested language.
+pub(crate) async fn download_problem_with_lang(
+    client: &LeetCodeClient,
+    problem: &Problem,
+    lang: Option<&str>,
+    with_proptest: bool,
+) -> Result<()> {
     let id = problem.stat.frontend_question_id;
+    if problem.paid_only && !client.is_premium().await? {
+        return Err(crate::error::CliError::PremiumRequired(format!(
+            "{id} ({})",
+            problem.stat.question_title()
+        ))
+        .into());
+    }
     println!("{}", format!("Downloading problem {id}...").cyan());
 
     let detail = client
         .get_problem_detail(&problem.stat.question_title_slug())
         .await?;
 
-    // Create module name: p0001_two_sum (prefix with 'p' for valid Rust identifier)
+    if let Some(votes) = crate::commands::show::render_votes_line(&detail) {
+        println!("{} {}", "Votes:".bold(), votes);
+    }
+
+    // Create module name from the configured template (default: p0001_two_sum)
+    let config = crate::config::Config::load()?;
     let slug = sanitize_file_name(&problem.stat.question_title_slug());
-    let module_name = format!("p{:04}_{}", id, slug.replace("-", "_"));
+    let module_name =
+        render_module_name(&config.get_file_template(), id, &slug, &detail.difficulty);
     let file_name = format!("{module_name}.rs");
 
-    // Ensure solutions directory exists
-    let solutions_dir = PathBuf::from("src/solutions");
-    std::fs::create_dir_all(&solutions_dir)?;
+    let template = CodeTemplate::new(&detail).with_config(config.template.clone());
+    let is_database = detail.is_database_problem();
+    let is_shell = !is_database && detail.is_shell_problem();
+    let lang = if is_database {
+        "sql"
+    } else if is_shell {
+        "bash"
+    } else {
+        lang.unwrap_or("rust")
+    };
+
+    let code_file = if is_database {
+        // Database problems get a SQL workspace instead of a Rust module
+        let sql_dir = PathBuf::from("sql").join(&module_name);
+        template.write_sql_template(&sql_dir)?;
+        sql_dir.join("solution.sql")
+    } else if is_shell {
+        // Shell problems get a bash workspace instead of a Rust module
+        let shell_dir = PathBuf::from("shell").join(&module_name);
+        template.write_shell_template(&shell_dir)?;
+        shell_dir.join("solution.sh")
+    } else if lang == "typescript" || lang == "javascript" {
+        let ext = if lang == "typescript" { "ts" } else { "js" };
+        let ts_dir = PathBuf::from(ext).join(&module_name);
+        template.write_ts_template(&ts_dir, lang)?;
+        ts_dir.join(format!("solution.{ext}"))
+    } else if lang == "python" {
+        let py_dir = PathBuf::from("py").join(&module_name);
+        template.write_python_template(&py_dir)?;
+        py_dir.join("solution.py")
+    } else if lang == "cpp" {
+        let cpp_dir = PathBuf::from("cpp").join(&module_name);
+        template.write_cpp_template(&cpp_dir)?;
+        cpp_dir.join("solution.cpp")
+    } else if lang == "go" {
+        let go_dir = PathBuf::from("go").join(&module_name);
+        template.write_go_template(&go_dir)?;
+        go_dir.join("solution.go")
+    } else {
+        // Ensure solutions directory exists
+        let solutions_dir = PathBuf::from("src/solutions");
+        std::fs::create_dir_all(&solutions_dir)?;
+
+        // Warn if another file for this problem already exists under a
+        // different name (e.g. downloaded with a different file template)
+        for existing in crate::commands::list_local_solutions()? {
+            if existing.id == id
+                && existing.path.file_name().is_some_and(|n| n != file_name.as_str())
+            {
+                println!(
+                    "{}",
+                    format!(
+                        "! problem {id} already exists as {}; run 'leetcode-cli doctor' to review",
+                        existing.path.display()
+                    )
+                    .yellow()
+                );
+            }
+        }
+
+        let code_file = solutions_dir.join(&file_name);
+        template.write_rust_template_with(&code_file, with_proptest)?;
+        if with_proptest {
+            if template.proptest_supported() {
+                ensure_proptest_dev_dependency()?;
+            } else {
+                println!(
+                    "{}",
+                    "! --with-proptest skipped: the signature is not simple numeric/array"
+                        .yellow()
+                );
+            }
+        }
+
+        // Add module declaration
+        add_module_declaration(&module_name)?;
+
+        // Wire configured extra dependencies into the workspace Cargo.toml
+        ensure_template_dependencies(&config.template)?;
+
+        // Shared style rules for every downloaded problem, if configured
+        write_clippy_toml(&config.template)?;
+
+        // Pin the toolchain LeetCode's judge runs, if configured
+        if let Some(ref version) = config.leetcode_rust_version {
+            write_toolchain_file(version)?;
+        }
+        code_file
+    };
+
+    // Seed the local judge limits from the known judge defaults for this
+    // language, so the metadata carries editable concrete numbers
+    let limits = crate::judge::Limits::for_language(
+        lang,
+        detail
+            .difficulty
+            .parse::<crate::problem::DifficultyLevel>()
+            .map(crate::problem::DifficultyLevel::level)
+            .unwrap_or(2),
+    );
+
+    // Write per-problem metadata so other commands can resolve paths
+    // without guessing from file-name prefixes
+    let meta = ProblemMeta {
+        id: detail.question_id.parse().unwrap_or(0),
+        frontend_id: id,
+        slug: problem.stat.question_title_slug(),
+        title: detail.title.clone(),
+        difficulty: detail.difficulty.clone(),
+        tags: detail
+            .topic_tags
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|t| t.name)
+            .collect(),
+        downloaded_at: ProblemMeta::now(),
+        language: lang.to_string(),
+        module: Some(module_name.clone()),
+        time_limit_ms: Some(limits.time.as_millis() as u64),
+        memory_limit_mb: Some(limits.memory_kb / 1024),
+    };
+    meta.save()?;
 
-    // Generate code template
-    let template = CodeTemplate::new(&detail);
-    let code_file = solutions_dir.join(&file_name);
-    template.write_rust_template(&code_file)?;
+    // Snapshot the statement so later fetches can detect changes
+    ProblemMeta::save_description(id, &detail.clean_content())?;
 
-    // Add module declaration
-    add_module_declaration(&module_name)?;
+    // Keep index.json in step for external tooling
+    crate::workspace_index::refresh()?;
 
     println!(
         "{}",
@@ -112,7 +513,16 @@ async fn download_problem(client: &LeetCodeClient, problem: &Problem) -> Result<
     println!("  - Solution: {}", code_file.display());
     println!();
     println!("{}", "To run tests:".cyan());
-    println!("  cargo test {module_name}");
+    match lang {
+        "sql" => println!("  sh sql/{module_name}/test.sh"),
+        "bash" => println!("  sh shell/{module_name}/test.sh"),
+        "typescript" => println!("  npm --prefix ts/{module_name} test"),
+        "javascript" => println!("  npm --prefix js/{module_name} test"),
+        "python" => println!("  pytest py/{module_name}"),
+        "cpp" => println!("  leetcode-cli test {id}"),
+        "go" => println!("  go test -C go/{module_name} ./..."),
+        _ => println!("  cargo test {module_name}"),
+    }
 
     Ok(())
 }
@@ -166,6 +576,23 @@ mod tests {
         (mock_server, config)
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_write_toolchain_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        write_toolchain_file("1.79.0").unwrap();
+        let content = fs::read_to_string("rust-toolchain.toml").unwrap();
+        assert!(content.contains("channel = \"1.79.0\""));
+
+        // An existing pin to a different channel is not overwritten
+        fs::write("rust-toolchain.toml", "[toolchain]\nchannel = \"1.75.0\"\n").unwrap();
+        write_toolchain_file("1.79.0").unwrap();
+        let content = fs::read_to_string("rust-toolchain.toml").unwrap();
+        assert!(content.contains("channel = \"1.75.0\""));
+    }
+
     #[test]
     fn test_sanitize_file_name_normal() {
         assert_eq!(sanitize_file_name("two-sum"), "two-sum");
@@ -198,6 +625,34 @@ mod tests {
         assert_eq!(sanitize_file_name("/\\:*?\"<>|"), "");
     }
 
+    #[test]
+    fn test_render_module_name_default_template() {
+        assert_eq!(
+            render_module_name("p{id4}_{slug}", 1, "two-sum", "Easy"),
+            "p0001_two_sum"
+        );
+    }
+
+    #[test]
+    fn test_render_module_name_custom_templates() {
+        assert_eq!(
+            render_module_name("{difficulty}/{id}_{slug}", 1, "two-sum", "Easy"),
+            "easy_1_two_sum"
+        );
+        assert_eq!(
+            render_module_name("{id}_{slug}", 42, "trapping-rain-water", "Hard"),
+            "p42_trapping_rain_water"
+        );
+    }
+
+    #[test]
+    fn test_render_module_name_sanitizes_invalid_chars() {
+        assert_eq!(
+            render_module_name("{slug}!", 1, "two-sum", "Easy"),
+            "two_sum_"
+        );
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_add_module_declaration_creates_new_file() {
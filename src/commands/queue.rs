@@ -0,0 +1,94 @@
+//! Queue command - Work through the practice queue
+//!
+//! `leetcode-cli queue` lists the queued problems that are not solved yet;
+//! `leetcode-cli queue --next` opens the next remaining one in the editor.
+//! The queue is filled by `pick --count N`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{find_solution_file, pick::download_problem},
+    config::Config,
+    progress::Progress,
+    queue::PracticeQueue,
+};
+
+/// Show the practice queue, or open the next remaining problem
+pub async fn execute(client: &LeetCodeClient, next: bool) -> Result<()> {
+    let queue = PracticeQueue::load()?;
+    if queue.items.is_empty() {
+        println!(
+            "{}",
+            "Practice queue is empty; fill it with 'leetcode-cli pick --count N'.".yellow()
+        );
+        return Ok(());
+    }
+
+    let progress = Progress::load()?;
+    let remaining = queue.remaining(&progress);
+
+    if remaining.is_empty() {
+        println!(
+            "{}",
+            format!("✓ Queue done: all {} problems solved!", queue.items.len()).green()
+        );
+        return Ok(());
+    }
+
+    if next {
+        let item = remaining[0];
+        println!(
+            "{}",
+            format!("Next up: {}. {}", item.id, item.title).bold().cyan()
+        );
+
+        // Download the problem if it isn't in the workspace yet
+        let solution_file = match find_solution_file(item.id, None) {
+            Ok(file) => file,
+            Err(_) => {
+                let problem = client
+                    .get_problem_by_id(item.id)
+                    .await?
+                    .ok_or_else(|| {
+                        crate::error::CliError::ProblemNotFound(format!("ID {}", item.id))
+                    })?;
+                download_problem(client, &problem).await?;
+                find_solution_file(item.id, None)?
+            }
+        };
+
+        let config = Config::load()?;
+        let editor = config.get_editor();
+        println!(
+            "{}",
+            format!("Opening {} in {editor}...", solution_file.display()).cyan()
+        );
+        std::process::Command::new(&editor)
+            .arg(&solution_file)
+            .spawn()?;
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Practice queue: {} of {} remaining",
+            remaining.len(),
+            queue.items.len()
+        )
+        .bold()
+        .cyan()
+    );
+    for item in &queue.items {
+        if progress.is_solved(item.id) {
+            println!("  {} {}. {}", "✓".green(), item.id, item.title);
+        } else {
+            println!("  {} {}. {}", "○".yellow(), item.id, item.title);
+        }
+    }
+    println!("\n{}", "Run 'leetcode-cli queue --next' to continue.".cyan());
+
+    Ok(())
+}
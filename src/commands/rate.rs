@@ -0,0 +1,55 @@
+//! Rate command - like or dislike a problem without leaving the terminal
+//!
+//! Sends the reaction through the GraphQL mutation the website uses; the
+//! recorded reaction shows up in `show` afterwards.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::api::LeetCodeClient;
+
+/// Record a like or dislike for a problem.
+pub async fn execute(client: &LeetCodeClient, id: u32, like: bool, dislike: bool) -> Result<()> {
+    if like == dislike {
+        anyhow::bail!("pass exactly one of --like or --dislike");
+    }
+
+    let problem = client
+        .get_problem_by_id(id)
+        .await?
+        .ok_or_else(|| crate::error::CliError::ProblemNotFound(format!("ID {id}")))?;
+
+    let counts = client
+        .react_to_problem(&problem.stat.question_id.to_string(), like)
+        .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✓ Recorded your {} for problem {id}",
+            reaction_label(like)
+        )
+        .green()
+    );
+    if let Some((likes, dislikes)) = counts {
+        println!("  now at {likes} likes / {dislikes} dislikes");
+    }
+
+    Ok(())
+}
+
+/// The reaction as a word for the confirmation message.
+fn reaction_label(like: bool) -> &'static str {
+    if like { "like" } else { "dislike" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reaction_label() {
+        assert_eq!(reaction_label(true), "like");
+        assert_eq!(reaction_label(false), "dislike");
+    }
+}
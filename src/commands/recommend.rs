@@ -0,0 +1,325 @@
+//! Recommend command - weak-topic practice suggestions
+//!
+//! Ranks the tags seen in the workspace by local solve rate and attempt
+//! count, then suggests unattempted problems from the tag index aimed at
+//! the weakest topics. Each pick comes with the evidence that flagged its
+//! topic, and the target difficulty ramps with how shaky the topic looks:
+//! Easy while the solve rate is underwater, Medium once the basics hold.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    meta::ProblemMeta,
+    problem::{DifficultyLevel, Problem},
+    progress::Progress,
+};
+
+/// How many problems to suggest in total.
+const RECOMMENDATIONS: usize = 5;
+/// Cap per tag, so one very weak topic doesn't crowd out the rest.
+const PER_TAG: usize = 2;
+
+/// Suggest the next problems to practice, weakest topics first.
+pub async fn execute(client: &LeetCodeClient) -> Result<()> {
+    let metas = ProblemMeta::load_all()?;
+    let progress = Progress::load()?;
+    let stats = tag_stats(&metas, &progress);
+    let ranked = weakest_tags(&stats);
+    if ranked.is_empty() {
+        anyhow::bail!(
+            "no weak topics to recommend from: work on a few problems first \
+             so there's local history to rank tags by"
+        );
+    }
+
+    let cache = client.tag_cache().await?;
+    let all = client.get_all_problems().await?;
+    let by_id: HashMap<u32, &Problem> = all
+        .iter()
+        .map(|p| (p.stat.frontend_question_id, p))
+        .collect();
+    // Anything with metadata or a progress record has been seen already
+    let seen: HashSet<u32> = metas
+        .iter()
+        .map(|m| m.frontend_id)
+        .chain(progress.problems.keys().copied())
+        .collect();
+
+    let mut picks: Vec<(&Problem, String)> = Vec::new();
+    for (tag, tag_stat) in &ranked {
+        let Some(ids) = cache.problems_with(tag) else {
+            continue;
+        };
+        let mut candidates: Vec<&Problem> = ids
+            .iter()
+            .filter(|id| !seen.contains(id))
+            .filter_map(|id| by_id.get(id).copied())
+            .filter(|p| !p.paid_only)
+            .collect();
+        // Closest to the target level first; among equals, the most-solved
+        // problems are the canonical practice for a topic
+        let target = tag_stat.target_level();
+        candidates.sort_by_key(|p| {
+            (
+                (p.difficulty.level - target).abs(),
+                p.difficulty.level,
+                std::cmp::Reverse(p.stat.total_acs),
+            )
+        });
+        for problem in candidates.into_iter().take(PER_TAG) {
+            if picks.len() == RECOMMENDATIONS {
+                break;
+            }
+            picks.push((problem, tag_stat.reason(tag)));
+        }
+        if picks.len() == RECOMMENDATIONS {
+            break;
+        }
+    }
+    if picks.is_empty() {
+        anyhow::bail!("the tag index has no unattempted free problems for your weakest topics");
+    }
+    // Ramp: easiest suggestion first, regardless of which tag it came from
+    picks.sort_by_key(|(p, _)| p.difficulty.level);
+
+    println!(
+        "{}",
+        "Recommended next problems (weakest topics first):".bold()
+    );
+    for (problem, reason) in &picks {
+        println!(
+            "  {:>5} {} [{}]",
+            problem.stat.frontend_question_id,
+            problem.stat.question_title(),
+            level_name(problem.difficulty.level)
+        );
+        println!("        {}", format!("why: {reason}").cyan());
+    }
+    println!("Start one with: leetcode-cli pick -i <id>");
+    Ok(())
+}
+
+/// Local evidence about one tag.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct TagStats {
+    /// Problems in the workspace carrying the tag.
+    pub(crate) downloaded: u32,
+    /// How many of those are solved.
+    pub(crate) solved: u32,
+    /// Total attempts (failed test runs plus submissions) across them.
+    pub(crate) attempts: u32,
+}
+
+impl TagStats {
+    /// Fraction of the tag's downloaded problems that are solved.
+    fn solve_rate(self) -> f64 {
+        f64::from(self.solved) / f64::from(self.downloaded)
+    }
+
+    /// Average attempts per downloaded problem carrying the tag.
+    fn avg_attempts(self) -> f64 {
+        f64::from(self.attempts) / f64::from(self.downloaded)
+    }
+
+    /// The difficulty level to aim practice at.
+    fn target_level(self) -> i32 {
+        if self.solve_rate() < 0.5 {
+            DifficultyLevel::Easy.level()
+        } else {
+            DifficultyLevel::Medium.level()
+        }
+    }
+
+    /// The evidence that flagged the tag, shown with each pick.
+    fn reason(self, tag: &str) -> String {
+        format!(
+            "{tag}: solved {}/{} locally, {:.1} attempts per problem",
+            self.solved,
+            self.downloaded,
+            self.avg_attempts()
+        )
+    }
+}
+
+/// Per-tag stats built from the workspace metadata and progress database,
+/// keyed by tag slug.
+pub(crate) fn tag_stats(metas: &[ProblemMeta], progress: &Progress) -> BTreeMap<String, TagStats> {
+    let mut stats: BTreeMap<String, TagStats> = BTreeMap::new();
+    for meta in metas {
+        let solved = progress.is_solved(meta.frontend_id);
+        let attempts = progress.attempts(meta.frontend_id);
+        for tag in &meta.tags {
+            let entry = stats.entry(crate::tags::normalize_tag(tag)).or_default();
+            entry.downloaded += 1;
+            entry.attempts += attempts;
+            if solved {
+                entry.solved += 1;
+            }
+        }
+    }
+    stats
+}
+
+/// Tags ranked weakest first: lowest solve rate, then most attempts per
+/// problem, then name for a stable order. Tags with everything solved on
+/// one attempt each aren't weak and are dropped.
+pub(crate) fn weakest_tags(stats: &BTreeMap<String, TagStats>) -> Vec<(String, TagStats)> {
+    let mut ranked: Vec<(String, TagStats)> = stats
+        .iter()
+        .filter(|(_, s)| s.solved < s.downloaded || s.avg_attempts() > 1.0)
+        .map(|(tag, s)| (tag.clone(), *s))
+        .collect();
+    ranked.sort_by(|a, b| {
+        a.1.solve_rate()
+            .partial_cmp(&b.1.solve_rate())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(
+                b.1.avg_attempts()
+                    .partial_cmp(&a.1.avg_attempts())
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    ranked
+}
+
+/// The display name of a numeric difficulty level.
+fn level_name(level: i32) -> &'static str {
+    match DifficultyLevel::try_from(level) {
+        Ok(DifficultyLevel::Easy) => "Easy",
+        Ok(DifficultyLevel::Medium) => "Medium",
+        Ok(DifficultyLevel::Hard) => "Hard",
+        Err(_) => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::SolveStatus;
+
+    fn make_meta(id: u32, tags: &[&str]) -> ProblemMeta {
+        ProblemMeta {
+            id,
+            frontend_id: id,
+            slug: format!("problem-{id}"),
+            title: format!("Problem {id}"),
+            difficulty: "Easy".to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            downloaded_at: 1_700_000_000,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_tag_stats_counts_solves_and_attempts() {
+        let metas = vec![
+            make_meta(1, &["Array", "Hash Table"]),
+            make_meta(2, &["Array"]),
+        ];
+        let mut progress = Progress::default();
+        progress.record(1, "problem-1", SolveStatus::Solved, "submit");
+        progress.record_submission(1, "problem-1", true);
+        progress.record_test_failure(2, "problem-2");
+        progress.record_test_failure(2, "problem-2");
+
+        let stats = tag_stats(&metas, &progress);
+        assert_eq!(
+            stats["array"],
+            TagStats {
+                downloaded: 2,
+                solved: 1,
+                attempts: 3,
+            }
+        );
+        assert_eq!(
+            stats["hash-table"],
+            TagStats {
+                downloaded: 1,
+                solved: 1,
+                attempts: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_weakest_tags_ranks_by_rate_then_attempts() {
+        let mut stats = BTreeMap::new();
+        // Unsolved: weakest
+        stats.insert(
+            "graph".to_string(),
+            TagStats {
+                downloaded: 2,
+                solved: 0,
+                attempts: 1,
+            },
+        );
+        // Half solved with many attempts: next
+        stats.insert(
+            "dynamic-programming".to_string(),
+            TagStats {
+                downloaded: 2,
+                solved: 1,
+                attempts: 6,
+            },
+        );
+        // Fully solved but hard-won: still listed, last
+        stats.insert(
+            "array".to_string(),
+            TagStats {
+                downloaded: 2,
+                solved: 2,
+                attempts: 5,
+            },
+        );
+        // Fully solved first try: not weak
+        stats.insert(
+            "hash-table".to_string(),
+            TagStats {
+                downloaded: 2,
+                solved: 2,
+                attempts: 2,
+            },
+        );
+
+        let ranked = weakest_tags(&stats);
+        let names: Vec<&str> = ranked.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(names, vec!["graph", "dynamic-programming", "array"]);
+    }
+
+    #[test]
+    fn test_target_level_ramps_with_solve_rate() {
+        let shaky = TagStats {
+            downloaded: 4,
+            solved: 1,
+            attempts: 9,
+        };
+        assert_eq!(shaky.target_level(), 1);
+        let steady = TagStats {
+            downloaded: 4,
+            solved: 3,
+            attempts: 5,
+        };
+        assert_eq!(steady.target_level(), 2);
+    }
+
+    #[test]
+    fn test_reason_mentions_the_evidence() {
+        let stats = TagStats {
+            downloaded: 3,
+            solved: 1,
+            attempts: 7,
+        };
+        assert_eq!(
+            stats.reason("graph"),
+            "graph: solved 1/3 locally, 2.3 attempts per problem"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Redo command - fresh re-attempt at an already-downloaded problem
+//!
+//! Stashes the current solution under `attempts/`, regenerates a pristine
+//! template in its place, and goes straight into a timeboxed solve
+//! session — the core loop for spaced-repetition re-solving without
+//! losing old attempts.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient, commands::find_solution_file, config::Config, meta::ProblemMeta,
+    template::CodeTemplate,
+};
+
+/// Archive the current solution and start a fresh attempt.
+pub async fn execute(client: &LeetCodeClient, id: u32, timebox: Option<String>) -> Result<()> {
+    let meta = match ProblemMeta::load(id)? {
+        Some(meta) => meta,
+        None => anyhow::bail!(
+            "no metadata for problem {id}: run 'leetcode-cli migrate' to index \
+             pre-existing solutions"
+        ),
+    };
+    if meta.language != "rust" {
+        anyhow::bail!(
+            "redo only supports Rust solutions (problem {id} uses {})",
+            meta.language
+        );
+    }
+    let solution_file = find_solution_file(id, None)?;
+
+    // Stash the current attempt before overwriting the file
+    let attempts_dir = PathBuf::from("attempts").join(meta.module_name());
+    std::fs::create_dir_all(&attempts_dir)?;
+    let attempt = attempts_dir.join(format!("attempt_{}.rs", next_attempt_number(&attempts_dir)));
+    std::fs::copy(&solution_file, &attempt)?;
+    println!(
+        "{}",
+        format!("✓ Saved current solution as {}", attempt.display()).green()
+    );
+
+    // Regenerate a pristine template in place
+    let detail = client.get_problem_detail(&meta.slug).await?;
+    let config = Config::load()?;
+    CodeTemplate::new(&detail)
+        .with_config(config.template.clone())
+        .write_rust_template(&solution_file)?;
+    println!(
+        "{}",
+        format!("✓ Fresh template written to {}", solution_file.display()).green()
+    );
+
+    // Straight into a timeboxed session
+    crate::commands::solve::execute(client, id, timebox).await
+}
+
+/// The next free attempt number in the attempts directory, starting at 1.
+fn next_attempt_number(dir: &Path) -> u32 {
+    let mut highest = 0;
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(n) = name
+                .strip_prefix("attempt_")
+                .and_then(|rest| rest.strip_suffix(".rs"))
+                .and_then(|n| n.parse::<u32>().ok())
+            {
+                highest = highest.max(n);
+            }
+        }
+    }
+    highest + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_next_attempt_number_empty_dir() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(next_attempt_number(dir.path()), 1);
+    }
+
+    #[test]
+    fn test_next_attempt_number_skips_used() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("attempt_1.rs"), "").unwrap();
+        std::fs::write(dir.path().join("attempt_3.rs"), "").unwrap();
+        std::fs::write(dir.path().join("notes.md"), "").unwrap();
+        assert_eq!(next_attempt_number(dir.path()), 4);
+    }
+
+    #[test]
+    fn test_next_attempt_number_missing_dir() {
+        assert_eq!(next_attempt_number(Path::new("/nonexistent/attempts")), 1);
+    }
+}
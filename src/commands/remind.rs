@@ -0,0 +1,145 @@
+//! Remind command - Daily-challenge reminder, one-shot or as a daemon
+//!
+//! Checks whether today's daily challenge is solved per the local progress
+//! database and fires a desktop notification if not. Without `--at` it
+//! checks once and exits (for cron or a systemd timer); with `--at HH:MM`
+//! it keeps running and checks at that time every day (for a systemd or
+//! launchd service).
+
+use std::time::Duration;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::send_notification,
+    progress::{Progress, now_ts},
+};
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Check the daily challenge once, or daily at `--at HH:MM` (UTC)
+pub async fn execute(client: &LeetCodeClient, at: Option<String>) -> Result<()> {
+    let Some(ref spec) = at else {
+        return check(client).await;
+    };
+    let (hour, minute) = parse_clock_time(spec)
+        .ok_or_else(|| anyhow::anyhow!("invalid time '{spec}': expected HH:MM, e.g. 20:00"))?;
+
+    // The daily challenge rotates at midnight UTC, so the schedule is UTC
+    // too — a reminder that drifts with DST could fire for the wrong day
+    loop {
+        let wait = seconds_until_next(now_ts(), hour, minute);
+        println!(
+            "{}",
+            format!(
+                "Next check at {hour:02}:{minute:02} UTC ({})",
+                super::solve::format_duration(Duration::from_secs(wait))
+            )
+            .cyan()
+        );
+        tokio::time::sleep(Duration::from_secs(wait)).await;
+        // A daemon shouldn't die on a transient network error
+        if let Err(e) = check(client).await {
+            println!("{}", format!("! daily challenge check failed: {e}").yellow());
+        }
+    }
+}
+
+/// Check today's daily challenge against the local progress database and
+/// notify if it's still unsolved.
+async fn check(client: &LeetCodeClient) -> Result<()> {
+    let challenge = client.get_daily_challenge().await?;
+    let progress = Progress::load()?;
+
+    if progress.is_solved(challenge.frontend_id) {
+        println!(
+            "{}",
+            format!(
+                "✓ Daily challenge {} solved: {}. {}",
+                challenge.date, challenge.frontend_id, challenge.title
+            )
+            .green()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "! Daily challenge {} not solved yet: {}. {}",
+            challenge.date, challenge.frontend_id, challenge.title
+        )
+        .yellow()
+    );
+    println!("  https://leetcode.com/problems/{}", challenge.slug);
+    send_notification(
+        "leetcode-cli",
+        &format!(
+            "Daily challenge not solved yet: {}. {}",
+            challenge.frontend_id, challenge.title
+        ),
+    );
+    Ok(())
+}
+
+/// Parse a wall-clock time like "20:00" into (hour, minute).
+pub(crate) fn parse_clock_time(spec: &str) -> Option<(u64, u64)> {
+    let (hour, minute) = spec.trim().split_once(':')?;
+    let hour: u64 = hour.parse().ok()?;
+    let minute: u64 = minute.parse().ok()?;
+    if hour < 24 && minute < 60 {
+        Some((hour, minute))
+    } else {
+        None
+    }
+}
+
+/// Seconds from `now` until the next occurrence of `hour:minute` UTC —
+/// later today if it hasn't passed yet, otherwise tomorrow.
+pub(crate) fn seconds_until_next(now: u64, hour: u64, minute: u64) -> u64 {
+    let day_start = now - now % SECS_PER_DAY;
+    let mut target = day_start + hour * 3600 + minute * 60;
+    if target <= now {
+        target += SECS_PER_DAY;
+    }
+    target - now
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clock_time() {
+        assert_eq!(parse_clock_time("20:00"), Some((20, 0)));
+        assert_eq!(parse_clock_time(" 8:30 "), Some((8, 30)));
+        assert_eq!(parse_clock_time("00:00"), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_parse_clock_time_invalid() {
+        assert_eq!(parse_clock_time("24:00"), None);
+        assert_eq!(parse_clock_time("20:60"), None);
+        assert_eq!(parse_clock_time("20"), None);
+        assert_eq!(parse_clock_time("eight:00"), None);
+        assert_eq!(parse_clock_time(""), None);
+    }
+
+    #[test]
+    fn test_seconds_until_next() {
+        let midnight = 20_000 * SECS_PER_DAY;
+
+        // Later today
+        assert_eq!(seconds_until_next(midnight, 20, 0), 20 * 3600);
+        assert_eq!(seconds_until_next(midnight + 19 * 3600, 20, 0), 3600);
+
+        // Already passed (or exactly now): tomorrow
+        assert_eq!(seconds_until_next(midnight + 21 * 3600, 20, 0), 23 * 3600);
+        assert_eq!(
+            seconds_until_next(midnight + 20 * 3600, 20, 0),
+            SECS_PER_DAY
+        );
+    }
+}
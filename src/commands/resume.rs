@@ -0,0 +1,134 @@
+//! Resume command - restore an interrupted session
+//!
+//! Reads the persisted session state back after a dead terminal: settles
+//! any submission polls that never finished, reopens the editor on the
+//! problem that was being worked, and restarts the clock with whatever
+//! time the old timebox had left.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{find_solution_file, print_submission_result, send_notification},
+    progress::Progress,
+    session::Session,
+};
+
+/// Restore an interrupted solve, interview, or submit session.
+pub async fn execute(client: &LeetCodeClient) -> Result<()> {
+    let Some(mut session) = Session::load()? else {
+        println!("{}", "No interrupted session found.".yellow());
+        let queue = crate::queue::PracticeQueue::load()?;
+        let remaining = queue.remaining(&Progress::load()?).len();
+        if remaining > 0 {
+            println!("  {remaining} problem(s) still queued — try: leetcode-cli queue --next");
+        }
+        return Ok(());
+    };
+    let id = session.problem_id;
+
+    // Settle submission polls the dead terminal never finished
+    if !session.pending_submissions.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "Checking {} pending submission(s)...",
+                session.pending_submissions.len()
+            )
+            .cyan()
+        );
+        let mut unresolved = Vec::new();
+        for submission_id in session.pending_submissions.clone() {
+            match client.check_submission(submission_id).await {
+                Ok(result) => {
+                    print_submission_result(&result);
+                    // The verdict reaches the attempt history like a
+                    // foreground submit would have
+                    let slug = crate::meta::ProblemMeta::load(id)?
+                        .map(|m| m.slug)
+                        .unwrap_or_else(|| format!("p{id:04}"));
+                    let mut progress = Progress::load()?;
+                    progress.record_submission(id, &slug, result.status_code == 10);
+                    if result.status_code == 10 {
+                        progress.record(id, &slug, crate::progress::SolveStatus::Solved, "submit");
+                    }
+                    progress.save()?;
+                }
+                Err(e) => {
+                    println!(
+                        "{}",
+                        format!("! could not check submission {submission_id}: {e}").yellow()
+                    );
+                    unresolved.push(submission_id);
+                }
+            }
+        }
+        session.pending_submissions = unresolved;
+        session.save()?;
+    }
+
+    let now = crate::progress::now_ts();
+    println!("{}", summary_line(&session, now).bold());
+    if let Some(secs) = session.remaining_secs(now) {
+        // Reopen the editor where work left off, then run out the clock
+        // like the original session would have
+        if let Ok(file) = find_solution_file(id, None) {
+            let editor = crate::config::Config::load()?.get_editor();
+            println!(
+                "{}",
+                format!("Reopening {} in {editor}...", file.display()).cyan()
+            );
+            std::process::Command::new(&editor).arg(&file).spawn()?;
+        }
+        tokio::time::sleep(Duration::from_secs(secs)).await;
+        println!("{}", "⏰ Time's up!".yellow().bold());
+        send_notification("leetcode-cli", &format!("Time's up for problem {id}"));
+    }
+    Session::clear()?;
+    Ok(())
+}
+
+/// One line saying what is being resumed and how much time is left.
+pub(crate) fn summary_line(session: &Session, now: u64) -> String {
+    let what = format!(
+        "Resuming {} session for problem {}",
+        session.mode, session.problem_id
+    );
+    match session.remaining_secs(now) {
+        Some(secs) => format!(
+            "{what}: {} left on the clock",
+            crate::commands::solve::format_duration(Duration::from_secs(secs))
+        ),
+        None if session.deadline > 0 => {
+            format!("{what}: the timebox already expired while the session was gone")
+        }
+        None => what,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_line() {
+        let session = Session::start("solve", 42, 1000);
+        assert_eq!(
+            summary_line(&session, 400),
+            "Resuming solve session for problem 42: 10m left on the clock"
+        );
+        assert_eq!(
+            summary_line(&session, 2000),
+            "Resuming solve session for problem 42: the timebox already expired \
+             while the session was gone"
+        );
+        // A submit-only session has no timer to report
+        assert_eq!(
+            summary_line(&Session::start("submit", 7, 0), 400),
+            "Resuming submit session for problem 7"
+        );
+    }
+}
@@ -0,0 +1,328 @@
+//! Serve command - JSON-RPC server over stdio for editor integrations
+//!
+//! Exposes the core operations (list, show, download, test, submit,
+//! status) as JSON-RPC 2.0 requests, one message per line on stdin and
+//! stdout, so a VS Code or Neovim plugin can drive the tool with
+//! structured requests instead of parsing CLI text.
+//!
+//! Responses and `progress` notifications are single lines starting with
+//! `{`. Human-oriented progress from the underlying commands may appear
+//! between them; clients should treat lines that don't parse as JSON as
+//! log output.
+
+use anyhow::Result;
+use serde_json::{Value, json};
+
+use crate::{api::LeetCodeClient, meta::ProblemMeta, problem::DifficultyLevel};
+
+/// JSON-RPC 2.0 error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// Serve JSON-RPC requests over stdin/stdout until EOF
+pub async fn execute(client: &LeetCodeClient, stdio: bool) -> Result<()> {
+    if !stdio {
+        anyhow::bail!("serve currently only supports --stdio");
+    }
+
+    let stdin = std::io::stdin();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if std::io::BufRead::read_line(&mut stdin.lock(), &mut line)? == 0 {
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = handle_line(client, &line).await;
+        println!("{response}");
+    }
+}
+
+/// Handle one request line and build the response message.
+async fn handle_line(client: &LeetCodeClient, line: &str) -> Value {
+    let (id, method, params) = match parse_request(line) {
+        Ok(parts) => parts,
+        Err(response) => return response,
+    };
+    match dispatch(client, &method, &params).await {
+        Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+        Err((code, message)) => error_response(id, code, &message),
+    }
+}
+
+/// Split a request line into its id, method, and params, or produce the
+/// error response for a malformed one.
+fn parse_request(line: &str) -> Result<(Value, String, Value), Value> {
+    let request: Value = serde_json::from_str(line)
+        .map_err(|e| error_response(Value::Null, PARSE_ERROR, &format!("invalid JSON: {e}")))?;
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let method = match request.get("method").and_then(Value::as_str) {
+        Some(method) => method.to_string(),
+        None => {
+            return Err(error_response(
+                id,
+                INVALID_REQUEST,
+                "missing 'method' field",
+            ));
+        }
+    };
+    let params = request.get("params").cloned().unwrap_or_else(|| json!({}));
+    Ok((id, method, params))
+}
+
+/// Run one method and build its result value. Also reused by the MCP
+/// server, whose tools map onto these methods.
+pub(crate) async fn dispatch(
+    client: &LeetCodeClient,
+    method: &str,
+    params: &Value,
+) -> Result<Value, (i64, String)> {
+    match method {
+        "list" => list(client, params).await,
+        "show" => show(client, require_id(params)?).await,
+        "download" => download(client, require_id(params)?, params).await,
+        "test" => test(require_id(params)?),
+        "submit" => submit(client, require_id(params)?).await,
+        "status" => status(),
+        _ => Err((METHOD_NOT_FOUND, format!("unknown method '{method}'"))),
+    }
+}
+
+/// The mandatory numeric `id` parameter.
+fn require_id(params: &Value) -> Result<u32, (i64, String)> {
+    params
+        .get("id")
+        .and_then(Value::as_u64)
+        .map(|id| id as u32)
+        .ok_or_else(|| (INVALID_PARAMS, "missing numeric 'id' parameter".to_string()))
+}
+
+async fn list(client: &LeetCodeClient, params: &Value) -> Result<Value, (i64, String)> {
+    let filter = crate::api::ProblemFilter::new()
+        .difficulty(params.get("difficulty").and_then(Value::as_str))
+        .status(params.get("status").and_then(Value::as_str))
+        .paid(params.get("paid").and_then(Value::as_bool));
+    let problems = client.get_all_problems().await.map_err(internal)?;
+    let rows: Vec<Value> = problems
+        .iter()
+        .filter(|p| filter.matches(p))
+        .map(problem_row)
+        .collect();
+    Ok(json!(rows))
+}
+
+async fn show(client: &LeetCodeClient, id: u32) -> Result<Value, (i64, String)> {
+    let problem = lookup(client, id).await?;
+    let detail = client
+        .get_problem_detail(&problem.stat.question_title_slug())
+        .await
+        .map_err(internal)?;
+    Ok(json!({
+        "id": id,
+        "title": detail.title,
+        "slug": detail.title_slug,
+        "difficulty": detail.difficulty,
+        "content": detail.clean_content(),
+        "hints": detail.hints.clone().unwrap_or_default(),
+        "tags": detail
+            .topic_tags
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|t| t.slug.clone())
+            .collect::<Vec<_>>(),
+    }))
+}
+
+async fn download(
+    client: &LeetCodeClient,
+    id: u32,
+    params: &Value,
+) -> Result<Value, (i64, String)> {
+    let problem = lookup(client, id).await?;
+    notify("progress", json!({"message": format!("downloading problem {id}")}));
+    let lang = params.get("lang").and_then(Value::as_str);
+    crate::commands::pick::download_problem_with_lang(client, &problem, lang, false)
+        .await
+        .map_err(internal)?;
+    let meta = load_meta(id)?;
+    Ok(json!({"id": id, "path": meta.solution_path()}))
+}
+
+fn test(id: u32) -> Result<Value, (i64, String)> {
+    let meta = load_meta(id)?;
+    if meta.language != "rust" {
+        return Err((
+            INVALID_PARAMS,
+            format!("the test method only supports Rust solutions, not {}", meta.language),
+        ));
+    }
+    let config = crate::config::Config::load().map_err(internal)?;
+    let runner = crate::commands::test::TestRunner::from_config(&config);
+    notify("progress", json!({"message": format!("running tests for problem {id}")}));
+    let mut command = std::process::Command::new("cargo");
+    command
+        .args(runner.cargo_args())
+        .arg(format!("{}::", meta.module_name()));
+    if let Some(dir) = config.target_dir {
+        command.env("CARGO_TARGET_DIR", dir);
+    }
+    let output = command.output().map_err(|e| internal(e.into()))?;
+    Ok(json!({
+        "passed": output.status.success(),
+        "stdout": String::from_utf8_lossy(&output.stdout),
+        "stderr": String::from_utf8_lossy(&output.stderr),
+    }))
+}
+
+async fn submit(client: &LeetCodeClient, id: u32) -> Result<Value, (i64, String)> {
+    let file = crate::commands::find_solution_file(id, None).map_err(internal)?;
+    notify("progress", json!({"message": format!("submitting problem {id}")}));
+    let result = client.submit(id, &file).await.map_err(internal)?;
+    Ok(json!({
+        "accepted": result.status_code == 10,
+        "status": result.status_msg,
+        "runtime": result.status_runtime,
+        "memory": result.status_memory,
+        "total_correct": result.total_correct,
+        "total_testcases": result.total_testcases,
+    }))
+}
+
+fn status() -> Result<Value, (i64, String)> {
+    let progress = crate::progress::Progress::load().map_err(internal)?;
+    let solved = progress.problems.keys().filter(|id| progress.is_solved(**id)).count();
+    let problems: Value = progress
+        .problems
+        .iter()
+        .map(|(id, p)| {
+            (
+                id.to_string(),
+                json!({
+                    "slug": p.slug,
+                    "status": p.status,
+                    "source": p.source,
+                }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into();
+    Ok(json!({
+        "solved": solved,
+        "attempting": progress.problems.len() - solved,
+        "problems": problems,
+    }))
+}
+
+/// Look up a problem by frontend ID.
+async fn lookup(client: &LeetCodeClient, id: u32) -> Result<crate::problem::Problem, (i64, String)> {
+    client
+        .get_problem_by_id(id)
+        .await
+        .map_err(internal)?
+        .ok_or_else(|| (INVALID_PARAMS, format!("problem {id} not found")))
+}
+
+/// Load problem metadata, mapping its absence to an invalid-params error.
+fn load_meta(id: u32) -> Result<ProblemMeta, (i64, String)> {
+    ProblemMeta::load(id)
+        .map_err(internal)?
+        .ok_or_else(|| (INVALID_PARAMS, format!("problem {id} is not downloaded")))
+}
+
+/// One list row for a problem.
+fn problem_row(problem: &crate::problem::Problem) -> Value {
+    let difficulty = match DifficultyLevel::try_from(problem.difficulty.level) {
+        Ok(level) => level.name(),
+        Err(_) => "Unknown",
+    };
+    json!({
+        "id": problem.stat.frontend_question_id,
+        "title": problem.stat.question_title(),
+        "slug": problem.stat.question_title_slug(),
+        "difficulty": difficulty,
+        "paid_only": problem.paid_only,
+        "status": problem.status,
+    })
+}
+
+/// Emit a JSON-RPC notification on stdout.
+fn notify(method: &str, params: Value) {
+    println!("{}", json!({"jsonrpc": "2.0", "method": method, "params": params}));
+}
+
+/// Build a JSON-RPC error response.
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message},
+    })
+}
+
+/// Map an internal failure onto the JSON-RPC internal error code.
+fn internal(e: anyhow::Error) -> (i64, String) {
+    (INTERNAL_ERROR, e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_valid() {
+        let (id, method, params) =
+            parse_request(r#"{"jsonrpc":"2.0","id":7,"method":"show","params":{"id":1}}"#)
+                .unwrap();
+        assert_eq!(id, json!(7));
+        assert_eq!(method, "show");
+        assert_eq!(params, json!({"id": 1}));
+    }
+
+    #[test]
+    fn test_parse_request_defaults_params() {
+        let (_, _, params) = parse_request(r#"{"id":1,"method":"status"}"#).unwrap();
+        assert_eq!(params, json!({}));
+    }
+
+    #[test]
+    fn test_parse_request_invalid_json() {
+        let response = parse_request("{not json").unwrap_err();
+        assert_eq!(response["error"]["code"], json!(PARSE_ERROR));
+        assert_eq!(response["id"], Value::Null);
+    }
+
+    #[test]
+    fn test_parse_request_missing_method() {
+        let response = parse_request(r#"{"id":3,"params":{}}"#).unwrap_err();
+        assert_eq!(response["error"]["code"], json!(INVALID_REQUEST));
+        assert_eq!(response["id"], json!(3));
+    }
+
+    #[test]
+    fn test_require_id() {
+        assert_eq!(require_id(&json!({"id": 42})).unwrap(), 42);
+        assert_eq!(
+            require_id(&json!({"id": "42"})).unwrap_err().0,
+            INVALID_PARAMS
+        );
+        assert_eq!(require_id(&json!({})).unwrap_err().0, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_error_response_shape() {
+        let response = error_response(json!(5), METHOD_NOT_FOUND, "unknown method 'frobnicate'");
+        assert_eq!(response["jsonrpc"], json!("2.0"));
+        assert_eq!(response["id"], json!(5));
+        assert_eq!(response["error"]["code"], json!(METHOD_NOT_FOUND));
+        assert_eq!(
+            response["error"]["message"],
+            json!("unknown method 'frobnicate'")
+        );
+    }
+}
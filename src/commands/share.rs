@@ -0,0 +1,183 @@
+//! Share command - Share a solution as a Gist or via the clipboard
+//!
+//! `share <id> --gist` uploads the cleaned solution plus a small README to
+//! a secret GitHub Gist (token from `GITHUB_TOKEN` or the config file) and
+//! prints the URL; `--clipboard` copies the cleaned code to the system
+//! clipboard. Handy for asking for code review.
+
+use std::process::Stdio;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient, commands::find_solution_file, config::Config, meta::ProblemMeta,
+};
+
+/// Share a solution as a GitHub Gist and/or via the clipboard
+pub async fn execute(id: u32, gist: bool, clipboard: bool) -> Result<()> {
+    if !gist && !clipboard {
+        anyhow::bail!("nothing to do: pass --gist and/or --clipboard");
+    }
+
+    let solution_file = find_solution_file(id, None)?;
+    let code = std::fs::read_to_string(&solution_file)?;
+    let cleaned =
+        LeetCodeClient::strip_local_attributes(&LeetCodeClient::extract_solution_code(&code));
+    let meta = ProblemMeta::load(id)?;
+
+    if clipboard {
+        copy_to_clipboard(&cleaned)?;
+        println!("{}", "✓ Copied cleaned solution to the clipboard".green());
+    }
+
+    if gist {
+        let token = github_token()?;
+        let file_name = solution_file
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| format!("p{id:04}.rs"));
+        let payload = gist_payload(id, meta.as_ref(), &file_name, &cleaned);
+
+        println!("{}", "Creating Gist...".cyan());
+        let response = reqwest::Client::new()
+            .post("https://api.github.com/gists")
+            .header("authorization", format!("Bearer {token}"))
+            .header("accept", "application/vnd.github+json")
+            .header("user-agent", "leetcode-cli")
+            .json(&payload)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            anyhow::bail!("failed to create Gist: HTTP {}", response.status());
+        }
+        let body: serde_json::Value = response.json().await?;
+        let url = body["html_url"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("unexpected Gist response: {body}"))?;
+        println!("{}", format!("✓ Gist created: {url}").green());
+    }
+    Ok(())
+}
+
+/// The GitHub token from the environment, falling back to the config file.
+fn github_token() -> Result<String> {
+    if let Ok(token) = std::env::var("GITHUB_TOKEN")
+        && !token.is_empty()
+    {
+        return Ok(token);
+    }
+    Config::load_file()?.github_token.ok_or_else(|| {
+        anyhow::anyhow!(
+            "no GitHub token: set GITHUB_TOKEN or 'github_token' in the config file"
+        )
+    })
+}
+
+/// The Gist creation payload: the solution file plus a README giving the
+/// problem context. Gists are created secret; sharing the URL still works.
+fn gist_payload(
+    id: u32,
+    meta: Option<&ProblemMeta>,
+    file_name: &str,
+    code: &str,
+) -> serde_json::Value {
+    let (title, readme) = match meta {
+        Some(meta) => (
+            format!("{id}. {}", meta.title),
+            format!(
+                "# {id}. {} ({})\n\nhttps://leetcode.com/problems/{}/\n",
+                meta.title, meta.difficulty, meta.slug
+            ),
+        ),
+        None => (
+            format!("LeetCode problem {id}"),
+            format!("# LeetCode problem {id}\n"),
+        ),
+    };
+    serde_json::json!({
+        "description": title,
+        "public": false,
+        "files": {
+            "README.md": {"content": readme},
+            file_name: {"content": code},
+        },
+    })
+}
+
+/// Copy text to the system clipboard, trying the usual helpers in turn:
+/// `pbcopy` on macOS, `wl-copy`/`xclip`/`xsel` elsewhere.
+fn copy_to_clipboard(text: &str) -> Result<()> {
+    #[cfg(target_os = "macos")]
+    let candidates: &[&[&str]] = &[&["pbcopy"]];
+    #[cfg(not(target_os = "macos"))]
+    let candidates: &[&[&str]] = &[
+        &["wl-copy"],
+        &["xclip", "-selection", "clipboard"],
+        &["xsel", "--clipboard", "--input"],
+    ];
+
+    for candidate in candidates {
+        let Ok(mut child) = std::process::Command::new(candidate[0])
+            .args(&candidate[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            use std::io::Write;
+            let _ = stdin.write_all(text.as_bytes());
+        }
+        if child.wait().is_ok_and(|status| status.success()) {
+            return Ok(());
+        }
+    }
+    anyhow::bail!("no clipboard helper found (tried pbcopy/wl-copy/xclip/xsel)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gist_payload_with_meta() {
+        let meta = ProblemMeta {
+            id: 1,
+            frontend_id: 1,
+            slug: "two-sum".to_string(),
+            title: "Two Sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: vec![],
+            downloaded_at: 0,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        };
+        let payload = gist_payload(1, Some(&meta), "p0001_two_sum.rs", "impl Solution {}");
+        assert_eq!(payload["description"], "1. Two Sum");
+        assert_eq!(payload["public"], false);
+        assert_eq!(
+            payload["files"]["p0001_two_sum.rs"]["content"],
+            "impl Solution {}"
+        );
+        let readme = payload["files"]["README.md"]["content"].as_str().unwrap();
+        assert!(readme.contains("# 1. Two Sum (Easy)"));
+        assert!(readme.contains("https://leetcode.com/problems/two-sum/"));
+    }
+
+    #[test]
+    fn test_gist_payload_without_meta() {
+        let payload = gist_payload(42, None, "p0042.rs", "code");
+        assert_eq!(payload["description"], "LeetCode problem 42");
+        assert!(
+            payload["files"]["README.md"]["content"]
+                .as_str()
+                .unwrap()
+                .contains("problem 42")
+        );
+    }
+}
@@ -3,40 +3,63 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::{api::LeetCodeClient, problem::DifficultyLevel};
+use crate::{api::LeetCodeClient, commands::check_statement_drift, meta::ProblemMeta};
 
 /// Show problem details
 pub async fn execute(client: &LeetCodeClient, id: u32) -> Result<()> {
-    let problem = client
-        .get_problem_by_id(id)
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("problem not found: ID {id}"))?;
+    let problem = client.get_problem_by_id(id).await?;
+
+    // Resolve the slug from the problem list, falling back to the local
+    // metadata file for problems downloaded previously
+    let slug = match (&problem, ProblemMeta::load(id)?) {
+        (Some(p), _) => p.stat.question_title_slug(),
+        (None, Some(meta)) => meta.slug,
+        (None, None) => {
+            return Err(crate::error::CliError::ProblemNotFound(format!("ID {id}")).into());
+        }
+    };
 
-    let detail = client
-        .get_problem_detail(&problem.stat.question_title_slug())
-        .await?;
+    let detail = client.get_problem_detail(&slug).await?;
+    check_statement_drift(id, &detail.clean_content())?;
 
     println!("\n{}", "═".repeat(80).cyan());
-    println!(
-        "{} {}. {}",
-        "Problem".bold(),
-        problem.stat.frontend_question_id,
-        problem.stat.question_title().bold()
-    );
+    println!("{} {}. {}", "Problem".bold(), id, detail.title.bold());
     println!("{}", "═".repeat(80).cyan());
 
-    let diff_str = match DifficultyLevel::try_from(problem.difficulty.level) {
-        Ok(DifficultyLevel::Easy) => "Easy".green(),
-        Ok(DifficultyLevel::Medium) => "Medium".yellow(),
-        Ok(DifficultyLevel::Hard) => "Hard".red(),
-        Err(_) => "Unknown".normal(),
+    let diff_str = match detail.difficulty.as_str() {
+        "Easy" => "Easy".green(),
+        "Medium" => "Medium".yellow(),
+        "Hard" => "Hard".red(),
+        other => other.normal(),
     };
     println!("{} {}", "Difficulty:".bold(), diff_str);
-    println!(
-        "{} {:.1}%",
-        "Acceptance Rate:".bold(),
-        problem.stat.total_acs as f64 / problem.stat.total_submitted as f64 * 100.0
-    );
+    if let Some(ref p) = problem {
+        println!(
+            "{} {:.1}%",
+            "Acceptance Rate:".bold(),
+            p.stat.total_acs as f64 / p.stat.total_submitted as f64 * 100.0
+        );
+    }
+    if let Some(line) = render_votes_line(&detail) {
+        println!("{} {}", "Votes:".bold(), line);
+    }
+    if let Some(liked) = detail.is_liked {
+        let reaction = if liked {
+            "liked".green()
+        } else {
+            "disliked".red()
+        };
+        println!("{} you {} this problem", "Reaction:".bold(), reaction);
+    }
+    if let Some(stats) = detail.parse_stats() {
+        println!(
+            "{} {} accepted / {} submissions ({})",
+            "Stats:".bold(),
+            stats.total_accepted,
+            stats.total_submission,
+            stats.ac_rate
+        );
+    }
     println!("{}", "─".repeat(80).cyan());
 
     // Print description
@@ -53,10 +76,22 @@ pub async fn execute(client: &LeetCodeClient, id: u32) -> Result<()> {
     Ok(())
 }
 
+/// Render the likes/dislikes line with the like ratio, e.g.
+/// "👍 1000 / 👎 50 (95.2% liked)". `None` if votes weren't fetched.
+pub(crate) fn render_votes_line(detail: &crate::problem::ProblemDetail) -> Option<String> {
+    let likes = detail.likes?;
+    let dislikes = detail.dislikes?;
+    let mut line = format!("👍 {likes} / 👎 {dislikes}");
+    if let Some(ratio) = detail.like_ratio() {
+        line.push_str(&format!(" ({:.1}% liked)", ratio * 100.0));
+    }
+    Some(line)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::problem::{CodeSnippet, Difficulty, Problem, ProblemDetail, Stat};
+    use crate::problem::{CodeSnippet, Difficulty, DifficultyLevel, Problem, ProblemDetail, Stat};
 
     fn create_test_problem(id: u32, title: &str, slug: &str, level: i32) -> Problem {
         Problem {
@@ -98,6 +133,10 @@ mod tests {
             }]),
             hints: Some(vec!["Hint 1".to_string(), "Hint 2".to_string()]),
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         }
     }
 
@@ -153,6 +192,32 @@ mod tests {
         assert!(snippet.unwrap().contains("impl Solution"));
     }
 
+    #[test]
+    fn test_render_votes_line() {
+        let mut detail = create_test_problem_detail("1", "Test", "test");
+        detail.likes = Some(950);
+        detail.dislikes = Some(50);
+        let line = render_votes_line(&detail).unwrap();
+        assert!(line.contains("950"));
+        assert!(line.contains("50"));
+        assert!(line.contains("95.0% liked"));
+    }
+
+    #[test]
+    fn test_render_votes_line_missing_votes() {
+        let detail = create_test_problem_detail("1", "Test", "test");
+        assert!(render_votes_line(&detail).is_none());
+    }
+
+    #[test]
+    fn test_render_votes_line_zero_votes_omits_ratio() {
+        let mut detail = create_test_problem_detail("1", "Test", "test");
+        detail.likes = Some(0);
+        detail.dislikes = Some(0);
+        let line = render_votes_line(&detail).unwrap();
+        assert!(!line.contains("liked"));
+    }
+
     #[test]
     fn test_acceptance_rate_calculation() {
         let problem = Problem {
@@ -0,0 +1,207 @@
+//! Solve command - Pomodoro-style solve session for a problem
+//!
+//! Downloads the problem if needed, opens it in the configured editor,
+//! counts down a timebox, and sends a desktop notification when time is up.
+//! Afterwards the user can reveal hints or jump to the editorial.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{find_solution_file, pick::download_problem, prompt_confirm, send_notification},
+    config::Config,
+};
+
+/// Run a timeboxed solve session for a problem
+pub async fn execute(client: &LeetCodeClient, id: u32, timebox: Option<String>) -> Result<()> {
+    let duration = match timebox {
+        Some(ref spec) => parse_timebox(spec)
+            .ok_or_else(|| anyhow::anyhow!("invalid timebox '{spec}': expected e.g. 40m, 1h, 90s"))?,
+        None => Duration::from_secs(40 * 60),
+    };
+
+    let problem = client
+        .get_problem_by_id(id)
+        .await?
+        .ok_or_else(|| crate::error::CliError::ProblemNotFound(format!("ID {id}")))?;
+
+    // Download the problem if there is no local solution file yet
+    let solution_file = match find_solution_file(id, None) {
+        Ok(file) => file,
+        Err(_) => {
+            download_problem(client, &problem).await?;
+            find_solution_file(id, None)?
+        }
+    };
+
+    // Open the editor on the solution file (non-blocking so the timer runs)
+    let config = Config::load()?;
+    let editor = config.get_editor();
+    println!(
+        "{}",
+        format!("Opening {} in {editor}...", solution_file.display()).cyan()
+    );
+    std::process::Command::new(&editor)
+        .arg(&solution_file)
+        .spawn()?;
+
+    println!(
+        "{}",
+        format!(
+            "⏱ Timebox started: {} — good luck!",
+            format_duration(duration)
+        )
+        .bold()
+        .cyan()
+    );
+    // Persist the session so `resume` can restore the clock if the
+    // terminal dies mid-timebox
+    crate::session::Session::start("solve", id, crate::progress::now_ts() + duration.as_secs())
+        .save()?;
+    tokio::time::sleep(duration).await;
+
+    println!("{}", "⏰ Time's up!".yellow().bold());
+    send_notification(
+        "leetcode-cli",
+        &format!(
+            "Time's up for problem {id}: {}",
+            problem.stat.question_title()
+        ),
+    );
+    crate::session::Session::clear()?;
+
+    // No peeking while a mock interview is live
+    if crate::commands::interview::hints_blocked() {
+        println!(
+            "{}",
+            "! hints and the editorial are disabled during an interview session".yellow()
+        );
+        return Ok(());
+    }
+
+    // Offer a hint, then the editorial
+    let detail = client
+        .get_problem_detail(&problem.stat.question_title_slug())
+        .await?;
+
+    if let Some(ref hints) = detail.hints
+        && !hints.is_empty()
+    {
+        for (i, hint) in hints.iter().enumerate() {
+            if !prompt_confirm(&format!("\nReveal hint {}/{}? [Y/n]", i + 1, hints.len()))? {
+                break;
+            }
+            println!("{} {}", format!("Hint {}:", i + 1).bold().cyan(), hint);
+        }
+    } else {
+        println!("{}", "No hints available for this problem.".yellow());
+    }
+
+    if prompt_confirm("\nShow link to the editorial? [Y/n]")? {
+        println!(
+            "{} https://leetcode.com/problems/{}/editorial/",
+            "Editorial:".bold(),
+            problem.stat.question_title_slug()
+        );
+    }
+
+    Ok(())
+}
+
+/// Parse a timebox specification like "40m", "1h", "90s", or a bare number
+/// of minutes. Returns `None` if the spec is empty, zero, or malformed.
+pub(crate) fn parse_timebox(spec: &str) -> Option<Duration> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return None;
+    }
+
+    let (value, unit) = match spec.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((i, _)) => (&spec[..i], &spec[i..]),
+        None => (spec, "m"), // bare number defaults to minutes
+    };
+
+    let value: u64 = value.parse().ok()?;
+    if value == 0 {
+        return None;
+    }
+
+    let seconds = match unit {
+        "s" | "sec" => value,
+        "m" | "min" => value * 60,
+        "h" | "hr" => value * 3600,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Format a duration for display, e.g. "40m" or "1h 30m".
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(format!("{hours}h"));
+    }
+    if minutes > 0 {
+        parts.push(format!("{minutes}m"));
+    }
+    if seconds > 0 || parts.is_empty() {
+        parts.push(format!("{seconds}s"));
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timebox_minutes() {
+        assert_eq!(parse_timebox("40m"), Some(Duration::from_secs(40 * 60)));
+        assert_eq!(parse_timebox("25min"), Some(Duration::from_secs(25 * 60)));
+    }
+
+    #[test]
+    fn test_parse_timebox_hours_and_seconds() {
+        assert_eq!(parse_timebox("1h"), Some(Duration::from_secs(3600)));
+        assert_eq!(parse_timebox("2hr"), Some(Duration::from_secs(7200)));
+        assert_eq!(parse_timebox("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_timebox("30sec"), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_parse_timebox_bare_number_defaults_to_minutes() {
+        assert_eq!(parse_timebox("40"), Some(Duration::from_secs(40 * 60)));
+    }
+
+    #[test]
+    fn test_parse_timebox_invalid() {
+        assert_eq!(parse_timebox(""), None);
+        assert_eq!(parse_timebox("0m"), None);
+        assert_eq!(parse_timebox("abc"), None);
+        assert_eq!(parse_timebox("40x"), None);
+        assert_eq!(parse_timebox("-5m"), None);
+    }
+
+    #[test]
+    fn test_parse_timebox_trims_whitespace() {
+        assert_eq!(parse_timebox(" 10m "), Some(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(40 * 60)), "40m");
+        assert_eq!(format_duration(Duration::from_secs(3600)), "1h");
+        assert_eq!(format_duration(Duration::from_secs(5400)), "1h 30m");
+        assert_eq!(format_duration(Duration::from_secs(90)), "1m 30s");
+        assert_eq!(format_duration(Duration::from_secs(0)), "0s");
+    }
+}
@@ -8,22 +8,611 @@ use colored::Colorize;
 use crate::{
     api::LeetCodeClient,
     commands::{find_solution_file, print_submission_result},
+    problem::TestConfig,
 };
 
 /// Submit solution to LeetCode
-pub async fn execute(client: &LeetCodeClient, id: u32, file: Option<PathBuf>) -> Result<()> {
+pub async fn execute(
+    client: &LeetCodeClient,
+    id: u32,
+    file: Option<PathBuf>,
+    force: bool,
+    no_wait: bool,
+    all_tests_locally_first: bool,
+) -> Result<()> {
     let solution_file = find_solution_file(id, file)?;
+    let config = crate::config::Config::load()?;
+
+    if solution_file.extension().is_some_and(|e| e == "rs") {
+        let code = std::fs::read_to_string(&solution_file)?;
+
+        // The judge only provides std and rand; anything else fails remotely
+        let external = external_crate_uses(&code);
+        if !external.is_empty() {
+            // Crates the user wired in via [template] dependencies are
+            // local-only conveniences; call them out so the fix is obvious
+            let configured: Vec<String> = config
+                .template
+                .dependency_specs()
+                .into_iter()
+                .map(|(name, _)| name)
+                .filter(|name| external.contains(name))
+                .collect();
+            let note = if configured.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "; {} configured in template.dependencies — inline those helpers first",
+                    configured.join(", ")
+                )
+            };
+            if force {
+                println!(
+                    "{}",
+                    format!(
+                        "! solution uses crates the judge doesn't provide: {}{note}",
+                        external.join(", ")
+                    )
+                    .yellow()
+                );
+            } else {
+                anyhow::bail!(
+                    "solution uses crates LeetCode doesn't provide: {} \
+                     (only std and rand are available; pass --force to submit anyway){}",
+                    external.join(", "),
+                    note
+                );
+            }
+        }
+
+        // Check the target method still matches the problem metadata; drift
+        // after refactoring is a guaranteed compile error on the judge
+        if let Some(problem) = client.get_problem_by_id(id).await? {
+            let detail = client
+                .get_problem_detail(&problem.stat.question_title_slug())
+                .await?;
+            let cfg = detail.parse_metadata().and_then(|m| m.test_config);
+            if let Some(ref cfg) = cfg {
+                let mismatches = signature_mismatches(&code, cfg);
+                if !mismatches.is_empty() {
+                    if force {
+                        println!(
+                            "{}",
+                            format!(
+                                "! solution signature drifted from the problem metadata: {}",
+                                mismatches.join("; ")
+                            )
+                            .yellow()
+                        );
+                    } else {
+                        anyhow::bail!(
+                            "solution signature drifted from the problem metadata: {} \
+                             (pass --force to submit anyway)",
+                            mismatches.join("; ")
+                        );
+                    }
+                }
+            }
+
+            // Local judge gate: compile the solution into a runner and put
+            // every example case through it before spending a submission.
+            // A judge that can't run (unsupported types, no rustc) only
+            // warns; a real verdict other than AC aborts the submit
+            if all_tests_locally_first {
+                match cfg {
+                    Some(ref cfg) => {
+                        println!(
+                            "{}",
+                            "Running the local judge over the example cases...".cyan()
+                        );
+                        match local_judge(&solution_file, id, problem.difficulty.level, &detail, cfg)
+                        {
+                            Ok(verdict) if verdict.is_accepted() => {
+                                println!("{}", format!("✓ Local judge: {verdict}").green());
+                            }
+                            Ok(verdict) => anyhow::bail!(
+                                "local judge: {verdict} (fix the solution, or submit without \
+                                 --all-tests-locally-first)"
+                            ),
+                            Err(e) => {
+                                println!("{}", format!("! local judge skipped: {e:#}").yellow());
+                            }
+                        }
+                    }
+                    None => println!(
+                        "{}",
+                        "! local judge skipped: problem metadata has no test config".yellow()
+                    ),
+                }
+            }
+        }
+    }
+
+    // Pre-submit toolchain check: code built with a newer local rustc can
+    // use std APIs LeetCode's judge doesn't have yet
+    if let Some(ref expected) = config.leetcode_rust_version
+        && solution_file.extension().is_some_and(|e| e == "rs")
+        && let Some(local) = local_rustc_version()
+        && local != *expected
+    {
+        println!(
+            "{}",
+            format!(
+                "! local rustc is {local} but LeetCode's judge runs {expected}; \
+                 newer std APIs may fail remotely"
+            )
+            .yellow()
+        );
+    }
+
+    // Edited constraints invalidate local assumptions; check for statement
+    // drift before spending a submission on a stale copy. The check is
+    // advisory — a failed fetch doesn't block the submit
+    if let Some(meta) = crate::meta::ProblemMeta::load(id)?
+        && crate::meta::ProblemMeta::load_description(id)?.is_some()
+        && let Ok(detail) = client.get_problem_detail(&meta.slug).await
+    {
+        crate::commands::check_statement_drift(id, &detail.clean_content())?;
+    }
 
     println!(
         "{}",
         format!("Submitting solution for problem {id}...").cyan()
     );
-    let result = client.submit(id, &solution_file).await?;
+    // Print the submission ID before polling so it survives a Ctrl-C
+    let submission_id = client.upload(id, &solution_file).await?;
+    println!(
+        "{}",
+        format!("✓ Uploaded (submission ID: {submission_id})").green()
+    );
+    if no_wait {
+        // Remember the pending poll so `resume` can settle it later
+        let mut session = crate::session::Session::load()?
+            .unwrap_or_else(|| crate::session::Session::start("submit", id, 0));
+        session.add_pending_submission(submission_id);
+        session.save()?;
+        println!("  Check the result with: leetcode-cli check {submission_id}");
+        return Ok(());
+    }
+
+    let result = client.check_submission(submission_id).await?;
     print_submission_result(&result);
 
+    // Count the submission and its verdict toward the attempt history
+    {
+        let slug = crate::meta::ProblemMeta::load(id)?
+            .map(|m| m.slug)
+            .unwrap_or_else(|| format!("p{id:04}"));
+        let mut progress = crate::progress::Progress::load()?;
+        progress.record_submission(id, &slug, result.status_code == 10);
+        progress.save()?;
+    }
+
+    // Accepted: snapshot the code that passed before the working file gets
+    // edited again
+    if result.status_code == 10 {
+        // Advisory — the distributions are a nicety on top of the verdict
+        if let Err(e) = show_distributions(client, submission_id, &result).await {
+            println!(
+                "{}",
+                format!("! couldn't fetch the runtime distribution: {e}").yellow()
+            );
+        }
+        match snapshot_accepted(id, &solution_file, &result) {
+            Ok(path) => println!("  Snapshot saved to {}", path.display()),
+            Err(e) => println!(
+                "{}",
+                format!("! failed to save accepted snapshot: {e}").yellow()
+            ),
+        }
+    }
+
+    // Streak guard: a submit late in the day is the last natural moment to
+    // catch an unsolved daily challenge before it rotates at midnight UTC.
+    // Advisory — a failed lookup never taints the submit that just happened
+    if let Some(hour) = config.daily_reminder_hour
+        && let Err(e) = remind_daily(client, id, hour).await
+    {
+        println!("{}", format!("! daily challenge check failed: {e}").yellow());
+    }
+
+    Ok(())
+}
+
+/// Judge the solution locally against the example cases: `test_cases.json`
+/// next to the solution file when present, the statement examples
+/// otherwise. Limits come from the problem metadata when recorded there,
+/// falling back to the difficulty defaults.
+fn local_judge(
+    solution_file: &std::path::Path,
+    id: u32,
+    level: i32,
+    detail: &crate::problem::ProblemDetail,
+    cfg: &TestConfig,
+) -> Result<crate::judge::Verdict> {
+    let cases_path = solution_file
+        .parent()
+        .unwrap_or_else(|| std::path::Path::new(""))
+        .join("test_cases.json");
+    let test_cases: Vec<crate::problem::TestCase> = if cases_path.exists() {
+        serde_json::from_str(&std::fs::read_to_string(&cases_path)?)?
+    } else {
+        detail.parse_test_cases()
+    };
+    if test_cases.is_empty() {
+        anyhow::bail!("no example test cases to judge against");
+    }
+    let cases = crate::judge::cases_from(&test_cases, cfg)?;
+    let meta = crate::meta::ProblemMeta::load(id)?;
+    let limits = crate::judge::Limits::for_problem(
+        level,
+        meta.as_ref().and_then(|m| m.time_limit_ms),
+        meta.as_ref().and_then(|m| m.memory_limit_mb),
+    );
+    crate::judge::run(solution_file, cfg, &cases, &limits)
+}
+
+/// After a submit at or past the configured UTC hour, check whether today's
+/// daily challenge is still unsolved and offer to download it.
+async fn remind_daily(client: &LeetCodeClient, submitted_id: u32, hour: u32) -> Result<()> {
+    if utc_hour(crate::progress::now_ts()) < u64::from(hour) {
+        return Ok(());
+    }
+    let challenge = client.get_daily_challenge().await?;
+    // Don't nag about the problem that was just submitted
+    if challenge.frontend_id == submitted_id {
+        return Ok(());
+    }
+    let progress = crate::progress::Progress::load()?;
+    if progress.is_solved(challenge.frontend_id) {
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "! Daily challenge not solved yet: {}. {}",
+            challenge.frontend_id, challenge.title
+        )
+        .yellow()
+    );
+    if crate::commands::prompt_confirm("Download it now? [Y/n]")? {
+        match client.get_problem_by_id(challenge.frontend_id).await? {
+            Some(problem) => crate::commands::pick::download_problem(client, &problem).await?,
+            None => println!("  https://leetcode.com/problems/{}", challenge.slug),
+        }
+    }
     Ok(())
 }
 
+/// The UTC hour of day of a Unix timestamp.
+fn utc_hour(ts: u64) -> u64 {
+    ts % 86_400 / 3_600
+}
+
+/// Copy an accepted solution into `accepted/<module>/<timestamp>_<runtime>`
+/// (keeping the solution file's extension) and record it, along with the
+/// judge's runtime/memory figures, in the progress database.
+fn snapshot_accepted(
+    id: u32,
+    solution_file: &std::path::Path,
+    result: &crate::api::SubmissionResult,
+) -> Result<PathBuf> {
+    let meta = crate::meta::ProblemMeta::load(id)?;
+    let module = meta.as_ref().map(|m| m.module_name()).unwrap_or_else(|| {
+        solution_file
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| format!("p{id:04}"))
+    });
+    let ext = solution_file
+        .extension()
+        .map(|e| e.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "rs".to_string());
+
+    let dir = PathBuf::from("accepted").join(&module);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "{}_{}.{ext}",
+        crate::meta::ProblemMeta::now(),
+        sanitize_runtime(&result.status_runtime)
+    ));
+    std::fs::copy(solution_file, &path)?;
+
+    let slug = meta.map(|m| m.slug).unwrap_or_else(|| module.clone());
+    let mut progress = crate::progress::Progress::load()?;
+    progress.record(id, &slug, crate::progress::SolveStatus::Solved, "submit");
+    progress.add_snapshot(id, &path.to_string_lossy());
+    progress.record_perf(
+        id,
+        crate::progress::PerfSample {
+            at: crate::meta::ProblemMeta::now(),
+            runtime: result.status_runtime.clone(),
+            memory: result.status_memory.clone(),
+            runtime_percentile: result.runtime_percentile,
+            memory_percentile: result.memory_percentile,
+        },
+    );
+    progress.save()?;
+
+    // Keep index.json and any generated badge in step
+    crate::workspace_index::refresh()?;
+    crate::commands::badge::refresh()?;
+
+    Ok(path)
+}
+
+/// Fetch the runtime and memory distributions of an accepted submission
+/// and render them as terminal histograms with my bucket highlighted —
+/// where the submission landed, not just the percentile it beat.
+pub(crate) async fn show_distributions(
+    client: &LeetCodeClient,
+    submission_id: i64,
+    result: &crate::api::SubmissionResult,
+) -> Result<()> {
+    let runtime = client.get_runtime_distribution(submission_id).await?;
+    if !runtime.is_empty() {
+        println!("\n{}", "Runtime distribution:".bold());
+        for line in render_histogram("ms", &runtime, parse_leading_number(&result.status_runtime)) {
+            println!("{line}");
+        }
+    }
+    let memory = client.get_memory_distribution(submission_id).await?;
+    if !memory.is_empty() {
+        println!("\n{}", "Memory distribution:".bold());
+        for line in render_histogram("KB", &memory, parse_memory_kb(&result.status_memory)) {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+/// How many buckets a histogram shows before eliding the slow tail.
+const HISTOGRAM_ROWS: usize = 20;
+/// Bar width of the tallest bucket, in characters.
+const HISTOGRAM_WIDTH: usize = 30;
+
+/// Render a `(bucket, percent)` distribution as histogram rows, bars
+/// scaled to the tallest bucket and mine marked. Buckets past the row cap
+/// are elided, except mine, which is always shown.
+pub(crate) fn render_histogram(unit: &str, dist: &[(u32, f64)], mine: Option<u32>) -> Vec<String> {
+    let max = dist.iter().map(|(_, pct)| *pct).fold(0.0_f64, f64::max);
+    let mine_bucket = mine.and_then(|m| nearest_bucket(dist, m));
+    let mut lines = Vec::new();
+    let mut elided = false;
+    for (i, (bucket, pct)) in dist.iter().enumerate() {
+        let is_mine = mine_bucket == Some(*bucket);
+        if i >= HISTOGRAM_ROWS && !is_mine {
+            elided = true;
+            continue;
+        }
+        if elided {
+            lines.push("    ...".to_string());
+            elided = false;
+        }
+        let width = if max > 0.0 {
+            ((pct / max) * HISTOGRAM_WIDTH as f64).round() as usize
+        } else {
+            0
+        };
+        let bar = "█".repeat(width.max(usize::from(*pct > 0.0)));
+        let line = format!("  {bucket:>6} {unit} {bar} {pct:.1}%");
+        if is_mine {
+            lines.push(format!("{} {}", line.green().bold(), "◀ mine".green()));
+        } else {
+            lines.push(line);
+        }
+    }
+    if elided {
+        lines.push("    ...".to_string());
+    }
+    lines
+}
+
+/// The distribution bucket closest to my measurement — the judge rounds
+/// differently between the verdict and the chart, so an exact match isn't
+/// guaranteed.
+pub(crate) fn nearest_bucket(dist: &[(u32, f64)], mine: u32) -> Option<u32> {
+    dist.iter()
+        .map(|(bucket, _)| *bucket)
+        .min_by_key(|bucket| bucket.abs_diff(mine))
+}
+
+/// The leading integer of a judge figure like "4 ms".
+pub(crate) fn parse_leading_number(value: &str) -> Option<u32> {
+    let digits: String = value
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// A judge memory figure like "2.1 MB" in KB, to match the distribution's
+/// buckets.
+pub(crate) fn parse_memory_kb(value: &str) -> Option<u32> {
+    let number: String = value
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let number: f64 = number.parse().ok()?;
+    let unit = value.trim().rsplit(' ').next()?;
+    match unit {
+        "MB" => Some((number * 1000.0).round() as u32),
+        "KB" => Some(number.round() as u32),
+        _ => None,
+    }
+}
+
+/// A judge runtime like "4 ms" as a file-name-safe token, e.g. "4ms".
+fn sanitize_runtime(runtime: &str) -> String {
+    let token: String = runtime
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    if token.is_empty() {
+        "na".to_string()
+    } else {
+        token
+    }
+}
+
+/// Crates a solution pulls in that LeetCode's judge doesn't provide.
+///
+/// Scans `use` and `extern crate` statements and keeps the first path
+/// segment, minus std (and friends), `rand`, and in-crate paths.
+pub(crate) fn external_crate_uses(code: &str) -> Vec<String> {
+    let mut crates = Vec::new();
+    for line in code.lines() {
+        let line = line.trim_start();
+        let path = if let Some(rest) = line.strip_prefix("use ").or_else(|| {
+            line.strip_prefix("pub use ")
+                .or_else(|| line.strip_prefix("pub(crate) use "))
+        }) {
+            rest
+        } else if let Some(rest) = line.strip_prefix("extern crate ") {
+            rest
+        } else {
+            continue;
+        };
+        let root: String = path
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect();
+        match root.as_str() {
+            "" | "std" | "core" | "alloc" | "rand" | "crate" | "self" | "super" => {}
+            _ => {
+                if !crates.contains(&root) {
+                    crates.push(root);
+                }
+            }
+        }
+    }
+    crates.sort();
+    crates
+}
+
+/// Compare the solution's target method against the problem's `TestConfig`
+/// and describe any drift in name, arity, or parameter/return types.
+pub(crate) fn signature_mismatches(code: &str, cfg: &TestConfig) -> Vec<String> {
+    let Ok(file) = syn::parse_file(code) else {
+        // Unparsable code is the judge's problem to report, not ours
+        return Vec::new();
+    };
+    let method_name = cfg.rust_method_name();
+    let Some(func) = find_solution_method(&file, &method_name) else {
+        return vec![format!(
+            "method '{method_name}' not found in an 'impl Solution' block"
+        )];
+    };
+
+    let mut mismatches = Vec::new();
+    let params: Vec<_> = func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::FnArg::Typed(p) => Some(p),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .collect();
+    if params.len() != cfg.args.len() {
+        mismatches.push(format!(
+            "expected {} parameter(s), found {}",
+            cfg.args.len(),
+            params.len()
+        ));
+    }
+    for (i, (param, arg)) in params.iter().zip(&cfg.args).enumerate() {
+        if let Some(expected) = leetcode_type_to_rust(&arg.arg_type) {
+            let actual = type_string(&param.ty);
+            if actual != expected {
+                mismatches.push(format!(
+                    "parameter {} ({}): expected {expected}, found {actual}",
+                    i + 1,
+                    arg.name
+                ));
+            }
+        }
+    }
+    if let Some(expected) = leetcode_type_to_rust(&cfg.return_type) {
+        let actual = match &func.sig.output {
+            syn::ReturnType::Default => "()".to_string(),
+            syn::ReturnType::Type(_, ty) => type_string(ty),
+        };
+        if actual != expected {
+            mismatches.push(format!("return type: expected {expected}, found {actual}"));
+        }
+    }
+    mismatches
+}
+
+/// Find the named method inside an `impl Solution` block.
+fn find_solution_method<'a>(file: &'a syn::File, name: &str) -> Option<&'a syn::ImplItemFn> {
+    file.items.iter().find_map(|item| {
+        let syn::Item::Impl(imp) = item else {
+            return None;
+        };
+        let syn::Type::Path(path) = imp.self_ty.as_ref() else {
+            return None;
+        };
+        if !path.path.is_ident("Solution") {
+            return None;
+        }
+        imp.items.iter().find_map(|ii| match ii {
+            syn::ImplItem::Fn(f) if f.sig.ident == name => Some(f),
+            _ => None,
+        })
+    })
+}
+
+fn type_string(ty: &syn::Type) -> String {
+    use quote::ToTokens;
+    ty.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Map a LeetCode metadata type (e.g. "integer[]") to its Rust equivalent.
+/// Unmapped types (trees, linked lists, ...) skip the comparison.
+fn leetcode_type_to_rust(leetcode_type: &str) -> Option<String> {
+    if let Some(inner) = leetcode_type.strip_suffix("[]") {
+        return Some(format!("Vec<{}>", leetcode_type_to_rust(inner)?));
+    }
+    if let Some(inner) = leetcode_type
+        .strip_prefix("list<")
+        .and_then(|s| s.strip_suffix('>'))
+    {
+        return Some(format!("Vec<{}>", leetcode_type_to_rust(inner)?));
+    }
+    match leetcode_type {
+        "integer" => Some("i32".to_string()),
+        "long" => Some("i64".to_string()),
+        "double" => Some("f64".to_string()),
+        "string" => Some("String".to_string()),
+        "boolean" => Some("bool".to_string()),
+        "character" => Some("char".to_string()),
+        "void" => Some("()".to_string()),
+        _ => None,
+    }
+}
+
+/// The local rustc version, e.g. "1.79.0", if rustc is on PATH.
+fn local_rustc_version() -> Option<String> {
+    let output = std::process::Command::new("rustc")
+        .arg("--version")
+        .output()
+        .ok()?;
+    parse_rustc_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract the version number from `rustc --version` output.
+fn parse_rustc_version(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .nth(1)
+        .map(|v| v.trim_end_matches("-nightly").to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -32,6 +621,219 @@ mod tests {
 
     use crate::commands::TestDirGuard;
 
+    #[test]
+    fn test_render_histogram_marks_my_bucket() {
+        colored::control::set_override(false);
+        let dist = vec![(0, 12.5), (4, 50.0), (8, 25.0)];
+        let lines = super::render_histogram("ms", &dist, Some(4));
+        colored::control::unset_override();
+
+        assert_eq!(lines.len(), 3);
+        // The tallest bucket fills the full bar width, marked as mine
+        assert_eq!(
+            lines[1],
+            format!("       4 ms {} 50.0% ◀ mine", "█".repeat(30))
+        );
+        // Half the max percent gets half the bar
+        assert_eq!(lines[2], format!("       8 ms {} 25.0%", "█".repeat(15)));
+    }
+
+    #[test]
+    fn test_render_histogram_elides_tail_but_keeps_mine() {
+        colored::control::set_override(false);
+        let dist: Vec<(u32, f64)> = (0..30).map(|ms| (ms, 1.0)).collect();
+        let lines = super::render_histogram("ms", &dist, Some(29));
+        colored::control::unset_override();
+
+        // 20 rows, an ellipsis, then my elided bucket
+        assert_eq!(lines.len(), 22);
+        assert_eq!(lines[20], "    ...");
+        assert!(lines[21].ends_with("◀ mine"));
+    }
+
+    #[test]
+    fn test_nearest_bucket() {
+        let dist = vec![(0, 10.0), (4, 50.0), (8, 40.0)];
+        assert_eq!(super::nearest_bucket(&dist, 4), Some(4));
+        assert_eq!(super::nearest_bucket(&dist, 5), Some(4));
+        assert_eq!(super::nearest_bucket(&dist, 100), Some(8));
+        assert_eq!(super::nearest_bucket(&[], 4), None);
+    }
+
+    #[test]
+    fn test_parse_leading_number_and_memory_kb() {
+        assert_eq!(super::parse_leading_number("4 ms"), Some(4));
+        assert_eq!(super::parse_leading_number("N/A"), None);
+        assert_eq!(super::parse_memory_kb("2.1 MB"), Some(2100));
+        assert_eq!(super::parse_memory_kb("980 KB"), Some(980));
+        assert_eq!(super::parse_memory_kb("N/A"), None);
+    }
+
+    #[test]
+    fn test_external_crate_uses() {
+        let code = "use std::collections::HashMap;\n\
+                    use rand::Rng;\n\
+                    use itertools::Itertools;\n\
+                    use crate::solutions::p0001_two_sum;\n\
+                    pub use serde::Serialize;\n\
+                    extern crate regex;\n\
+                    use itertools::izip;\n";
+        assert_eq!(
+            super::external_crate_uses(code),
+            vec!["itertools", "regex", "serde"]
+        );
+    }
+
+    #[test]
+    fn test_external_crate_uses_clean_solution() {
+        let code = "use std::cmp::Ordering;\n\nimpl Solution {}\n";
+        assert!(super::external_crate_uses(code).is_empty());
+    }
+
+    fn make_test_config() -> crate::problem::TestConfig {
+        crate::problem::TestConfig {
+            namespace: "Solution".to_string(),
+            class_name: "Solution".to_string(),
+            method_name: "twoSum".to_string(),
+            return_type: "integer[]".to_string(),
+            args: vec![
+                crate::problem::Argument {
+                    arg_type: "integer[]".to_string(),
+                    name: "nums".to_string(),
+                },
+                crate::problem::Argument {
+                    arg_type: "integer".to_string(),
+                    name: "target".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_signature_matches() {
+        let code = "impl Solution {\n\
+                    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n\
+                    vec![]\n}\n}\n";
+        assert!(super::signature_mismatches(code, &make_test_config()).is_empty());
+    }
+
+    #[test]
+    fn test_signature_method_missing() {
+        let code = "impl Solution {\npub fn other() {}\n}\n";
+        let mismatches = super::signature_mismatches(code, &make_test_config());
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].contains("'two_sum' not found"));
+    }
+
+    #[test]
+    fn test_signature_drift_reported() {
+        // Wrong arity, wrong parameter type, wrong return type
+        let code = "impl Solution {\n\
+                    pub fn two_sum(nums: Vec<i64>) -> i32 {\n0\n}\n}\n";
+        let mismatches = super::signature_mismatches(code, &make_test_config());
+        assert!(mismatches.iter().any(|m| m.contains("2 parameter(s)")));
+        assert!(
+            mismatches
+                .iter()
+                .any(|m| m.contains("expected Vec<i32>, found Vec<i64>"))
+        );
+        assert!(
+            mismatches
+                .iter()
+                .any(|m| m.contains("return type: expected Vec<i32>, found i32"))
+        );
+    }
+
+    #[test]
+    fn test_signature_skips_unmapped_types() {
+        let cfg = crate::problem::TestConfig {
+            method_name: "insert".to_string(),
+            return_type: "TreeNode".to_string(),
+            args: vec![crate::problem::Argument {
+                arg_type: "TreeNode".to_string(),
+                name: "root".to_string(),
+            }],
+            ..make_test_config()
+        };
+        let code = "impl Solution {\n\
+                    pub fn insert(root: Option<Rc<RefCell<TreeNode>>>) \
+                    -> Option<Rc<RefCell<TreeNode>>> {\nroot\n}\n}\n";
+        assert!(super::signature_mismatches(code, &cfg).is_empty());
+    }
+
+    #[test]
+    fn test_parse_rustc_version() {
+        assert_eq!(
+            super::parse_rustc_version("rustc 1.79.0 (129f3b996 2024-06-10)"),
+            Some("1.79.0".to_string())
+        );
+        assert_eq!(
+            super::parse_rustc_version("rustc 1.82.0-nightly (abc123 2024-08-01)"),
+            Some("1.82.0".to_string())
+        );
+        assert_eq!(super::parse_rustc_version(""), None);
+    }
+
+    #[test]
+    fn test_sanitize_runtime() {
+        assert_eq!(super::sanitize_runtime("4 ms"), "4ms");
+        assert_eq!(super::sanitize_runtime("N/A"), "NA");
+        assert_eq!(super::sanitize_runtime(""), "na");
+    }
+
+    #[test]
+    fn test_utc_hour() {
+        assert_eq!(super::utc_hour(0), 0);
+        assert_eq!(super::utc_hour(18 * 3600 + 59), 18);
+        // 2023-11-14 22:13:20 UTC
+        assert_eq!(super::utc_hour(1_700_000_000), 22);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_snapshot_accepted() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("src/solutions")).unwrap();
+        fs::write(
+            temp_dir.path().join("src/solutions/p0001_two_sum.rs"),
+            "impl Solution {}",
+        )
+        .unwrap();
+
+        let _guard = TestDirGuard::new(temp_dir);
+        crate::commands::tests::write_test_meta(1, "two-sum");
+
+        let result = crate::api::SubmissionResult {
+            status_code: 10,
+            status_msg: "Accepted".to_string(),
+            status_runtime: "4 ms".to_string(),
+            status_memory: "2.1 MB".to_string(),
+            runtime_percentile: Some(95.2),
+            memory_percentile: Some(80.1),
+            ..Default::default()
+        };
+        let path = super::snapshot_accepted(
+            1,
+            std::path::Path::new("src/solutions/p0001_two_sum.rs"),
+            &result,
+        )
+        .unwrap();
+        assert!(path.starts_with("accepted/p0001_two_sum"));
+        assert!(path.to_string_lossy().ends_with("_4ms.rs"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "impl Solution {}");
+
+        let progress = crate::progress::Progress::load().unwrap();
+        assert!(progress.is_solved(1));
+        assert_eq!(
+            progress.problems[&1].accepted_snapshots,
+            vec![path.to_string_lossy().into_owned()]
+        );
+        let perf = &progress.problems[&1].perf_history;
+        assert_eq!(perf.len(), 1);
+        assert_eq!(perf[0].runtime, "4 ms");
+        assert_eq!(perf[0].runtime_percentile, Some(95.2));
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_find_solution_file_for_submit() {
@@ -46,6 +848,7 @@ mod tests {
         .unwrap();
 
         let _guard = TestDirGuard::new(temp_dir);
+        crate::commands::tests::write_test_meta(1, "two-sum");
 
         // Test finding solution file
         let result = super::find_solution_file(1, None);
@@ -0,0 +1,155 @@
+//! Sync command - Commit and push solutions to a git remote
+//!
+//! Wraps git to keep a solutions repository in sync across machines:
+//! configures the remote, commits newly changed solution files with a
+//! normalized message, pushes, and can pull on another machine.
+
+use std::process::Command;
+
+use anyhow::{Result, anyhow};
+use colored::Colorize;
+
+/// Paths that `sync` stages; everything else is left to the user.
+const SYNCED_PATHS: &[&str] = &["src/solutions", "SOLUTIONS.md", "notes"];
+
+/// Sync solutions with a git remote
+pub async fn execute(remote: Option<String>, pull: bool, message: Option<String>) -> Result<()> {
+    // Make sure we're inside a git repository
+    if run_git(&["rev-parse", "--is-inside-work-tree"]).is_err() {
+        println!("{}", "Initializing git repository...".cyan());
+        run_git(&["init"])?;
+    }
+
+    // Configure the remote if one was given
+    if let Some(ref url) = remote {
+        if run_git(&["remote", "get-url", "origin"]).is_ok() {
+            run_git(&["remote", "set-url", "origin", url])?;
+        } else {
+            run_git(&["remote", "add", "origin", url])?;
+        }
+        println!("{}", format!("✓ Remote 'origin' set to {url}").green());
+    }
+
+    if pull {
+        println!("{}", "Pulling from origin...".cyan());
+        match run_git(&["pull", "--rebase", "origin", "HEAD"]) {
+            Ok(output) => {
+                println!("{}", output.trim());
+                println!("{}", "✓ Pulled latest solutions".green());
+            }
+            Err(e) => {
+                return Err(anyhow!(
+                    "pull failed (resolve conflicts with 'git rebase --continue' or \
+                     'git rebase --abort'): {e}"
+                ));
+            }
+        }
+        return Ok(());
+    }
+
+    // Stage the solution-related paths that exist
+    for path in SYNCED_PATHS {
+        if std::path::Path::new(path).exists() {
+            run_git(&["add", path])?;
+        }
+    }
+
+    // Anything to commit?
+    let staged = run_git(&["diff", "--cached", "--name-only"])?;
+    let changed: Vec<&str> = staged.lines().filter(|l| !l.is_empty()).collect();
+    if changed.is_empty() {
+        println!("{}", "Nothing to sync: working tree is clean.".yellow());
+        return Ok(());
+    }
+
+    let commit_message = message.unwrap_or_else(|| default_commit_message(changed.len()));
+    run_git(&["commit", "-m", &commit_message])?;
+    println!(
+        "{}",
+        format!("✓ Committed {} file(s): {commit_message}", changed.len()).green()
+    );
+
+    if run_git(&["remote", "get-url", "origin"]).is_err() {
+        println!(
+            "{}",
+            "No remote configured; run with --remote <url> to push.".yellow()
+        );
+        return Ok(());
+    }
+
+    println!("{}", "Pushing to origin...".cyan());
+    match run_git(&["push", "-u", "origin", "HEAD"]) {
+        Ok(_) => println!("{}", "✓ Pushed to origin".green()),
+        Err(e) => {
+            return Err(anyhow!(
+                "push rejected; run 'leetcode-cli sync --pull' first to merge remote changes: {e}"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Run a git command and return its stdout, failing with stderr on error.
+pub(crate) fn run_git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).output()?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(anyhow!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Normalized commit message for synced solutions.
+pub(crate) fn default_commit_message(file_count: usize) -> String {
+    format!(
+        "leetcode: sync {} solution file{}",
+        file_count,
+        if file_count == 1 { "" } else { "s" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::commands::TestDirGuard;
+
+    #[test]
+    fn test_default_commit_message_singular() {
+        assert_eq!(default_commit_message(1), "leetcode: sync 1 solution file");
+    }
+
+    #[test]
+    fn test_default_commit_message_plural() {
+        assert_eq!(default_commit_message(3), "leetcode: sync 3 solution files");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_run_git_in_fresh_repo() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        run_git(&["init"]).unwrap();
+        let result = run_git(&["rev-parse", "--is-inside-work-tree"]).unwrap();
+        assert_eq!(result.trim(), "true");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_run_git_failure_includes_stderr() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        run_git(&["init"]).unwrap();
+        let result = run_git(&["remote", "get-url", "origin"]);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("git remote failed"));
+    }
+}
@@ -5,21 +5,320 @@ use std::process::Command;
 use anyhow::Result;
 use colored::*;
 
-/// Run local tests for a problem
-pub async fn execute(id: u32) -> Result<()> {
+use crate::meta::ProblemMeta;
+
+/// Run local tests for a problem, dispatching on the recorded language
+pub async fn execute(
+    id: u32,
+    coverage: bool,
+    miri: bool,
+    asan: bool,
+    args: Vec<String>,
+) -> Result<()> {
     println!("{}", format!("Running tests for problem {id}...").cyan());
 
-    // Run tests for the specific problem module
-    // Module name pattern: p0001_two_sum::
-    let module_pattern = format!("p{id:04}::");
+    let meta = match ProblemMeta::load(id)? {
+        Some(meta) => meta,
+        None => anyhow::bail!(
+            "no metadata for problem {id}: run 'leetcode-cli migrate' to index \
+             pre-existing solutions"
+        ),
+    };
 
-    println!("{}", "Running cargo test...".cyan());
+    if (coverage || miri || asan || !args.is_empty()) && meta.language != "rust" {
+        anyhow::bail!(
+            "--coverage, --miri, --asan, and '-- <args>' are only supported for Rust \
+             solutions (problem {id} uses {})",
+            meta.language
+        );
+    }
 
-    let output = Command::new("cargo")
-        .arg("test")
-        .arg(&module_pattern)
-        .output()?;
+    // Non-Rust workspaces have their own runners
+    match meta.language.as_str() {
+        "typescript" | "javascript" => return run_npm_test(&meta),
+        "python" => return run_pytest(&meta),
+        "cpp" => return run_cpp_test(&meta),
+        "go" => return run_go_test(&meta),
+        "sql" | "bash" => return run_harness(&meta),
+        _ => {}
+    }
+
+    if coverage {
+        return run_coverage(&meta, &args);
+    }
+    if miri {
+        return run_miri(&meta, &args);
+    }
+    if asan {
+        return run_asan(&meta, &args);
+    }
+
+    // Use the exact module name from the problem metadata
+    let module_pattern = format!("{}::", meta.module_name());
+
+    let mut runner = TestRunner::from_config(&crate::config::Config::load()?);
+    if runner == TestRunner::Nextest && !args.is_empty() {
+        // Arguments after `--` target libtest, whose flags nextest rejects
+        println!(
+            "{}",
+            "! '-- <args>' are libtest arguments; using cargo test for this run".yellow()
+        );
+        runner = TestRunner::CargoTest;
+    }
+    println!("{}", format!("Running {}...", runner.describe()).cyan());
+
+    let mut command = Command::new("cargo");
+    command.args(runner.cargo_args()).arg(&module_pattern);
+    if !args.is_empty() {
+        command.arg("--").args(&args);
+    }
+    run_and_report_measured(command, &meta)
+}
+
+/// Which runner executes a problem's tests. cargo-nextest runs tests in
+/// parallel processes, which pays off most under 'verify-all'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TestRunner {
+    CargoTest,
+    Nextest,
+}
+
+impl TestRunner {
+    /// Pick a runner from the config's `test_runner` ("cargo" or "nextest");
+    /// when unset, use nextest if it is installed. A configured nextest that
+    /// is missing falls back to cargo test with a warning.
+    pub(crate) fn from_config(config: &crate::config::Config) -> Self {
+        match config.test_runner.as_deref() {
+            Some("cargo") => Self::CargoTest,
+            Some("nextest") => {
+                if nextest_installed() {
+                    Self::Nextest
+                } else {
+                    println!(
+                        "{}",
+                        "! cargo-nextest is configured but not installed; falling back to \
+                         cargo test (install it with 'cargo install cargo-nextest')"
+                            .yellow()
+                    );
+                    Self::CargoTest
+                }
+            }
+            Some(other) => {
+                println!(
+                    "{}",
+                    format!(
+                        "! unknown test_runner '{other}' in config (expected 'cargo' or \
+                         'nextest'); using cargo test"
+                    )
+                    .yellow()
+                );
+                Self::CargoTest
+            }
+            None => {
+                if nextest_installed() {
+                    Self::Nextest
+                } else {
+                    Self::CargoTest
+                }
+            }
+        }
+    }
+
+    /// The cargo subcommand arguments, before the test-name filter.
+    pub(crate) fn cargo_args(self) -> &'static [&'static str] {
+        match self {
+            Self::CargoTest => &["test"],
+            Self::Nextest => &["nextest", "run"],
+        }
+    }
+
+    /// Human-readable name for progress messages.
+    fn describe(self) -> &'static str {
+        match self {
+            Self::CargoTest => "cargo test",
+            Self::Nextest => "cargo nextest",
+        }
+    }
+}
+
+/// Whether `cargo nextest` is available.
+fn nextest_installed() -> bool {
+    Command::new("cargo")
+        .args(["nextest", "--version"])
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+/// Run a cargo test invocation and pretty-print its output. The shared
+/// target directory is applied when one is configured.
+fn run_and_report(mut command: Command) -> Result<()> {
+    // Reuse the shared target directory when one is configured
+    if let Some(ref dir) = crate::config::Config::load()?.target_dir {
+        command.env("CARGO_TARGET_DIR", dir);
+    }
+    let _timer = crate::timings::start("cargo run");
+    let output = command.output()?;
+    report_output(&output);
+    Ok(())
+}
+
+/// Like [`run_and_report`], but also measure wall time and peak RSS of the
+/// run and store them in the progress database, so an optimization's effect
+/// on memory shows up locally before burning a submission. The RSS samples
+/// cover the whole cargo process tree; with a warm build the test binary
+/// dominates.
+fn run_and_report_measured(mut command: Command, meta: &ProblemMeta) -> Result<()> {
+    if let Some(ref dir) = crate::config::Config::load()?.target_dir {
+        command.env("CARGO_TARGET_DIR", dir);
+    }
+    let (output, wall, peak_rss_kb) = {
+        let _timer = crate::timings::start("cargo run");
+        run_measured(command)?
+    };
+    report_output(&output);
+
+    let mut progress = crate::progress::Progress::load()?;
+    let previous = progress
+        .problems
+        .get(&meta.frontend_id)
+        .and_then(|p| p.last_test_peak_rss_kb);
+    let mut line = format!("Wall time: {:.2}s", wall.as_secs_f64());
+    if let Some(kb) = peak_rss_kb {
+        line.push_str(&format!(", peak RSS: {}", format_rss_kb(kb)));
+        if let Some(prev) = previous {
+            line.push_str(&format!(" (last run: {})", format_rss_kb(prev)));
+        }
+    }
+    println!("{}", format!("  {line}").cyan());
+
+    progress.record_test_run(
+        meta.frontend_id,
+        &meta.slug,
+        wall.as_millis() as u64,
+        peak_rss_kb,
+    );
+    // Failed runs count toward the attempt history, so `dashboard` can
+    // tell first-try solves from hard-won ones
+    if !output.status.success() {
+        progress.record_test_failure(meta.frontend_id, &meta.slug);
+    }
+    progress.save()?;
+    Ok(())
+}
+
+/// Run a command to completion while sampling the peak RSS of its process
+/// tree, returning its output, wall time, and the highest sample.
+fn run_measured(
+    mut command: Command,
+) -> Result<(std::process::Output, std::time::Duration, Option<u64>)> {
+    use std::io::Read;
+
+    command
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    let start = std::time::Instant::now();
+    let mut child = command.spawn()?;
+    let pid = child.id();
+
+    // Drain the pipes on threads so the child never blocks on a full pipe
+    let mut stdout_pipe = child.stdout.take();
+    let stdout_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(ref mut pipe) = stdout_pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    });
+    let mut stderr_pipe = child.stderr.take();
+    let stderr_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(ref mut pipe) = stderr_pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    });
+
+    let mut peak_rss_kb = None;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if let Some(kb) = sample_tree_rss_kb(pid) {
+            peak_rss_kb = Some(peak_rss_kb.unwrap_or(0).max(kb));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+    let wall = start.elapsed();
+
+    let output = std::process::Output {
+        status,
+        stdout: stdout_thread.join().unwrap_or_default(),
+        stderr: stderr_thread.join().unwrap_or_default(),
+    };
+    Ok((output, wall, peak_rss_kb))
+}
+
+/// The largest peak-RSS high-water mark (`VmHWM`), in kilobytes, across a
+/// process and its descendants, read from /proc. None off Linux or once
+/// the processes are gone.
+fn sample_tree_rss_kb(root: u32) -> Option<u64> {
+    let mut procs = Vec::new();
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let Ok(status) = std::fs::read_to_string(entry.path().join("status")) else {
+            continue;
+        };
+        let Some(ppid) = parse_proc_field(&status, "PPid:") else {
+            continue;
+        };
+        procs.push((pid, ppid as u32, parse_proc_field(&status, "VmHWM:")));
+    }
 
+    // Walk down the process tree from the root
+    let mut tree = vec![root];
+    let mut max_kb = None;
+    let mut i = 0;
+    while i < tree.len() {
+        let pid = tree[i];
+        i += 1;
+        for &(child, ppid, hwm) in &procs {
+            if ppid == pid {
+                tree.push(child);
+            }
+            if child == pid && let Some(kb) = hwm {
+                max_kb = Some(max_kb.unwrap_or(0).max(kb));
+            }
+        }
+    }
+    max_kb
+}
+
+/// The numeric value of a `Field:\tvalue ...` line in a /proc status file.
+fn parse_proc_field(status: &str, field: &str) -> Option<u64> {
+    status.lines().find_map(|line| {
+        line.strip_prefix(field)?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()
+    })
+}
+
+/// Format a kilobyte count in the closest friendly unit.
+fn format_rss_kb(kb: u64) -> String {
+    if kb >= 1024 * 1024 {
+        format!("{:.2} GB", kb as f64 / (1024.0 * 1024.0))
+    } else if kb >= 1024 {
+        format!("{:.1} MB", kb as f64 / 1024.0)
+    } else {
+        format!("{kb} KB")
+    }
+}
+
+/// Pretty-print a finished test run's output and verdict.
+fn report_output(output: &std::process::Output) {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
@@ -29,7 +328,11 @@ pub async fn execute(id: u32) -> Result<()> {
         format_test_output(&stdout);
     }
 
-    if !stderr.is_empty()
+    if is_nextest_report(&stderr) {
+        // nextest writes its run report to stderr
+        println!("\n{}", "Test Output:".bold());
+        format_test_output(&stderr);
+    } else if !stderr.is_empty()
         && !stderr.contains("Compiling")
         && !stderr.contains("Finished")
         && !stderr.contains("Running")
@@ -42,15 +345,376 @@ pub async fn execute(id: u32) -> Result<()> {
     if output.status.success() {
         println!("\n{}", "✓ All tests passed!".green().bold());
     } else {
+        // nextest captures failing-test output on stderr, cargo test on stdout
+        print_assertion_diffs(&format!("{stdout}{stderr}"));
         println!("\n{}", "✗ Some tests failed".red().bold());
     }
+}
+
+/// Render a colored word-level diff for each `assert_eq!` failure in the
+/// test output, which reads far better than the raw left/right dumps when
+/// the values are long vectors or strings.
+fn print_assertion_diffs(output: &str) {
+    for (left, right) in assert_eq_payloads(output) {
+        let (left, right) = crate::commands::diff::word_diff_colored(&left, &right);
+        println!("\n{}", "Assertion diff (left vs right):".bold());
+        println!("  left:  {left}");
+        println!("  right: {right}");
+    }
+}
+
+/// Extract the left/right values from `assert_eq!` panic payloads, in both
+/// the current ("assertion `left == right` failed") and the pre-1.73
+/// ("assertion failed: `(left == right)`") formats.
+fn assert_eq_payloads(output: &str) -> Vec<(String, String)> {
+    let lines: Vec<&str> = output.lines().collect();
+    let mut pairs = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.trim_start();
+        if !line.starts_with("assertion `left == right` failed")
+            && !line.contains("assertion failed: `(left == right)`")
+        {
+            continue;
+        }
+        // The values follow on their own lines, sometimes after a message
+        let mut left = None;
+        let mut right = None;
+        for candidate in lines[i + 1..].iter().take(4) {
+            let candidate = candidate.trim_start();
+            if let Some(value) = candidate.strip_prefix("left: ") {
+                left = Some(clean_payload(value));
+            } else if let Some(value) = candidate.strip_prefix("right: ") {
+                right = Some(clean_payload(value));
+                break;
+            }
+        }
+        if let (Some(left), Some(right)) = (left, right) {
+            pairs.push((left, right));
+        }
+    }
+    pairs
+}
+
+/// Strip the backticks the older panic format wraps values in, along with
+/// the source location that trails the closing one.
+fn clean_payload(value: &str) -> String {
+    let value = value.trim();
+    if let Some(rest) = value.strip_prefix('`')
+        && let Some((inner, _)) = rest.rsplit_once('`')
+    {
+        return inner.to_string();
+    }
+    value.trim_end_matches(',').to_string()
+}
+
+/// Run the problem's tests under Miri, which interprets the code and flags
+/// undefined behavior the judge would only surface as a Runtime Error.
+fn run_miri(meta: &ProblemMeta, args: &[String]) -> Result<()> {
+    if !nightly_installed() {
+        anyhow::bail!(
+            "Miri needs the nightly toolchain: install it with 'rustup toolchain install nightly'"
+        );
+    }
+    if !Command::new("cargo")
+        .args(["+nightly", "miri", "--version"])
+        .output()
+        .is_ok_and(|o| o.status.success())
+    {
+        anyhow::bail!(
+            "Miri is not installed: run 'rustup +nightly component add miri'"
+        );
+    }
+
+    println!("{}", "Running cargo miri test...".cyan());
+    let mut command = Command::new("cargo");
+    command
+        .args(["+nightly", "miri", "test"])
+        .arg(format!("{}::", meta.module_name()));
+    if !args.is_empty() {
+        command.arg("--").args(args);
+    }
+    run_and_report(command)
+}
+
+/// Run the problem's tests with AddressSanitizer, which catches memory
+/// errors in unsafe code at run time. Needs nightly, since `-Zsanitizer`
+/// is unstable.
+fn run_asan(meta: &ProblemMeta, args: &[String]) -> Result<()> {
+    if !nightly_installed() {
+        anyhow::bail!(
+            "AddressSanitizer needs the nightly toolchain: install it with \
+             'rustup toolchain install nightly'"
+        );
+    }
+    let target = host_target()?;
+
+    println!("{}", "Running cargo test with AddressSanitizer...".cyan());
+    let mut command = Command::new("cargo");
+    command
+        .args(["+nightly", "test", "--target", &target])
+        .arg(format!("{}::", meta.module_name()))
+        // The explicit --target keeps the sanitizer out of build scripts
+        .env("RUSTFLAGS", "-Zsanitizer=address");
+    if !args.is_empty() {
+        command.arg("--").args(args);
+    }
+    run_and_report(command)
+}
+
+/// Whether a nightly toolchain is available.
+fn nightly_installed() -> bool {
+    Command::new("cargo")
+        .args(["+nightly", "--version"])
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+/// The host target triple, from `rustc -vV`.
+fn host_target() -> Result<String> {
+    let output = Command::new("rustc").arg("-vV").output()?;
+    parse_host_target(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| anyhow::anyhow!("could not determine the host target from 'rustc -vV'"))
+}
+
+/// Extract the `host:` line from `rustc -vV` output.
+fn parse_host_target(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(|host| host.trim().to_string())
+}
+
+/// Run the problem's tests under `cargo llvm-cov` and report line coverage
+/// for the solution file, with its uncovered lines called out.
+fn run_coverage(meta: &ProblemMeta, args: &[String]) -> Result<()> {
+    if !llvm_cov_installed() {
+        if !crate::commands::prompt_confirm(
+            "cargo-llvm-cov is not installed. Install it now? [Y/n]",
+        )? {
+            anyhow::bail!(
+                "coverage needs cargo-llvm-cov: install it with 'cargo install cargo-llvm-cov'"
+            );
+        }
+        println!("{}", "Installing cargo-llvm-cov...".cyan());
+        let status = Command::new("cargo")
+            .args(["install", "cargo-llvm-cov"])
+            .status()?;
+        if !status.success() {
+            anyhow::bail!("failed to install cargo-llvm-cov");
+        }
+    }
+
+    let module_pattern = format!("{}::", meta.module_name());
+    println!("{}", "Running cargo llvm-cov...".cyan());
+
+    let mut command = Command::new("cargo");
+    command
+        .args(["llvm-cov", "--summary-only", "--show-missing-lines", "--"])
+        .arg(&module_pattern)
+        .args(args);
+    if let Some(ref dir) = crate::config::Config::load()?.target_dir {
+        command.env("CARGO_TARGET_DIR", dir);
+    }
+    let output = command.output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        print!("{stdout}");
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        anyhow::bail!("cargo llvm-cov failed");
+    }
+
+    let file_name = format!("{}.rs", meta.module_name());
+    match parse_line_coverage(&stdout, &file_name) {
+        Some(percent) => {
+            let line = format!("Line coverage for {file_name}: {percent}");
+            if percent.trim_end_matches('%').parse::<f64>().unwrap_or(0.0) >= 100.0 {
+                println!("{}", format!("✓ {line}").green().bold());
+            } else {
+                println!("{}", format!("! {line}").yellow().bold());
+            }
+        }
+        None => println!(
+            "{}",
+            format!("! no coverage reported for {file_name}").yellow()
+        ),
+    }
+    for missing in uncovered_lines(&stdout, &file_name) {
+        println!("{}", format!("  Uncovered lines: {missing}").yellow());
+    }
 
     Ok(())
 }
 
+/// Whether `cargo llvm-cov` is available.
+fn llvm_cov_installed() -> bool {
+    Command::new("cargo")
+        .args(["llvm-cov", "--version"])
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+/// The line-coverage percentage for `file_name` from an llvm-cov summary
+/// table row (filename, then twelve numeric columns; lines cover is the
+/// tenth).
+fn parse_line_coverage(summary: &str, file_name: &str) -> Option<String> {
+    summary.lines().find_map(|line| {
+        let mut tokens = line.split_whitespace();
+        if !tokens.next()?.ends_with(file_name) {
+            return None;
+        }
+        tokens.nth(8).map(|t| t.to_string())
+    })
+}
+
+/// The uncovered-line ranges llvm-cov lists for `file_name` under its
+/// "Uncovered Lines:" section, e.g. `12, 15-18`.
+fn uncovered_lines(summary: &str, file_name: &str) -> Vec<String> {
+    summary
+        .lines()
+        .filter_map(|line| {
+            let (path, ranges) = line.split_once(": ")?;
+            if !path.trim().ends_with(file_name) {
+                return None;
+            }
+            Some(ranges.trim().to_string())
+        })
+        .collect()
+}
+
+/// The directory of a non-Rust problem workspace, e.g. `ts/p0001_two_sum`.
+fn workspace_dir(meta: &ProblemMeta) -> Result<std::path::PathBuf> {
+    let path = meta.solution_path();
+    match path.parent() {
+        Some(dir) if dir.exists() => Ok(dir.to_path_buf()),
+        Some(dir) => anyhow::bail!(
+            "workspace {} not found: re-download the problem with 'leetcode-cli pick --id {}'",
+            dir.display(),
+            meta.frontend_id
+        ),
+        None => anyhow::bail!("no workspace directory for {}", path.display()),
+    }
+}
+
+/// Run `npm test` in a TypeScript/JavaScript problem workspace.
+fn run_npm_test(meta: &ProblemMeta) -> Result<()> {
+    let dir = workspace_dir(meta)?;
+    println!("{}", "Running npm test...".cyan());
+
+    let output = Command::new("npm").arg("test").current_dir(&dir).output()?;
+    print_command_output(&output);
+    Ok(())
+}
+
+/// Run `pytest` in a Python problem workspace.
+fn run_pytest(meta: &ProblemMeta) -> Result<()> {
+    let dir = workspace_dir(meta)?;
+    println!("{}", "Running pytest...".cyan());
+
+    let output = Command::new("pytest").current_dir(&dir).output()?;
+    print_command_output(&output);
+    Ok(())
+}
+
+/// Run `go test ./...` in a Go problem workspace.
+fn run_go_test(meta: &ProblemMeta) -> Result<()> {
+    let dir = workspace_dir(meta)?;
+    println!("{}", "Running go test...".cyan());
+
+    let output = Command::new("go")
+        .args(["test", "./..."])
+        .current_dir(&dir)
+        .output()?;
+    print_command_output(&output);
+    Ok(())
+}
+
+/// Compile and run the single-file harness in a C++ problem workspace.
+/// Sanitizers are on so interleaving/memory bugs surface locally.
+fn run_cpp_test(meta: &ProblemMeta) -> Result<()> {
+    let dir = workspace_dir(meta)?;
+    let compiler = cpp_compiler()?;
+    println!("{}", format!("Compiling with {compiler}...").cyan());
+
+    let build = Command::new(compiler)
+        .args(["-O2", "-fsanitize=address,undefined", "-o", "solution", "solution.cpp"])
+        .current_dir(&dir)
+        .output()?;
+    if !build.status.success() {
+        println!("\n{}", "Compiler Messages:".yellow());
+        println!("{}", String::from_utf8_lossy(&build.stderr));
+        println!("\n{}", "✗ Compilation failed".red().bold());
+        return Ok(());
+    }
+
+    println!("{}", "Running solution...".cyan());
+    let output = Command::new("./solution").current_dir(&dir).output()?;
+    print_command_output(&output);
+    Ok(())
+}
+
+/// The first C++ compiler available on PATH.
+fn cpp_compiler() -> Result<&'static str> {
+    for compiler in ["g++", "clang++"] {
+        if Command::new(compiler)
+            .arg("--version")
+            .output()
+            .is_ok_and(|o| o.status.success())
+        {
+            return Ok(compiler);
+        }
+    }
+    anyhow::bail!("no C++ compiler found: install g++ or clang++")
+}
+
+/// Run the `test.sh` harness in a SQL or shell problem workspace.
+fn run_harness(meta: &ProblemMeta) -> Result<()> {
+    let dir = workspace_dir(meta)?;
+    println!("{}", "Running test.sh...".cyan());
+
+    let output = Command::new("sh").arg("test.sh").current_dir(&dir).output()?;
+    print_command_output(&output);
+    Ok(())
+}
+
+fn print_command_output(output: &std::process::Output) {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stdout.is_empty() {
+        println!("\n{}", "Test Output:".bold());
+        println!("{stdout}");
+    }
+    if !stderr.is_empty() {
+        println!("{stderr}");
+    }
+    if output.status.success() {
+        println!("\n{}", "✓ All tests passed!".green().bold());
+    } else {
+        println!("\n{}", "✗ Some tests failed".red().bold());
+    }
+}
+
+/// Whether stderr holds a nextest run report rather than compiler noise.
+fn is_nextest_report(stderr: &str) -> bool {
+    stderr
+        .lines()
+        .any(|line| line.trim_start().starts_with("Summary ["))
+}
+
 fn format_test_output(output: &str) {
     for line in output.lines() {
-        if line.contains("test result: ok") {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("PASS [") {
+            println!("  {}", line.green());
+        } else if trimmed.starts_with("FAIL [") {
+            println!("  {}", line.red());
+        } else if trimmed.starts_with("Summary [") {
+            if line.contains("0 failed") {
+                println!("{}", line.green());
+            } else {
+                println!("{}", line.red());
+            }
+        } else if line.contains("test result: ok") {
             println!("{}", line.green());
         } else if line.contains("test result: FAILED") {
             println!("{}", line.red());
@@ -152,6 +816,172 @@ mod tests {
         format_test_output(output);
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_workspace_dir_resolution() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("ts/p0001_two_sum")).unwrap();
+        let _guard = crate::commands::TestDirGuard::new(temp_dir);
+
+        let meta = ProblemMeta {
+            id: 1,
+            frontend_id: 1,
+            slug: "two-sum".to_string(),
+            title: "Two Sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: Vec::new(),
+            downloaded_at: 0,
+            language: "typescript".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        };
+        assert_eq!(
+            workspace_dir(&meta).unwrap(),
+            std::path::PathBuf::from("ts/p0001_two_sum")
+        );
+
+        let missing = ProblemMeta {
+            language: "javascript".to_string(),
+            ..meta
+        };
+        let err = workspace_dir(&missing).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_parse_host_target() {
+        let output = "rustc 1.79.0 (129f3b996 2024-06-10)\n\
+                      binary: rustc\n\
+                      host: x86_64-unknown-linux-gnu\n\
+                      release: 1.79.0\n";
+        assert_eq!(
+            parse_host_target(output),
+            Some("x86_64-unknown-linux-gnu".to_string())
+        );
+        assert_eq!(parse_host_target("rustc 1.79.0"), None);
+    }
+
+    #[test]
+    fn test_parse_line_coverage() {
+        let summary = "Filename                      Regions    Missed Regions     Cover   \
+                       Functions  Missed Functions  Executed       Lines  Missed Lines     \
+                       Cover    Branches   Missed Branches     Cover\n\
+                       src/solutions/p0001_two_sum.rs      10                 2    80.00%          \
+                       2                 0   100.00%          20             3    85.00%           \
+                       0                 0         -\n";
+        assert_eq!(
+            parse_line_coverage(summary, "p0001_two_sum.rs"),
+            Some("85.00%".to_string())
+        );
+        assert_eq!(parse_line_coverage(summary, "p0002_other.rs"), None);
+    }
+
+    #[test]
+    fn test_uncovered_lines() {
+        let summary = "Uncovered Lines:\n\
+                       src/solutions/p0001_two_sum.rs: 12, 15-18\n\
+                       src/solutions/p0002_other.rs: 3\n";
+        assert_eq!(
+            uncovered_lines(summary, "p0001_two_sum.rs"),
+            vec!["12, 15-18"]
+        );
+        assert!(uncovered_lines(summary, "p0003_missing.rs").is_empty());
+    }
+
+    #[test]
+    fn test_assert_eq_payloads_current_format() {
+        let output = "---- p0001_two_sum::tests::test_case_0001_1 stdout ----\n\
+                      thread 'p0001_two_sum::tests::test_case_0001_1' panicked at src/solutions/p0001_two_sum.rs:42:9:\n\
+                      assertion `left == right` failed\n  \
+                        left: [0, 1]\n \
+                       right: [0, 2]\n";
+        assert_eq!(
+            assert_eq_payloads(output),
+            vec![("[0, 1]".to_string(), "[0, 2]".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_assert_eq_payloads_old_format() {
+        let output = "thread 'tests::t' panicked at 'assertion failed: `(left == right)`\n  \
+                        left: `\"abc\"`,\n \
+                       right: `\"abd\"`', src/lib.rs:5:9\n";
+        assert_eq!(
+            assert_eq_payloads(output),
+            vec![("\"abc\"".to_string(), "\"abd\"".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_assert_eq_payloads_none() {
+        assert!(assert_eq_payloads("test result: ok. 3 passed\n").is_empty());
+        // A plain panic without left/right values yields nothing
+        assert!(
+            assert_eq_payloads("thread 'tests::t' panicked at 'boom', src/lib.rs:1:1\n")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parse_proc_field() {
+        let status = "Name:\tcargo\nPPid:\t1234\nVmHWM:\t  204800 kB\n";
+        assert_eq!(parse_proc_field(status, "PPid:"), Some(1234));
+        assert_eq!(parse_proc_field(status, "VmHWM:"), Some(204_800));
+        assert_eq!(parse_proc_field(status, "VmRSS:"), None);
+    }
+
+    #[test]
+    fn test_format_rss_kb() {
+        assert_eq!(format_rss_kb(512), "512 KB");
+        assert_eq!(format_rss_kb(140_000), "136.7 MB");
+        assert_eq!(format_rss_kb(3 * 1024 * 1024), "3.00 GB");
+    }
+
+    #[test]
+    fn test_runner_cargo_args() {
+        assert_eq!(TestRunner::CargoTest.cargo_args(), ["test"]);
+        assert_eq!(TestRunner::Nextest.cargo_args(), ["nextest", "run"]);
+    }
+
+    #[test]
+    fn test_runner_forced_cargo() {
+        let config = crate::config::Config {
+            test_runner: Some("cargo".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(TestRunner::from_config(&config), TestRunner::CargoTest);
+    }
+
+    #[test]
+    fn test_runner_unknown_value_falls_back() {
+        let config = crate::config::Config {
+            test_runner: Some("pytest".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(TestRunner::from_config(&config), TestRunner::CargoTest);
+    }
+
+    #[test]
+    fn test_is_nextest_report() {
+        let report = "    Starting 3 tests across 1 binary\n\
+                          PASS [   0.012s] leetcode-practice p0001_two_sum::tests::test_one\n\
+                       Summary [   0.050s] 3 tests run: 3 passed, 0 skipped\n";
+        assert!(is_nextest_report(report));
+        assert!(!is_nextest_report("Compiling leetcode-practice v0.1.0\n"));
+        assert!(!is_nextest_report(""));
+    }
+
+    #[test]
+    fn test_format_test_output_nextest() {
+        // nextest-style lines take the PASS/FAIL/Summary branches
+        let output = "    Starting 2 tests across 1 binary\n\
+                          PASS [   0.012s] leetcode-practice p0001_two_sum::tests::test_one\n\
+                          FAIL [   0.015s] leetcode-practice p0001_two_sum::tests::test_two\n\
+                       Summary [   0.050s] 2 tests run: 1 passed, 1 failed, 0 skipped\n";
+        format_test_output(output);
+    }
+
     #[test]
     fn test_module_pattern_formatting() {
         // Verify module pattern is formatted correctly for different IDs
@@ -0,0 +1,166 @@
+//! Update command - Refresh problem assets without touching solution code
+//!
+//! Re-fetches the description, test cases, and metadata for one or all
+//! downloaded problems, rewrites the metadata and statement snapshot, and
+//! reports when the statement changed since it was downloaded. The solution
+//! `.rs` file is never overwritten.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::list_local_solutions,
+    meta::ProblemMeta,
+};
+
+/// Update problem assets for one problem or the whole workspace
+pub async fn execute(client: &LeetCodeClient, id: Option<u32>, all: bool) -> Result<()> {
+    let targets: Vec<(u32, String)> = if all {
+        list_local_solutions()?
+            .into_iter()
+            .map(|s| (s.id, s.slug))
+            .collect()
+    } else {
+        let id = id.ok_or_else(|| anyhow::anyhow!("specify a problem ID or use --all"))?;
+        let slug = match ProblemMeta::load(id)? {
+            Some(meta) => meta.slug,
+            None => client
+                .get_problem_by_id(id)
+                .await?
+                .map(|p| p.stat.question_title_slug())
+                .ok_or_else(|| crate::error::CliError::ProblemNotFound(format!("ID {id}")))?,
+        };
+        vec![(id, slug)]
+    };
+
+    if targets.is_empty() {
+        println!("{}", "No local solutions found in src/solutions/.".yellow());
+        return Ok(());
+    }
+
+    let mut changed_count = 0;
+    for (id, slug) in &targets {
+        let detail = match client.get_problem_detail(slug).await {
+            Ok(d) => d,
+            Err(e) => {
+                println!("  {} problem {id}: {e}", "✗ failed:".red());
+                continue;
+            }
+        };
+
+        let new_description = detail.clean_content();
+
+        // Compare against the stored snapshot to detect statement changes
+        if let Some(old_description) = ProblemMeta::load_description(*id)? {
+            let (added, removed) = diff_summary(&old_description, &new_description);
+            if added > 0 || removed > 0 {
+                changed_count += 1;
+                println!(
+                    "  {} problem {id} ({slug}): +{added}/-{removed} lines",
+                    "! statement changed:".yellow()
+                );
+                // Only offer the diff interactively for a single problem;
+                // prompting through an --all sweep would be tedious
+                if targets.len() == 1
+                    && crate::commands::prompt_confirm("Show a diff of the statement? [Y/n]")?
+                {
+                    crate::commands::diff::print_unified_diff(&old_description, &new_description);
+                }
+            } else {
+                println!("  {} problem {id} ({slug})", "= unchanged:".normal());
+            }
+        } else {
+            println!("  {} problem {id} ({slug})", "+ snapshot created:".cyan());
+        }
+
+        // Rewrite metadata, preserving download-time fields
+        let existing = ProblemMeta::load(*id)?;
+        let meta = ProblemMeta {
+            id: detail.question_id.parse().unwrap_or(0),
+            frontend_id: *id,
+            slug: slug.clone(),
+            title: detail.title.clone(),
+            difficulty: detail.difficulty.clone(),
+            tags: detail
+                .topic_tags
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|t| t.name)
+                .collect(),
+            downloaded_at: existing
+                .as_ref()
+                .map(|m| m.downloaded_at)
+                .unwrap_or_else(ProblemMeta::now),
+            language: existing
+                .as_ref()
+                .map(|m| m.language.clone())
+                .unwrap_or_else(|| "rust".to_string()),
+            time_limit_ms: existing.as_ref().and_then(|m| m.time_limit_ms),
+            memory_limit_mb: existing.as_ref().and_then(|m| m.memory_limit_mb),
+            module: existing.and_then(|m| m.module),
+        };
+        meta.save()?;
+        ProblemMeta::save_description(*id, &new_description)?;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "✓ Updated {} problem(s), {} with statement changes",
+            targets.len(),
+            changed_count
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+/// Summarize the difference between two statements as (added, removed)
+/// line counts, ignoring ordering.
+pub(crate) fn diff_summary(old: &str, new: &str) -> (usize, usize) {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    let added = new_lines
+        .iter()
+        .filter(|line| !old_lines.contains(line))
+        .count();
+    let removed = old_lines
+        .iter()
+        .filter(|line| !new_lines.contains(line))
+        .count();
+    (added, removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_summary_identical() {
+        assert_eq!(diff_summary("a\nb\nc", "a\nb\nc"), (0, 0));
+    }
+
+    #[test]
+    fn test_diff_summary_added_lines() {
+        assert_eq!(diff_summary("a\nb", "a\nb\nc\nd"), (2, 0));
+    }
+
+    #[test]
+    fn test_diff_summary_removed_lines() {
+        assert_eq!(diff_summary("a\nb\nc", "a"), (0, 2));
+    }
+
+    #[test]
+    fn test_diff_summary_changed_line() {
+        assert_eq!(diff_summary("1 <= n <= 100", "1 <= n <= 1000"), (1, 1));
+    }
+
+    #[test]
+    fn test_diff_summary_ignores_reordering() {
+        assert_eq!(diff_summary("a\nb", "b\na"), (0, 0));
+    }
+}
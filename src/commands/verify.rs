@@ -0,0 +1,162 @@
+//! Verify command - Run the tests of every downloaded problem
+//!
+//! Iterates all solution modules in the workspace, runs `cargo test` (or
+//! cargo-nextest when installed or configured) for each, and prints a
+//! pass/fail matrix plus a summary — a single command to confirm the whole
+//! archive still builds and passes.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use colored::Colorize;
+use tokio::sync::Semaphore;
+
+use crate::{commands::list_local_solutions, config::Config};
+
+/// Outcome of one problem's test run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum VerifyOutcome {
+    Passed,
+    Failed,
+}
+
+/// Run tests for every downloaded problem, up to `jobs` in parallel
+pub async fn execute(jobs: usize) -> Result<()> {
+    let solutions = list_local_solutions()?;
+    if solutions.is_empty() {
+        println!("{}", "No local solutions found in src/solutions/.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Verifying {} problems ({} job{})...",
+            solutions.len(),
+            jobs,
+            if jobs == 1 { "" } else { "s" }
+        )
+        .cyan()
+    );
+
+    // Share a target directory across runs so dependencies compile once
+    let config = Config::load()?;
+    let target_dir = config.target_dir.clone();
+    let runner = crate::commands::test::TestRunner::from_config(&config);
+
+    let semaphore = Arc::new(Semaphore::new(jobs.max(1)));
+    let mut handles = Vec::new();
+    for solution in &solutions {
+        let module = solution
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let id = solution.id;
+        let slug = solution.slug.clone();
+        let semaphore = semaphore.clone();
+        let target_dir = target_dir.clone();
+
+        handles.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire().await.expect("semaphore closed");
+            let mut command = tokio::process::Command::new("cargo");
+            command.args(runner.cargo_args()).arg(format!("{module}::"));
+            if let Some(dir) = target_dir {
+                command.env("CARGO_TARGET_DIR", dir);
+            }
+            let outcome = match command.output().await {
+                Ok(output) if output.status.success() => VerifyOutcome::Passed,
+                _ => VerifyOutcome::Failed,
+            };
+            (id, slug, outcome)
+        }));
+    }
+
+    let mut results = Vec::new();
+    for handle in handles {
+        let (id, slug, outcome) = handle.await?;
+        println!(
+            "  {} p{id:04} {slug}",
+            match outcome {
+                VerifyOutcome::Passed => "✓".green(),
+                VerifyOutcome::Failed => "✗".red(),
+            }
+        );
+        results.push((id, outcome));
+    }
+
+    print!("{}", render_summary(&results));
+    if results.iter().any(|(_, o)| *o == VerifyOutcome::Failed) {
+        anyhow::bail!("some problems failed verification");
+    }
+
+    Ok(())
+}
+
+/// Render the pass/fail matrix and summary line.
+pub(crate) fn render_summary(results: &[(u32, VerifyOutcome)]) -> String {
+    let passed = results
+        .iter()
+        .filter(|(_, o)| *o == VerifyOutcome::Passed)
+        .count();
+    let failed = results.len() - passed;
+
+    let mut out = String::from("\nMatrix: ");
+    for (_, outcome) in results {
+        out.push(match outcome {
+            VerifyOutcome::Passed => '.',
+            VerifyOutcome::Failed => 'F',
+        });
+    }
+    out.push('\n');
+
+    if failed > 0 {
+        out.push_str("Failed problems: ");
+        let ids: Vec<String> = results
+            .iter()
+            .filter(|(_, o)| *o == VerifyOutcome::Failed)
+            .map(|(id, _)| id.to_string())
+            .collect();
+        out.push_str(&ids.join(", "));
+        out.push('\n');
+    }
+
+    out.push_str(&format!(
+        "Summary: {passed} passed, {failed} failed, {} total\n",
+        results.len()
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_summary_all_passed() {
+        let results = vec![(1, VerifyOutcome::Passed), (2, VerifyOutcome::Passed)];
+        let summary = render_summary(&results);
+        assert!(summary.contains("Matrix: .."));
+        assert!(summary.contains("Summary: 2 passed, 0 failed, 2 total"));
+        assert!(!summary.contains("Failed problems"));
+    }
+
+    #[test]
+    fn test_render_summary_with_failures() {
+        let results = vec![
+            (1, VerifyOutcome::Passed),
+            (9, VerifyOutcome::Failed),
+            (42, VerifyOutcome::Failed),
+        ];
+        let summary = render_summary(&results);
+        assert!(summary.contains("Matrix: .FF"));
+        assert!(summary.contains("Failed problems: 9, 42"));
+        assert!(summary.contains("Summary: 1 passed, 2 failed, 3 total"));
+    }
+
+    #[test]
+    fn test_render_summary_empty() {
+        let summary = render_summary(&[]);
+        assert!(summary.contains("Summary: 0 passed, 0 failed, 0 total"));
+    }
+}
@@ -0,0 +1,206 @@
+//! Whatsnew command - Spot newly added problems
+//!
+//! Compares the freshly fetched problem list against a cached set of known
+//! IDs (`known_problems.json` at the workspace root) and lists questions
+//! added since the last check. `--download` grabs the new free ones,
+//! optionally restricted to a topic tag.
+
+use std::{collections::BTreeSet, path::PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::LeetCodeClient,
+    commands::pick::download_problem,
+    problem::{DifficultyLevel, Problem},
+};
+
+const KNOWN_FILE: &str = "known_problems.json";
+
+/// The set of problem IDs seen on previous runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct KnownProblems {
+    #[serde(default)]
+    ids: BTreeSet<u32>,
+}
+
+impl KnownProblems {
+    fn load() -> Result<Self> {
+        let path = PathBuf::from(KNOWN_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    fn save(&self) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(PathBuf::from(KNOWN_FILE), content)?;
+        Ok(())
+    }
+}
+
+/// List problems added since the last check, optionally downloading them
+pub async fn execute(client: &LeetCodeClient, download: bool, tag: Option<String>) -> Result<()> {
+    println!("{}", "Fetching problems...".cyan());
+    let problems = client.get_all_problems().await?;
+    let mut known = KnownProblems::load()?;
+
+    // First run: record the baseline instead of reporting everything as new
+    if known.ids.is_empty() {
+        known.ids = problems
+            .iter()
+            .map(|p| p.stat.frontend_question_id)
+            .collect();
+        known.save()?;
+        println!(
+            "{}",
+            format!(
+                "✓ Recorded {} problems as the baseline; run 'whatsnew' again later \
+                 to see additions",
+                known.ids.len()
+            )
+            .green()
+        );
+        return Ok(());
+    }
+
+    let new_problems: Vec<&Problem> = problems
+        .iter()
+        .filter(|p| !known.ids.contains(&p.stat.frontend_question_id))
+        .collect();
+
+    if new_problems.is_empty() {
+        println!("{}", "✓ No new problems since the last check.".green());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("{} new problem(s) since the last check:", new_problems.len()).bold()
+    );
+    for problem in &new_problems {
+        println!("  {}", describe(problem));
+    }
+
+    if download {
+        let tag_slug = tag.map(|t| t.to_lowercase().replace(' ', "-"));
+        for problem in &new_problems {
+            if problem.paid_only {
+                continue;
+            }
+            // Tags only come with the problem detail, so filter there
+            if let Some(ref slug) = tag_slug {
+                let detail = match client
+                    .get_problem_detail(&problem.stat.question_title_slug())
+                    .await
+                {
+                    Ok(d) => d,
+                    Err(_) => continue,
+                };
+                let matches = detail
+                    .topic_tags
+                    .as_ref()
+                    .is_some_and(|tags| tags.iter().any(|t| t.slug == *slug));
+                if !matches {
+                    continue;
+                }
+            }
+            download_problem(client, problem).await?;
+        }
+    }
+
+    known.ids.extend(
+        new_problems
+            .iter()
+            .map(|p| p.stat.frontend_question_id),
+    );
+    known.save()?;
+    Ok(())
+}
+
+/// One listing line: ID, title, difficulty, plus markers for paid-only
+/// problems and LeetCode's own "new" flag.
+fn describe(problem: &Problem) -> String {
+    let difficulty = match DifficultyLevel::try_from(problem.difficulty.level) {
+        Ok(DifficultyLevel::Easy) => "Easy",
+        Ok(DifficultyLevel::Medium) => "Medium",
+        Ok(DifficultyLevel::Hard) => "Hard",
+        Err(_) => "Unknown",
+    };
+    let mut line = format!(
+        "{}. {} ({difficulty})",
+        problem.stat.frontend_question_id,
+        problem.stat.question_title()
+    );
+    if problem.paid_only {
+        line.push_str(" 🔒");
+    }
+    if problem.stat.is_new_question {
+        line.push_str(" [new]");
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::commands::TestDirGuard;
+    use crate::problem::{Difficulty, Stat};
+
+    fn test_problem(id: u32, title: &str, level: i32) -> Problem {
+        Problem {
+            stat: Stat {
+                question_id: id,
+                question__article__live: None,
+                question__article__slug: None,
+                question__title: Some(title.to_string()),
+                question__title_slug: title.to_lowercase().replace(' ', "-"),
+                question__hide: false,
+                total_acs: 100,
+                total_submitted: 200,
+                frontend_question_id: id,
+                is_new_question: true,
+            },
+            difficulty: Difficulty { level },
+            paid_only: false,
+            is_favor: false,
+            frequency: 0,
+            progress: 0,
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_describe_markers() {
+        let mut problem = test_problem(3200, "Brand New", 2);
+        problem.paid_only = true;
+        let line = describe(&problem);
+        assert!(line.starts_with("3200. Brand New (Medium)"));
+        assert!(line.contains("🔒"));
+        assert!(line.contains("[new]"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_known_problems_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestDirGuard::new(temp_dir);
+
+        let known = KnownProblems::load().unwrap();
+        assert!(known.ids.is_empty());
+
+        let known = KnownProblems {
+            ids: [1, 53, 217].into_iter().collect(),
+        };
+        known.save().unwrap();
+
+        let reloaded = KnownProblems::load().unwrap();
+        assert_eq!(reloaded.ids, known.ids);
+    }
+}
@@ -0,0 +1,238 @@
+//! Writeup command - Assemble a shareable Markdown solution write-up
+//!
+//! Builds a Markdown post from the problem summary, the per-problem notes
+//! file (the approach), complexity comments scraped from the solution
+//! source, and the cleaned code — ready for LeetCode discuss or a blog.
+//! With `--post` the write-up is published to LeetCode's solutions area.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::{
+    api::LeetCodeClient,
+    commands::{export::extract_complexity_comments, find_solution_file},
+    problem::ProblemDetail,
+};
+
+/// Assemble a Markdown write-up for a problem, optionally posting it
+pub async fn execute(client: &LeetCodeClient, id: u32, post: bool) -> Result<()> {
+    let problem = client
+        .get_problem_by_id(id)
+        .await?
+        .ok_or_else(|| crate::error::CliError::ProblemNotFound(format!("ID {id}")))?;
+    let slug = problem.stat.question_title_slug();
+    let detail = client.get_problem_detail(&slug).await?;
+
+    let solution_file = find_solution_file(id, None)?;
+    let code = std::fs::read_to_string(&solution_file)?;
+    let complexity = extract_complexity_comments(&code);
+    let cleaned =
+        LeetCodeClient::strip_local_attributes(&LeetCodeClient::extract_solution_code(&code));
+
+    // Per-problem notes hold the approach, same location export uses
+    let notes_path =
+        PathBuf::from("notes").join(format!("p{id:04}_{}.md", slug.replace('-', "_")));
+    let notes = std::fs::read_to_string(&notes_path).ok();
+    if notes.is_none() {
+        println!(
+            "{}",
+            format!(
+                "! no notes found at {}; the approach section is a placeholder",
+                notes_path.display()
+            )
+            .yellow()
+        );
+    }
+
+    let markdown = build_writeup(
+        id,
+        &slug,
+        &detail,
+        notes.as_deref(),
+        &complexity,
+        &cleaned,
+        fence_language(&solution_file),
+    );
+
+    let output = PathBuf::from("writeups").join(format!("p{id:04}_{}.md", slug.replace('-', "_")));
+    std::fs::create_dir_all("writeups")?;
+    std::fs::write(&output, &markdown)?;
+    println!(
+        "{}",
+        format!("✓ Write-up saved to {}", output.display()).green()
+    );
+
+    if post {
+        println!("{}", "Posting to LeetCode solutions...".cyan());
+        let url = post_solution(client, &slug, &detail.title, &markdown).await?;
+        println!("{}", format!("✓ Posted: {url}").green());
+    }
+    Ok(())
+}
+
+/// Assemble the Markdown post from its pieces.
+#[allow(clippy::too_many_arguments)]
+fn build_writeup(
+    id: u32,
+    slug: &str,
+    detail: &ProblemDetail,
+    notes: Option<&str>,
+    complexity: &[String],
+    code: &str,
+    language: &str,
+) -> String {
+    let mut md = String::new();
+    md.push_str(&format!("# {id}. {} ({})\n\n", detail.title, detail.difficulty));
+    md.push_str(&format!("> https://leetcode.com/problems/{slug}/\n\n"));
+    if let Some(ref tags) = detail.topic_tags
+        && !tags.is_empty()
+    {
+        let names: Vec<&str> = tags.iter().map(|t| t.name.as_str()).collect();
+        md.push_str(&format!("Tags: {}\n\n", names.join(", ")));
+    }
+
+    md.push_str("## Approach\n\n");
+    match notes {
+        Some(notes) => {
+            md.push_str(notes.trim());
+            md.push_str("\n\n");
+        }
+        None => md.push_str("<!-- Describe your approach here -->\n\n"),
+    }
+
+    md.push_str("## Complexity\n\n");
+    if complexity.is_empty() {
+        md.push_str("<!-- Time/space complexity here -->\n\n");
+    } else {
+        for line in complexity {
+            md.push_str(&format!("- {line}\n"));
+        }
+        md.push('\n');
+    }
+
+    md.push_str(&format!("## Code\n\n```{language}\n{}\n```\n", code.trim_end()));
+    md
+}
+
+/// The Markdown code-fence language for a solution file.
+fn fence_language(path: &std::path::Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("py") => "python",
+        Some("ts") => "typescript",
+        Some("js") => "javascript",
+        Some("cpp") => "cpp",
+        Some("go") => "go",
+        Some("sql") => "sql",
+        Some("sh") => "bash",
+        _ => "rust",
+    }
+}
+
+/// Publish the write-up to LeetCode's solutions area and return its URL.
+async fn post_solution(
+    client: &LeetCodeClient,
+    slug: &str,
+    title: &str,
+    content: &str,
+) -> Result<String> {
+    let mutation = r#"
+        mutation ugcArticleCreateSolution($data: UgcArticleCreateOrUpdateSolutionInput!) {
+            ugcArticleCreateSolution(data: $data) {
+                article {
+                    slug
+                }
+            }
+        }
+    "#;
+    let mut variables = HashMap::new();
+    variables.insert(
+        "data".to_string(),
+        serde_json::json!({
+            "questionSlug": slug,
+            "title": title,
+            "content": content,
+            "tags": [],
+            "isPartial": false,
+        }),
+    );
+    let data = client.execute_graphql(mutation, variables).await?;
+    let article_slug = data["data"]["ugcArticleCreateSolution"]["article"]["slug"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("unexpected post response: {data}"))?;
+    Ok(format!(
+        "https://leetcode.com/problems/{slug}/solutions/{article_slug}/"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::TopicTag;
+
+    fn test_detail() -> ProblemDetail {
+        ProblemDetail {
+            question_id: "1".to_string(),
+            title: "Two Sum".to_string(),
+            title_slug: "two-sum".to_string(),
+            content: String::new(),
+            difficulty: "Easy".to_string(),
+            example_testcases: None,
+            sample_test_case: None,
+            meta_data: None,
+            code_snippets: None,
+            hints: None,
+            topic_tags: Some(vec![
+                TopicTag {
+                    name: "Array".to_string(),
+                    slug: "array".to_string(),
+                },
+                TopicTag {
+                    name: "Hash Table".to_string(),
+                    slug: "hash-table".to_string(),
+                },
+            ]),
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
+        }
+    }
+
+    #[test]
+    fn test_build_writeup_full() {
+        let md = build_writeup(
+            1,
+            "two-sum",
+            &test_detail(),
+            Some("Use a hash map of value -> index.\n"),
+            &["Time Complexity: O(n)".to_string()],
+            "impl Solution {}",
+            "rust",
+        );
+        assert!(md.starts_with("# 1. Two Sum (Easy)"));
+        assert!(md.contains("> https://leetcode.com/problems/two-sum/"));
+        assert!(md.contains("Tags: Array, Hash Table"));
+        assert!(md.contains("Use a hash map of value -> index."));
+        assert!(md.contains("- Time Complexity: O(n)"));
+        assert!(md.contains("```rust\nimpl Solution {}\n```"));
+    }
+
+    #[test]
+    fn test_build_writeup_placeholders() {
+        let mut detail = test_detail();
+        detail.topic_tags = None;
+        let md = build_writeup(1, "two-sum", &detail, None, &[], "impl Solution {}", "rust");
+        assert!(!md.contains("Tags:"));
+        assert!(md.contains("<!-- Describe your approach here -->"));
+        assert!(md.contains("<!-- Time/space complexity here -->"));
+    }
+
+    #[test]
+    fn test_fence_language() {
+        assert_eq!(fence_language(std::path::Path::new("a/solution.py")), "python");
+        assert_eq!(fence_language(std::path::Path::new("a/solution.sh")), "bash");
+        assert_eq!(fence_language(std::path::Path::new("src/solutions/p0001.rs")), "rust");
+    }
+}
@@ -5,35 +5,283 @@ use serde::{Deserialize, Serialize};
 
 const APP_NAME: &str = "leetcode-cli";
 
+/// Current config schema version; bump it (and extend
+/// [`Config::migrate_schema`]) whenever a saved field changes shape.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Prefix marking an encrypted credential: `enc:v1:<hex nonce+ciphertext>`,
+/// ChaCha20-Poly1305 under a SHA-256-derived passphrase key.
+const ENC_PREFIX: &str = "enc:v1:";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Schema version of the file this config was loaded from; files
+    /// predating the field deserialize as 0.
+    #[serde(default)]
+    pub version: u32,
     pub session_cookie: Option<String>,
     pub csrf_token: Option<String>,
     pub default_language: String,
     pub workspace_path: Option<PathBuf>,
     pub editor: Option<String>,
+    /// Template for solution module names. Placeholders: {id} (plain ID),
+    /// {id4} (zero-padded to 4), {slug} (underscored), {difficulty}.
+    #[serde(default)]
+    pub file_template: Option<String>,
+    /// Shared cargo target directory for test runs, so repeated builds
+    /// reuse compiled dependencies instead of filling per-checkout targets.
+    #[serde(default)]
+    pub target_dir: Option<PathBuf>,
+    /// Rust version LeetCode's judge runs, e.g. "1.79.0". When set, downloads
+    /// pin `rust-toolchain.toml` to it and submit warns if the local rustc
+    /// differs, since newer std APIs compile locally but fail remotely.
+    #[serde(default)]
+    pub leetcode_rust_version: Option<String>,
+    /// Run rustfmt and strip local-only attributes/doc comments before
+    /// uploading, so scratch formatting doesn't end up in the submission
+    /// history.
+    #[serde(default)]
+    pub submit_format: bool,
+    /// Max polling attempts while waiting for a submission verdict
+    /// (default 30).
+    #[serde(default)]
+    pub poll_max_attempts: Option<usize>,
+    /// Minimum delay between polling attempts, in seconds (default 3).
+    #[serde(default)]
+    pub poll_min_delay_secs: Option<u64>,
+    /// Maximum delay between polling attempts, in seconds (default 15).
+    #[serde(default)]
+    pub poll_max_delay_secs: Option<u64>,
+    /// Test runner: "nextest" for cargo-nextest, "cargo" for plain cargo
+    /// test. Unset auto-detects nextest and uses it when installed.
+    #[serde(default)]
+    pub test_runner: Option<String>,
+    /// Base URL of the LeetCode endpoint (default https://leetcode.com);
+    /// override e.g. for leetcode.cn.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// Default columns for `list`, e.g. "id,title,difficulty,acceptance".
+    /// Overridden per invocation by `list --columns`.
+    #[serde(default)]
+    pub list_columns: Option<String>,
+    /// Daily goal: problems to solve per day, shown by `dashboard` and
+    /// enforced by the streak warning on `pick`.
+    #[serde(default)]
+    pub daily_target: Option<u32>,
+    /// Weekly goal: medium-difficulty problems to solve per rolling
+    /// seven-day window, shown by `dashboard`.
+    #[serde(default)]
+    pub weekly_medium: Option<u32>,
+    /// GitHub token used by `share --gist`; the GITHUB_TOKEN environment
+    /// variable takes precedence.
+    #[serde(default)]
+    pub github_token: Option<String>,
+    /// UTC hour (0-23) after which `submit` reminds about an unsolved
+    /// daily challenge, e.g. 18 for 6pm UTC. Unset disables the reminder.
+    #[serde(default)]
+    pub daily_reminder_hour: Option<u32>,
+    /// Template generation settings, the `[template]` section.
+    #[serde(default)]
+    pub template: TemplateConfig,
+}
+
+/// How generated solution files are decorated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct TemplateConfig {
+    /// Author name substituted for `{author}` in header lines.
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Extra header lines added after the standard Problem/Difficulty/URL
+    /// block. Placeholders: {author}, {date} (UTC, YYYY-MM-DD), {id},
+    /// {title}, {slug}, {difficulty}, {rating} (like ratio), {tags}, {url}.
+    #[serde(default)]
+    pub header: Vec<String>,
+    /// Leave the problem statement and example comments out of generated
+    /// files, keeping only the header block and the starter snippet.
+    #[serde(default)]
+    pub skip_statement: bool,
+    /// Extra crates injected into every generated `Cargo.toml`, as bare
+    /// names (`itertools`) or `name = "version"` entries (`rand = "0.9"`).
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Rust edition for generated `Cargo.toml` files (default "2021").
+    #[serde(default)]
+    pub edition: Option<String>,
+    /// `[lints]` entries for generated `Cargo.toml` files, as dotted keys
+    /// relative to the table, e.g. `clippy.pedantic = "warn"`.
+    #[serde(default)]
+    pub lints: Vec<String>,
+    /// Contents written to `clippy.toml` in the workspace, so every
+    /// downloaded problem follows the same style rules.
+    #[serde(default)]
+    pub clippy_toml: Option<String>,
+}
+
+impl TemplateConfig {
+    /// The configured dependencies as `(name, version requirement)` pairs;
+    /// bare names default to `"*"`.
+    pub fn dependency_specs(&self) -> Vec<(String, String)> {
+        self.dependencies
+            .iter()
+            .map(|entry| match entry.split_once('=') {
+                Some((name, version)) => (
+                    name.trim().to_string(),
+                    version.trim().trim_matches('"').to_string(),
+                ),
+                None => (entry.trim().to_string(), "*".to_string()),
+            })
+            .collect()
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            version: CONFIG_VERSION,
             session_cookie: None,
             csrf_token: None,
             default_language: "rust".to_string(),
             workspace_path: None,
             editor: None,
+            file_template: None,
+            target_dir: None,
+            leetcode_rust_version: None,
+            submit_format: false,
+            poll_max_attempts: None,
+            poll_min_delay_secs: None,
+            poll_max_delay_secs: None,
+            test_runner: None,
+            endpoint: None,
+            list_columns: None,
+            daily_target: None,
+            weekly_medium: None,
+            github_token: None,
+            daily_reminder_hour: None,
+            template: TemplateConfig::default(),
         }
     }
 }
 
 impl Config {
     pub fn load() -> Result<Self> {
-        let config: Config = confy::load(APP_NAME, None)?;
+        let mut config = Self::load_file()?;
+        config.migrate_schema();
+        config.apply_env_overrides();
+        if config.has_encrypted_credentials() {
+            let key = passphrase_key()?;
+            config.decrypt_credentials(&key)?;
+        }
         Ok(config)
     }
 
+    /// Load the config file without migrations or environment overrides,
+    /// so `config migrate` doesn't bake transient env values into it.
+    pub(crate) fn load_file() -> Result<Self> {
+        Ok(confy::load(APP_NAME, None)?)
+    }
+
+    /// Upgrade an older schema to [`CONFIG_VERSION`] step by step,
+    /// returning whether anything changed. Each arm migrates one version;
+    /// unknown future versions are left alone.
+    pub(crate) fn migrate_schema(&mut self) -> bool {
+        let from = self.version;
+        while self.version < CONFIG_VERSION {
+            // One arm per version bump; v0 -> v1 only introduced the
+            // version field itself, so values carry over unchanged
+            self.version += 1;
+        }
+        self.version != from
+    }
+
+    /// Apply `LEETCODE_*` environment overrides, which take precedence
+    /// over the config file — useful in CI and containers where writing
+    /// one is awkward:
+    ///
+    /// - `LEETCODE_SESSION` — session cookie
+    /// - `LEETCODE_CSRF` — CSRF token
+    /// - `LEETCODE_ENDPOINT` — API base URL
+    /// - `LEETCODE_WORKSPACE` — workspace path
+    /// - `LEETCODE_LANG` — default template language
+    /// - `LEETCODE_EDITOR` — editor command
+    /// - `LEETCODE_TARGET_DIR` — shared cargo target directory
+    ///
+    /// Empty values are ignored, so `LEETCODE_SESSION= leetcode-cli ...`
+    /// doesn't clobber a configured cookie.
+    fn apply_env_overrides(&mut self) {
+        fn env(name: &str) -> Option<String> {
+            std::env::var(name).ok().filter(|v| !v.is_empty())
+        }
+        if let Some(v) = env("LEETCODE_SESSION") {
+            self.session_cookie = Some(v);
+        }
+        if let Some(v) = env("LEETCODE_CSRF") {
+            self.csrf_token = Some(v);
+        }
+        if let Some(v) = env("LEETCODE_ENDPOINT") {
+            self.endpoint = Some(v);
+        }
+        if let Some(v) = env("LEETCODE_WORKSPACE") {
+            self.workspace_path = Some(PathBuf::from(v));
+        }
+        if let Some(v) = env("LEETCODE_LANG") {
+            self.default_language = v;
+        }
+        if let Some(v) = env("LEETCODE_EDITOR") {
+            self.editor = Some(v);
+        }
+        if let Some(v) = env("LEETCODE_TARGET_DIR") {
+            self.target_dir = Some(PathBuf::from(v));
+        }
+    }
+
+    /// Whether any stored credential is encrypted at rest.
+    pub fn has_encrypted_credentials(&self) -> bool {
+        [&self.session_cookie, &self.csrf_token]
+            .iter()
+            .any(|field| {
+                field
+                    .as_deref()
+                    .is_some_and(|v| v.starts_with(ENC_PREFIX))
+            })
+    }
+
+    /// Encrypt the stored credentials in place under `key`; values that
+    /// are already encrypted stay as they are.
+    pub fn encrypt_credentials(&mut self, key: &[u8; 32]) -> Result<()> {
+        for field in [&mut self.session_cookie, &mut self.csrf_token] {
+            if let Some(value) = field.as_deref()
+                && !value.starts_with(ENC_PREFIX)
+            {
+                *field = Some(encrypt_value(value, key)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Decrypt the stored credentials in place under `key`. Fails on a
+    /// wrong passphrase, since Poly1305 authenticates the ciphertext.
+    pub fn decrypt_credentials(&mut self, key: &[u8; 32]) -> Result<()> {
+        for field in [&mut self.session_cookie, &mut self.csrf_token] {
+            if let Some(payload) = field.as_deref().and_then(|v| v.strip_prefix(ENC_PREFIX)) {
+                *field = Some(decrypt_value(payload, key)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Save the config with write-then-rename, so a crash mid-write can't
+    /// leave a corrupt file behind.
     pub fn save(&self) -> Result<()> {
-        confy::store(APP_NAME, None, self)?;
+        let mut config = self.clone();
+        config.version = CONFIG_VERSION;
+
+        let path = get_config_path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("toml.tmp");
+        std::fs::write(&tmp, toml::to_string_pretty(&config)?)?;
+        std::fs::rename(&tmp, &path)?;
         Ok(())
     }
 
@@ -54,6 +302,13 @@ impl Config {
         self.workspace_path = Some(path);
     }
 
+    /// The solution file-name template, defaulting to the standard layout.
+    pub fn get_file_template(&self) -> String {
+        self.file_template
+            .clone()
+            .unwrap_or_else(|| "p{id4}_{slug}".to_string())
+    }
+
     #[allow(dead_code)]
     pub fn get_editor(&self) -> String {
         self.editor
@@ -71,6 +326,64 @@ impl Config {
     }
 }
 
+/// The 32-byte encryption key: SHA-256 of the passphrase from
+/// `LEETCODE_CONFIG_KEY`, or prompted for when the variable is unset.
+pub(crate) fn passphrase_key() -> Result<[u8; 32]> {
+    use sha2::Digest;
+
+    let passphrase = match std::env::var("LEETCODE_CONFIG_KEY") {
+        Ok(v) if !v.is_empty() => v,
+        _ => crate::commands::prompt_input("Config passphrase:")?,
+    };
+    if passphrase.is_empty() {
+        anyhow::bail!("the config passphrase must not be empty");
+    }
+    Ok(sha2::Sha256::digest(passphrase.as_bytes()).into())
+}
+
+/// Encrypt a credential, prepending the random nonce to the ciphertext.
+fn encrypt_value(plaintext: &str, key: &[u8; 32]) -> Result<String> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+
+    let cipher = chacha20poly1305::ChaCha20Poly1305::new(key.into());
+    let nonce: [u8; 12] = rand::random();
+    let ciphertext = cipher
+        .encrypt((&nonce).into(), plaintext.as_bytes())
+        .map_err(|_| anyhow::anyhow!("failed to encrypt credential"))?;
+    let mut payload = nonce.to_vec();
+    payload.extend(ciphertext);
+    Ok(format!("{ENC_PREFIX}{}", hex_encode(&payload)))
+}
+
+/// Decrypt an `enc:v1:` payload (without its prefix).
+fn decrypt_value(payload: &str, key: &[u8; 32]) -> Result<String> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+
+    let payload = hex_decode(payload)
+        .filter(|p| p.len() > 12)
+        .ok_or_else(|| anyhow::anyhow!("malformed encrypted credential"))?;
+    let (nonce, ciphertext) = payload.split_at(12);
+    let cipher = chacha20poly1305::ChaCha20Poly1305::new(key.into());
+    let plaintext = cipher
+        .decrypt(nonce.into(), ciphertext)
+        .map_err(|_| anyhow::anyhow!("wrong passphrase or corrupted credential"))?;
+    Ok(String::from_utf8(plaintext)?)
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
 // Helper function to get config file path
 #[allow(dead_code)]
 pub fn get_config_path() -> Result<PathBuf> {
@@ -92,6 +405,77 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_migrate_schema() {
+        let mut config = Config {
+            version: 0,
+            ..Default::default()
+        };
+        assert!(config.migrate_schema());
+        assert_eq!(config.version, CONFIG_VERSION);
+
+        // Already-current configs are untouched
+        assert!(!config.migrate_schema());
+        assert_eq!(config.version, CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_toml_roundtrip_keeps_version() {
+        let config = Config::default();
+        let toml = toml::to_string_pretty(&config).unwrap();
+        let reloaded: Config = toml::from_str(&toml).unwrap();
+        assert_eq!(reloaded.version, CONFIG_VERSION);
+
+        // A file from before the version field deserializes as version 0
+        let legacy: Config = toml::from_str("default_language = \"rust\"\n").unwrap();
+        assert_eq!(legacy.version, 0);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let key = [7u8; 32];
+        let mut config = Config {
+            session_cookie: Some("secret-session".to_string()),
+            csrf_token: Some("secret-csrf".to_string()),
+            ..Default::default()
+        };
+
+        config.encrypt_credentials(&key).unwrap();
+        assert!(config.has_encrypted_credentials());
+        let encrypted = config.session_cookie.clone().unwrap();
+        assert!(encrypted.starts_with("enc:v1:"));
+        assert!(!encrypted.contains("secret"));
+
+        // Encrypting again is a no-op on already-encrypted values
+        config.encrypt_credentials(&key).unwrap();
+        assert_eq!(config.session_cookie.as_deref(), Some(encrypted.as_str()));
+
+        config.decrypt_credentials(&key).unwrap();
+        assert!(!config.has_encrypted_credentials());
+        assert_eq!(config.session_cookie.as_deref(), Some("secret-session"));
+        assert_eq!(config.csrf_token.as_deref(), Some("secret-csrf"));
+    }
+
+    #[test]
+    fn test_decrypt_wrong_key_fails() {
+        let mut config = Config {
+            session_cookie: Some("secret-session".to_string()),
+            ..Default::default()
+        };
+        config.encrypt_credentials(&[7u8; 32]).unwrap();
+
+        let err = config.decrypt_credentials(&[8u8; 32]).unwrap_err();
+        assert!(err.to_string().contains("wrong passphrase"));
+    }
+
+    #[test]
+    fn test_hex_roundtrip() {
+        assert_eq!(hex_encode(&[0x00, 0xff, 0x1a]), "00ff1a");
+        assert_eq!(hex_decode("00ff1a"), Some(vec![0x00, 0xff, 0x1a]));
+        assert_eq!(hex_decode("0f0"), None, "odd length");
+        assert_eq!(hex_decode("zz"), None);
+    }
+
     #[test]
     fn test_default_config() {
         let config = Config::default();
@@ -180,11 +564,35 @@ mod tests {
     #[test]
     fn test_config_serde_roundtrip() {
         let config = Config {
+            version: CONFIG_VERSION,
             session_cookie: Some("session123".to_string()),
             csrf_token: Some("csrf456".to_string()),
             default_language: "python".to_string(),
             workspace_path: Some(PathBuf::from("/workspace")),
             editor: Some("emacs".to_string()),
+            file_template: Some("{difficulty}_{id}_{slug}".to_string()),
+            target_dir: Some(PathBuf::from("/tmp/leetcode-target")),
+            leetcode_rust_version: Some("1.79.0".to_string()),
+            submit_format: true,
+            poll_max_attempts: Some(10),
+            poll_min_delay_secs: Some(1),
+            poll_max_delay_secs: Some(5),
+            test_runner: Some("nextest".to_string()),
+            endpoint: Some("https://leetcode.cn".to_string()),
+            list_columns: Some("id,title,acceptance".to_string()),
+            daily_target: Some(2),
+            weekly_medium: Some(5),
+            github_token: Some("ghp_test".to_string()),
+            daily_reminder_hour: Some(18),
+            template: TemplateConfig {
+                author: Some("alice".to_string()),
+                header: vec!["Author: {author}".to_string()],
+                skip_statement: true,
+                dependencies: vec!["itertools".to_string()],
+                edition: Some("2024".to_string()),
+                lints: vec!["clippy.pedantic = \"warn\"".to_string()],
+                clippy_toml: Some("too-many-arguments-threshold = 10".to_string()),
+            },
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -195,5 +603,84 @@ mod tests {
         assert_eq!(deserialized.default_language, config.default_language);
         assert_eq!(deserialized.workspace_path, config.workspace_path);
         assert_eq!(deserialized.editor, config.editor);
+        assert_eq!(deserialized.file_template, config.file_template);
+        assert_eq!(deserialized.target_dir, config.target_dir);
+        assert_eq!(
+            deserialized.leetcode_rust_version,
+            config.leetcode_rust_version
+        );
+        assert_eq!(deserialized.submit_format, config.submit_format);
+        assert_eq!(deserialized.poll_max_attempts, config.poll_max_attempts);
+        assert_eq!(deserialized.poll_min_delay_secs, config.poll_min_delay_secs);
+        assert_eq!(deserialized.poll_max_delay_secs, config.poll_max_delay_secs);
+        assert_eq!(deserialized.test_runner, config.test_runner);
+        assert_eq!(deserialized.endpoint, config.endpoint);
+        assert_eq!(deserialized.daily_target, config.daily_target);
+        assert_eq!(deserialized.weekly_medium, config.weekly_medium);
+        assert_eq!(deserialized.daily_reminder_hour, config.daily_reminder_hour);
+        assert_eq!(deserialized.template, config.template);
+    }
+
+    #[test]
+    fn test_dependency_specs_parses_entries() {
+        let template = TemplateConfig {
+            dependencies: vec![
+                "rand = \"0.9\"".to_string(),
+                "itertools".to_string(),
+                " serde =\"1\" ".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            template.dependency_specs(),
+            vec![
+                ("rand".to_string(), "0.9".to_string()),
+                ("itertools".to_string(), "*".to_string()),
+                ("serde".to_string(), "1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_apply_env_overrides() {
+        unsafe {
+            env::set_var("LEETCODE_SESSION", "env-session");
+            env::set_var("LEETCODE_ENDPOINT", "https://leetcode.cn");
+            env::set_var("LEETCODE_CSRF", "");
+        }
+
+        let mut config = Config {
+            session_cookie: Some("file-session".to_string()),
+            csrf_token: Some("file-csrf".to_string()),
+            ..Default::default()
+        };
+        config.apply_env_overrides();
+
+        // Set variables win over the file; empty ones are ignored
+        assert_eq!(config.session_cookie.as_deref(), Some("env-session"));
+        assert_eq!(config.csrf_token.as_deref(), Some("file-csrf"));
+        assert_eq!(config.endpoint.as_deref(), Some("https://leetcode.cn"));
+
+        unsafe {
+            env::remove_var("LEETCODE_SESSION");
+            env::remove_var("LEETCODE_ENDPOINT");
+            env::remove_var("LEETCODE_CSRF");
+        }
+    }
+
+    #[test]
+    fn test_get_file_template_default() {
+        let config = Config::default();
+        assert_eq!(config.get_file_template(), "p{id4}_{slug}");
+    }
+
+    #[test]
+    fn test_get_file_template_custom() {
+        let config = Config {
+            file_template: Some("{difficulty}_{slug}".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.get_file_template(), "{difficulty}_{slug}");
     }
 }
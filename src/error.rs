@@ -0,0 +1,142 @@
+//! Error taxonomy with remediation hints
+//!
+//! Failures with a known cause and a known fix are raised as [`CliError`]
+//! variants instead of bare `anyhow!` strings, so the user sees a colored,
+//! actionable hint ("run `leetcode-cli login`", "retry after N seconds")
+//! next to the error. Everything else stays plain `anyhow`.
+
+use std::fmt;
+
+use colored::Colorize;
+
+/// A failure the CLI knows how to explain and fix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// No session cookie is stored.
+    NotAuthenticated,
+    /// A session cookie is stored, but LeetCode rejected it.
+    SessionExpired,
+    /// The problem is locked behind a premium subscription. The string
+    /// identifies the problem, e.g. `1 (Two Sum)`.
+    PremiumRequired(String),
+    /// LeetCode throttled the request, with the `Retry-After` delay if
+    /// the response carried one.
+    RateLimited { retry_after_secs: Option<u64> },
+    /// The request never reached LeetCode.
+    NetworkError(String),
+    /// No problem matches the given reference, e.g. `ID 9999`.
+    ProblemNotFound(String),
+    /// The judge did not report a verdict within the polling budget.
+    JudgePending,
+}
+
+impl CliError {
+    fn message(&self) -> String {
+        match self {
+            Self::NotAuthenticated => "not authenticated".to_string(),
+            Self::SessionExpired => "LeetCode rejected the stored session".to_string(),
+            Self::PremiumRequired(problem) => {
+                format!("problem {problem} is premium-only and this account has no premium subscription")
+            }
+            Self::RateLimited { .. } => "rate limited by LeetCode".to_string(),
+            Self::NetworkError(detail) => format!("network error: {detail}"),
+            Self::ProblemNotFound(reference) => format!("problem not found: {reference}"),
+            Self::JudgePending => "timeout waiting for submission result".to_string(),
+        }
+    }
+
+    fn hint(&self) -> String {
+        match self {
+            Self::NotAuthenticated => "run `leetcode-cli login` first".to_string(),
+            Self::SessionExpired => {
+                "the session cookie has likely expired; run `leetcode-cli login` again".to_string()
+            }
+            Self::PremiumRequired(_) => {
+                "pick a free problem or subscribe at https://leetcode.com/subscribe/".to_string()
+            }
+            Self::RateLimited {
+                retry_after_secs: Some(secs),
+            } => format!("retry after {secs} seconds"),
+            Self::RateLimited {
+                retry_after_secs: None,
+            } => "wait a minute before retrying".to_string(),
+            Self::NetworkError(_) => {
+                "check your connection and the configured endpoint".to_string()
+            }
+            Self::ProblemNotFound(_) => {
+                "IDs are the frontend numbers shown by `leetcode-cli list`".to_string()
+            }
+            Self::JudgePending => {
+                "the judge may still be running; check later with `leetcode-cli check <submission-id>`"
+                    .to_string()
+            }
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\n  {}",
+            self.message(),
+            format!("hint: {}", self.hint()).yellow()
+        )
+    }
+}
+
+impl std::error::Error for CliError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_authenticated_display() {
+        colored::control::set_override(false);
+        let msg = CliError::NotAuthenticated.to_string();
+        colored::control::unset_override();
+        assert!(msg.contains("not authenticated"));
+        assert!(msg.contains("hint: run `leetcode-cli login` first"));
+    }
+
+    #[test]
+    fn test_rate_limited_hint_includes_delay() {
+        colored::control::set_override(false);
+        let with_delay = CliError::RateLimited {
+            retry_after_secs: Some(30),
+        }
+        .to_string();
+        let without_delay = CliError::RateLimited {
+            retry_after_secs: None,
+        }
+        .to_string();
+        colored::control::unset_override();
+        assert!(with_delay.contains("retry after 30 seconds"));
+        assert!(without_delay.contains("wait a minute before retrying"));
+    }
+
+    #[test]
+    fn test_problem_not_found_carries_reference() {
+        colored::control::set_override(false);
+        let msg = CliError::ProblemNotFound("ID 9999".to_string()).to_string();
+        colored::control::unset_override();
+        assert!(msg.contains("problem not found: ID 9999"));
+        assert!(msg.contains("leetcode-cli list"));
+    }
+
+    #[test]
+    fn test_judge_pending_keeps_timeout_wording() {
+        colored::control::set_override(false);
+        let msg = CliError::JudgePending.to_string();
+        colored::control::unset_override();
+        assert!(msg.contains("timeout waiting for submission result"));
+        assert!(msg.contains("leetcode-cli check"));
+    }
+
+    #[test]
+    fn test_converts_into_anyhow() {
+        let err: anyhow::Error = CliError::SessionExpired.into();
+        assert!(err.to_string().contains("rejected the stored session"));
+    }
+}
@@ -0,0 +1,832 @@
+//! Local judge emulation for Rust solutions.
+//!
+//! Compiles the solution together with a generated `main` into a small
+//! runner binary, feeds it the example test inputs over stdin (one encoded
+//! argument per line), and compares the runner's stdout against the
+//! expected outputs, applying per-problem time and memory limits. The
+//! result is a LeetCode-style verdict (AC/WA/TLE/MLE, plus CE/RE), so an
+//! obviously broken solution never costs a network submission.
+//!
+//! The generated runner uses only `std`, so a plain `rustc` invocation is
+//! enough — no cargo, no extra crates. Argument and return types come from
+//! the problem's [`TestConfig`]; problems using types the encoder doesn't
+//! cover (trees, linked lists, ...) are reported as unjudgeable rather
+//! than guessed at.
+
+use std::{
+    fmt,
+    io::Write as _,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::problem::{TestCase, TestConfig};
+
+/// A LeetCode-style verdict from a local judge run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Accepted {
+        cases: usize,
+    },
+    WrongAnswer {
+        case: usize,
+        expected: String,
+        actual: String,
+    },
+    TimeLimitExceeded {
+        case: usize,
+        limit: Duration,
+    },
+    MemoryLimitExceeded {
+        case: usize,
+        limit_kb: u64,
+    },
+    RuntimeError {
+        case: usize,
+        stderr: String,
+    },
+    CompileError {
+        stderr: String,
+    },
+}
+
+impl Verdict {
+    /// The short verdict code LeetCode users know.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Accepted { .. } => "AC",
+            Verdict::WrongAnswer { .. } => "WA",
+            Verdict::TimeLimitExceeded { .. } => "TLE",
+            Verdict::MemoryLimitExceeded { .. } => "MLE",
+            Verdict::RuntimeError { .. } => "RE",
+            Verdict::CompileError { .. } => "CE",
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Verdict::Accepted { .. })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Accepted { cases } => write!(f, "AC ({cases} case(s))"),
+            Verdict::WrongAnswer {
+                case,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "WA on case {case}: expected {}, got {}",
+                display_encoded(expected),
+                display_encoded(actual)
+            ),
+            Verdict::TimeLimitExceeded { case, limit } => {
+                write!(f, "TLE on case {case} (limit {:.1}s)", limit.as_secs_f64())
+            }
+            Verdict::MemoryLimitExceeded { case, limit_kb } => {
+                write!(f, "MLE on case {case} (limit {} MB)", limit_kb / 1024)
+            }
+            Verdict::RuntimeError { case, stderr } => {
+                write!(f, "RE on case {case}: {}", stderr.trim())
+            }
+            Verdict::CompileError { stderr } => write!(f, "CE: {}", stderr.trim()),
+        }
+    }
+}
+
+/// Per-problem resource limits applied to each test case.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    pub time: Duration,
+    pub memory_kb: u64,
+}
+
+impl Limits {
+    /// Limits scaled by difficulty level (1-3). LeetCode doesn't publish
+    /// its per-problem limits, so these are deliberately generous: a
+    /// solution that trips them locally has no chance remotely.
+    pub fn for_difficulty(level: i32) -> Self {
+        let time = match level {
+            1 => Duration::from_secs(2),
+            3 => Duration::from_secs(8),
+            _ => Duration::from_secs(4),
+        };
+        Limits {
+            time,
+            memory_kb: 256 * 1024,
+        }
+    }
+
+    /// Limits for a language at a difficulty level: the difficulty
+    /// defaults scaled by how much slack LeetCode's judge gives slower
+    /// runtimes. These seed the `time_limit_ms`/`memory_limit_mb` fields
+    /// in downloaded problem metadata.
+    pub fn for_language(language: &str, level: i32) -> Self {
+        let factor = match language {
+            "python" => 5,
+            "javascript" | "typescript" => 4,
+            "java" | "go" => 2,
+            // rust, cpp, c run at the native limits
+            _ => 1,
+        };
+        let base = Self::for_difficulty(level);
+        Limits {
+            time: base.time * factor,
+            memory_kb: base.memory_kb,
+        }
+    }
+
+    /// Per-problem limits: the difficulty defaults, overridden by the
+    /// `time_limit_ms`/`memory_limit_mb` recorded in the problem metadata.
+    pub fn for_problem(level: i32, time_limit_ms: Option<u64>, memory_limit_mb: Option<u64>) -> Self {
+        let mut limits = Self::for_difficulty(level);
+        if let Some(ms) = time_limit_ms {
+            limits.time = Duration::from_millis(ms);
+        }
+        if let Some(mb) = memory_limit_mb {
+            limits.memory_kb = mb * 1024;
+        }
+        limits
+    }
+}
+
+/// One judgeable case: the encoded stdin payload (one line per argument)
+/// and the encoded expected output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JudgeCase {
+    pub input: String,
+    pub expected: String,
+}
+
+/// Build judge cases from the example [`TestCase`]s in `test_cases.json`.
+///
+/// Each case's `input` holds one JSON-serialized value per argument, in
+/// declaration order, separated by newlines; `expected` holds the
+/// JSON-serialized return value.
+pub fn cases_from(test_cases: &[TestCase], cfg: &TestConfig) -> Result<Vec<JudgeCase>> {
+    let mut cases = Vec::new();
+    for (i, tc) in test_cases.iter().enumerate() {
+        let lines: Vec<&str> = tc.input.lines().collect();
+        if lines.len() != cfg.args.len() {
+            bail!(
+                "case {}: expected {} argument(s), found {}",
+                i + 1,
+                cfg.args.len(),
+                lines.len()
+            );
+        }
+        let mut encoded = Vec::new();
+        for (line, arg) in lines.iter().zip(&cfg.args) {
+            let value: serde_json::Value = serde_json::from_str(line)
+                .with_context(|| format!("case {}: argument '{}' is not valid JSON", i + 1, arg.name))?;
+            encoded.push(
+                encode_value(&value, &arg.arg_type)
+                    .with_context(|| format!("case {}: argument '{}'", i + 1, arg.name))?,
+            );
+        }
+        let expected_value: serde_json::Value = serde_json::from_str(&tc.expected)
+            .with_context(|| format!("case {}: expected output is not valid JSON", i + 1))?;
+        let expected = encode_value(&expected_value, &cfg.return_type)
+            .with_context(|| format!("case {}: expected output", i + 1))?;
+        cases.push(JudgeCase {
+            input: encoded.join("\n"),
+            expected,
+        });
+    }
+    Ok(cases)
+}
+
+/// Compile the solution into a runner and judge every case against it.
+///
+/// `Err` means the judge couldn't run at all (unsupported types, rustc
+/// missing, ...); an actual failing solution comes back as a non-accepted
+/// [`Verdict`].
+pub fn run(
+    solution: &Path,
+    cfg: &TestConfig,
+    cases: &[JudgeCase],
+    limits: &Limits,
+) -> Result<Verdict> {
+    let runner = match Runner::compile(solution, cfg)? {
+        Ok(runner) => runner,
+        Err(stderr) => return Ok(Verdict::CompileError { stderr }),
+    };
+
+    for (i, case) in cases.iter().enumerate() {
+        let case_no = i + 1;
+        match runner.run_case(&case.input, limits)? {
+            CaseRun::TimedOut => {
+                return Ok(Verdict::TimeLimitExceeded {
+                    case: case_no,
+                    limit: limits.time,
+                });
+            }
+            CaseRun::OverMemory => {
+                return Ok(Verdict::MemoryLimitExceeded {
+                    case: case_no,
+                    limit_kb: limits.memory_kb,
+                });
+            }
+            CaseRun::Finished(output) => {
+                if !output.status.success() {
+                    return Ok(Verdict::RuntimeError {
+                        case: case_no,
+                        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+                    });
+                }
+                let actual = String::from_utf8_lossy(&output.stdout)
+                    .trim_end()
+                    .to_string();
+                if actual != case.expected {
+                    return Ok(Verdict::WrongAnswer {
+                        case: case_no,
+                        expected: case.expected.clone(),
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+    Ok(Verdict::Accepted { cases: cases.len() })
+}
+
+/// How a single case run ended, before verdict mapping.
+pub enum CaseRun {
+    Finished(std::process::Output),
+    TimedOut,
+    OverMemory,
+}
+
+/// A compiled runner binary for a solution, living in a scratch directory
+/// that is removed when the runner is dropped.
+pub struct Runner {
+    dir: PathBuf,
+    binary: PathBuf,
+}
+
+impl Runner {
+    /// Compile the solution plus the generated `main`. `Ok(Err(stderr))`
+    /// is a compile error in the solution itself; `Err` means the judge
+    /// couldn't run at all (unsupported types, rustc missing, ...).
+    pub fn compile(solution: &Path, cfg: &TestConfig) -> Result<Result<Self, String>> {
+        let code = std::fs::read_to_string(solution)
+            .with_context(|| format!("cannot read {}", solution.display()))?;
+        let source = runner_source(&code, cfg)?;
+
+        let dir = scratch_dir();
+        std::fs::create_dir_all(&dir)?;
+        let source_path = dir.join("main.rs");
+        let binary = dir.join("runner");
+        std::fs::write(&source_path, &source)?;
+
+        let output = Command::new("rustc")
+            .args(["-O", "--edition", "2021", "-o"])
+            .arg(&binary)
+            .arg(&source_path)
+            .output()
+            .context("cannot run rustc (is it on PATH?)");
+        match output {
+            Ok(output) if output.status.success() => Ok(Ok(Runner { dir, binary })),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+                let _ = std::fs::remove_dir_all(&dir);
+                Ok(Err(stderr))
+            }
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&dir);
+                Err(e)
+            }
+        }
+    }
+
+    /// Run the runner on one case's input, enforcing the limits by
+    /// polling the child: wall clock for TLE, `VmHWM` from /proc (Linux
+    /// only) for MLE.
+    pub fn run_case(&self, input: &str, limits: &Limits) -> Result<CaseRun> {
+        run_case(&self.binary, input, limits)
+    }
+}
+
+impl Drop for Runner {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn run_case(binary: &Path, input: &str, limits: &Limits) -> Result<CaseRun> {
+    use std::io::Read as _;
+
+    let mut child = Command::new(binary)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(input.as_bytes());
+        let _ = stdin.write_all(b"\n");
+    }
+    let pid = child.id();
+
+    // Drain the pipes on threads so the child never blocks on a full pipe
+    let mut stdout_pipe = child.stdout.take();
+    let stdout_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(ref mut pipe) = stdout_pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    });
+    let mut stderr_pipe = child.stderr.take();
+    let stderr_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(ref mut pipe) = stderr_pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    });
+
+    let deadline = Instant::now() + limits.time;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(CaseRun::TimedOut);
+        }
+        if sample_rss_kb(pid).is_some_and(|kb| kb > limits.memory_kb) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(CaseRun::OverMemory);
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+
+    Ok(CaseRun::Finished(std::process::Output {
+        status,
+        stdout: stdout_thread.join().unwrap_or_default(),
+        stderr: stderr_thread.join().unwrap_or_default(),
+    }))
+}
+
+/// The runner's peak RSS (`VmHWM`) in kilobytes, from /proc. `None` off
+/// Linux or once the process is gone — memory limits simply don't apply
+/// there.
+fn sample_rss_kb(pid: u32) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    status.lines().find_map(|line| {
+        line.strip_prefix("VmHWM:")?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()
+    })
+}
+
+/// A scratch directory under the system temp dir, unique per invocation so
+/// parallel judges (and tests) never collide.
+fn scratch_dir() -> PathBuf {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    std::env::temp_dir().join(format!(
+        "leetcode-cli-judge-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// Generate the runner: the solution verbatim (its inner attributes stay
+/// at the top of the file) followed by a `main` that loops over cases from
+/// stdin until EOF, one encoded argument per line, printing one encoded
+/// result line per case.
+fn runner_source(code: &str, cfg: &TestConfig) -> Result<String> {
+    let method = cfg.rust_method_name();
+    let mut body = String::new();
+    let mut call_args = Vec::new();
+    for (i, arg) in cfg.args.iter().enumerate() {
+        let codec = codec(&arg.arg_type)
+            .ok_or_else(|| anyhow!("unsupported argument type '{}'", arg.arg_type))?;
+        if i == 0 {
+            body.push_str(&format!(
+                "        let arg0: {} = {};\n",
+                codec.rust, codec.parse
+            ));
+        } else {
+            body.push_str(
+                "        let line = match lines.next() { Some(Ok(l)) => l, _ => break };\n",
+            );
+            body.push_str(&format!(
+                "        let arg{i}: {} = {};\n",
+                codec.rust, codec.parse
+            ));
+        }
+        call_args.push(format!("arg{i}"));
+    }
+    let ret = codec(&cfg.return_type)
+        .ok_or_else(|| anyhow!("unsupported return type '{}'", cfg.return_type))?;
+    body.push_str(&format!(
+        "        let result: {} = Solution::{method}({});\n",
+        ret.rust,
+        call_args.join(", ")
+    ));
+    body.push_str(&format!("        println!(\"{{}}\", {});\n", ret.print));
+
+    Ok(format!(
+        "{code}\n\
+         fn main() {{\n\
+         \x20   use std::io::BufRead as _;\n\
+         \x20   let stdin = std::io::stdin();\n\
+         \x20   let mut lines = stdin.lock().lines();\n\
+         \x20   while let Some(Ok(line)) = lines.next() {{\n\
+         {body}\
+         \x20   }}\n\
+         }}\n"
+    ))
+}
+
+/// Parse/print code for one supported type: the Rust type, an expression
+/// parsing it from the in-scope `line: String`, and an expression encoding
+/// a `result` of that type back to the wire format.
+struct Codec {
+    rust: &'static str,
+    parse: &'static str,
+    print: &'static str,
+}
+
+/// The codec for a LeetCode metadata type, or `None` for types the judge
+/// doesn't emulate (trees, linked lists, ...). Lists are encoded
+/// comma-separated, nested lists row-separated by ';', string lists by the
+/// unit separator so commas in strings survive.
+fn codec(leetcode_type: &str) -> Option<Codec> {
+    let codec = match normalize_type(leetcode_type).as_str() {
+        "integer" => Codec {
+            rust: "i32",
+            parse: "line.trim().parse().unwrap()",
+            print: "result.to_string()",
+        },
+        "long" => Codec {
+            rust: "i64",
+            parse: "line.trim().parse().unwrap()",
+            print: "result.to_string()",
+        },
+        "double" => Codec {
+            rust: "f64",
+            parse: "line.trim().parse().unwrap()",
+            print: "result.to_string()",
+        },
+        "boolean" => Codec {
+            rust: "bool",
+            parse: "line.trim().parse().unwrap()",
+            print: "result.to_string()",
+        },
+        "character" => Codec {
+            rust: "char",
+            parse: "line.chars().next().unwrap()",
+            print: "result.to_string()",
+        },
+        "string" => Codec {
+            rust: "String",
+            parse: "line",
+            print: "result",
+        },
+        "integer[]" => Codec {
+            rust: "Vec<i32>",
+            parse: "if line.is_empty() { Vec::new() } else { \
+                    line.split(',').map(|t| t.trim().parse().unwrap()).collect() }",
+            print: "result.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(\",\")",
+        },
+        "long[]" => Codec {
+            rust: "Vec<i64>",
+            parse: "if line.is_empty() { Vec::new() } else { \
+                    line.split(',').map(|t| t.trim().parse().unwrap()).collect() }",
+            print: "result.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(\",\")",
+        },
+        "integer[][]" => Codec {
+            rust: "Vec<Vec<i32>>",
+            parse: "if line.is_empty() { Vec::new() } else { \
+                    line.split(';').map(|row| if row.is_empty() { Vec::new() } else { \
+                    row.split(',').map(|t| t.trim().parse().unwrap()).collect() }).collect() }",
+            print: "result.iter().map(|row| row.iter().map(|x| x.to_string())\
+                    .collect::<Vec<_>>().join(\",\")).collect::<Vec<_>>().join(\";\")",
+        },
+        "string[]" => Codec {
+            rust: "Vec<String>",
+            parse: "if line.is_empty() { Vec::new() } else { \
+                    line.split('\\u{1f}').map(str::to_string).collect() }",
+            print: "result.join(\"\\u{1f}\")",
+        },
+        _ => return None,
+    };
+    Some(codec)
+}
+
+/// Normalize `list<...>` spellings to the `[]` forms the codec table uses.
+fn normalize_type(leetcode_type: &str) -> String {
+    let mut t = leetcode_type.trim().to_string();
+    while let Some(inner) = t.strip_prefix("list<").and_then(|s| s.strip_suffix('>')) {
+        t = format!("{inner}[]");
+    }
+    t
+}
+
+/// Encode a JSON value for the wire, mirroring the runner's generated
+/// print expression for the same type.
+pub(crate) fn encode_value(value: &serde_json::Value, leetcode_type: &str) -> Result<String> {
+    let normalized = normalize_type(leetcode_type);
+    match normalized.as_str() {
+        "integer" | "long" => value
+            .as_i64()
+            .map(|n| n.to_string())
+            .ok_or_else(|| anyhow!("expected an integer, found {value}")),
+        "double" => value
+            .as_f64()
+            .map(|f| f.to_string())
+            .ok_or_else(|| anyhow!("expected a number, found {value}")),
+        "boolean" => value
+            .as_bool()
+            .map(|b| b.to_string())
+            .ok_or_else(|| anyhow!("expected a boolean, found {value}")),
+        "character" | "string" => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| anyhow!("expected a string, found {value}"))?;
+            if s.contains('\n') || s.contains('\u{1f}') {
+                bail!("string contains characters the judge can't encode");
+            }
+            Ok(s.to_string())
+        }
+        _ => {
+            let inner = normalized
+                .strip_suffix("[]")
+                .ok_or_else(|| anyhow!("unsupported type '{leetcode_type}'"))?;
+            let items = value
+                .as_array()
+                .ok_or_else(|| anyhow!("expected an array, found {value}"))?;
+            let encoded: Vec<String> = items
+                .iter()
+                .map(|item| encode_value(item, inner))
+                .collect::<Result<_>>()?;
+            let separator = match normalized.as_str() {
+                "integer[][]" => ';',
+                "string[]" => '\u{1f}',
+                _ => ',',
+            };
+            Ok(encoded.join(&separator.to_string()))
+        }
+    }
+}
+
+/// Replace the unit separator with a comma for human-readable verdicts.
+fn display_encoded(encoded: &str) -> String {
+    encoded.replace('\u{1f}', ",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::Argument;
+
+    fn two_sum_config() -> TestConfig {
+        TestConfig {
+            namespace: "Solution".to_string(),
+            class_name: "Solution".to_string(),
+            method_name: "twoSum".to_string(),
+            return_type: "integer[]".to_string(),
+            args: vec![
+                Argument {
+                    arg_type: "integer[]".to_string(),
+                    name: "nums".to_string(),
+                },
+                Argument {
+                    arg_type: "integer".to_string(),
+                    name: "target".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_encode_value() {
+        let enc = |v: serde_json::Value, t: &str| encode_value(&v, t).unwrap();
+        assert_eq!(enc(serde_json::json!(42), "integer"), "42");
+        assert_eq!(enc(serde_json::json!(2.0), "double"), "2");
+        assert_eq!(enc(serde_json::json!(true), "boolean"), "true");
+        assert_eq!(enc(serde_json::json!("abc"), "string"), "abc");
+        assert_eq!(enc(serde_json::json!([1, 2, 3]), "integer[]"), "1,2,3");
+        assert_eq!(enc(serde_json::json!([]), "integer[]"), "");
+        assert_eq!(
+            enc(serde_json::json!([[1, 2], [3]]), "integer[][]"),
+            "1,2;3"
+        );
+        assert_eq!(
+            enc(serde_json::json!(["a,b", "c"]), "string[]"),
+            "a,b\u{1f}c"
+        );
+        assert_eq!(enc(serde_json::json!([1, 2]), "list<integer>"), "1,2");
+    }
+
+    #[test]
+    fn test_encode_value_rejects_mismatches() {
+        assert!(encode_value(&serde_json::json!("x"), "integer").is_err());
+        assert!(encode_value(&serde_json::json!("a\nb"), "string").is_err());
+        assert!(encode_value(&serde_json::json!(1), "TreeNode").is_err());
+    }
+
+    #[test]
+    fn test_cases_from() {
+        let tc = TestCase {
+            input: "[2,7,11,15]\n9".to_string(),
+            expected: "[0,1]".to_string(),
+            explanation: None,
+        };
+        let cases = cases_from(&[tc], &two_sum_config()).unwrap();
+        assert_eq!(
+            cases,
+            vec![JudgeCase {
+                input: "2,7,11,15\n9".to_string(),
+                expected: "0,1".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_cases_from_wrong_arity() {
+        let tc = TestCase {
+            input: "[2,7]".to_string(),
+            expected: "[0,1]".to_string(),
+            explanation: None,
+        };
+        let err = cases_from(&[tc], &two_sum_config()).unwrap_err();
+        assert!(err.to_string().contains("expected 2 argument(s), found 1"));
+    }
+
+    #[test]
+    fn test_cases_from_invalid_json() {
+        let tc = TestCase {
+            input: "2,7,11,15\n9".to_string(),
+            expected: "[0,1]".to_string(),
+            explanation: None,
+        };
+        let err = cases_from(&[tc], &two_sum_config()).unwrap_err();
+        assert!(err.to_string().contains("not valid JSON"));
+    }
+
+    #[test]
+    fn test_runner_source_shape() {
+        let source = runner_source("impl Solution {}", &two_sum_config()).unwrap();
+        assert!(source.contains("let arg0: Vec<i32>"));
+        assert!(source.contains("let arg1: i32"));
+        assert!(source.contains("Solution::two_sum(arg0, arg1)"));
+        assert!(source.contains("fn main()"));
+    }
+
+    #[test]
+    fn test_runner_source_unsupported_type() {
+        let mut cfg = two_sum_config();
+        cfg.args[0].arg_type = "TreeNode".to_string();
+        let err = runner_source("", &cfg).unwrap_err();
+        assert!(err.to_string().contains("unsupported argument type"));
+    }
+
+    #[test]
+    fn test_verdict_labels() {
+        assert_eq!(Verdict::Accepted { cases: 3 }.label(), "AC");
+        assert!(Verdict::Accepted { cases: 3 }.is_accepted());
+        let wa = Verdict::WrongAnswer {
+            case: 2,
+            expected: "1".to_string(),
+            actual: "2".to_string(),
+        };
+        assert_eq!(wa.label(), "WA");
+        assert!(!wa.is_accepted());
+        assert_eq!(wa.to_string(), "WA on case 2: expected 1, got 2");
+    }
+
+    #[test]
+    fn test_limits_for_difficulty() {
+        assert_eq!(Limits::for_difficulty(1).time, Duration::from_secs(2));
+        assert_eq!(Limits::for_difficulty(2).time, Duration::from_secs(4));
+        assert_eq!(Limits::for_difficulty(3).time, Duration::from_secs(8));
+    }
+
+    #[test]
+    fn test_limits_for_language_scaling() {
+        assert_eq!(Limits::for_language("rust", 1).time, Duration::from_secs(2));
+        assert_eq!(Limits::for_language("python", 1).time, Duration::from_secs(10));
+        assert_eq!(
+            Limits::for_language("typescript", 2).time,
+            Duration::from_secs(16)
+        );
+    }
+
+    #[test]
+    fn test_limits_for_problem_overrides() {
+        let limits = Limits::for_problem(2, Some(1500), Some(512));
+        assert_eq!(limits.time, Duration::from_millis(1500));
+        assert_eq!(limits.memory_kb, 512 * 1024);
+
+        let defaults = Limits::for_problem(2, None, None);
+        assert_eq!(defaults.time, Duration::from_secs(4));
+        assert_eq!(defaults.memory_kb, 256 * 1024);
+    }
+
+    fn add_one_config() -> TestConfig {
+        TestConfig {
+            namespace: "Solution".to_string(),
+            class_name: "Solution".to_string(),
+            method_name: "addOne".to_string(),
+            return_type: "integer".to_string(),
+            args: vec![Argument {
+                arg_type: "integer".to_string(),
+                name: "n".to_string(),
+            }],
+        }
+    }
+
+    fn write_solution(dir: &tempfile::TempDir, body: &str) -> std::path::PathBuf {
+        let path = dir.path().join("solution.rs");
+        std::fs::write(
+            &path,
+            format!("pub struct Solution;\nimpl Solution {{\n{body}\n}}\n"),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_judge_accepts_correct_solution() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let solution = write_solution(&dir, "pub fn add_one(n: i32) -> i32 { n + 1 }");
+        let cases = vec![
+            JudgeCase {
+                input: "1".to_string(),
+                expected: "2".to_string(),
+            },
+            JudgeCase {
+                input: "-5".to_string(),
+                expected: "-4".to_string(),
+            },
+        ];
+        let limits = Limits::for_difficulty(1);
+        let verdict = run(&solution, &add_one_config(), &cases, &limits).unwrap();
+        assert_eq!(verdict, Verdict::Accepted { cases: 2 });
+    }
+
+    #[test]
+    fn test_judge_flags_wrong_answer() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let solution = write_solution(&dir, "pub fn add_one(n: i32) -> i32 { n }");
+        let cases = vec![JudgeCase {
+            input: "1".to_string(),
+            expected: "2".to_string(),
+        }];
+        let limits = Limits::for_difficulty(1);
+        let verdict = run(&solution, &add_one_config(), &cases, &limits).unwrap();
+        assert_eq!(
+            verdict,
+            Verdict::WrongAnswer {
+                case: 1,
+                expected: "2".to_string(),
+                actual: "1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_judge_times_out_infinite_loop() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let solution = write_solution(&dir, "pub fn add_one(_n: i32) -> i32 { loop {} }");
+        let cases = vec![JudgeCase {
+            input: "1".to_string(),
+            expected: "2".to_string(),
+        }];
+        let limits = Limits {
+            time: Duration::from_millis(300),
+            memory_kb: 256 * 1024,
+        };
+        let verdict = run(&solution, &add_one_config(), &cases, &limits).unwrap();
+        assert!(matches!(
+            verdict,
+            Verdict::TimeLimitExceeded { case: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_judge_reports_compile_error() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let solution = write_solution(&dir, "pub fn add_one(n: i32) -> i32 { n +");
+        let cases = vec![JudgeCase {
+            input: "1".to_string(),
+            expected: "2".to_string(),
+        }];
+        let limits = Limits::for_difficulty(1);
+        let verdict = run(&solution, &add_one_config(), &cases, &limits).unwrap();
+        assert!(matches!(verdict, Verdict::CompileError { .. }));
+    }
+}
@@ -1,15 +1,52 @@
-//! LeetCode CLI Library
+//! Library behind the `leetcode-cli` binary.
 //!
-//! This library provides the core functionality for the LeetCode CLI tool.
+//! The binary is a thin wrapper over these modules, so other tooling
+//! (bots, stats sites, editor plugins) can drive the same client directly
+//! instead of shelling out to the CLI:
+//!
+//! - [`LeetCodeClient`] — authenticated HTTP/GraphQL client for problem
+//!   lists, problem details, and submissions
+//! - [`Problem`] / [`ProblemDetail`] — list entries and full problem data
+//!   as returned by the API
+//! - [`CodeTemplate`] — renders solution templates and test scaffolding
+//!   from a [`ProblemDetail`]
+//! - [`Config`] — credentials and workspace settings, persisted in the
+//!   platform config directory
+//!
+//! ```no_run
+//! use leetcode_cli::{Config, LeetCodeClient};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = LeetCodeClient::new(Config::load()?).await?;
+//! if let Some(problem) = client.get_problem_by_id(1).await? {
+//!     println!("{:?}", problem.stat.question__title);
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
 pub mod api;
 pub mod commands;
 pub mod config;
+pub mod error;
+pub mod judge;
+pub mod lists;
+pub mod mcp;
+pub mod meta;
 pub mod problem;
+pub mod progress;
+pub mod queue;
+pub mod session;
 pub mod solutions;
+pub mod table;
+pub mod tags;
 pub mod template;
+pub mod timings;
+pub mod workspace_index;
 
 // Re-export commonly used types
 pub use api::LeetCodeClient;
 pub use config::Config;
+pub use error::CliError;
 pub use problem::{Problem, ProblemDetail, ProblemList};
+pub use template::CodeTemplate;
@@ -0,0 +1,224 @@
+//! Named problem lists
+//!
+//! Stores curated sets of problem IDs (Blind 75, NeetCode 150, personal
+//! exclude-lists) in a `lists.json` file at the workspace root. Lists are
+//! managed with `leetcode-cli list-mgmt` and consumed by `pick --list` and
+//! `list --list`.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+const LISTS_FILE: &str = "lists.json";
+
+/// All named lists, keyed by list name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProblemLists {
+    #[serde(default)]
+    pub lists: BTreeMap<String, Vec<u32>>,
+}
+
+impl ProblemLists {
+    /// Load the lists from the current directory, or an empty set if the
+    /// file doesn't exist yet.
+    pub fn load() -> Result<Self> {
+        Self::load_from(Path::new(""))
+    }
+
+    /// Load the lists from the workspace rooted at `root`.
+    pub fn load_from(root: &Path) -> Result<Self> {
+        let path = Self::path(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the lists to the current directory.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(Path::new(""))
+    }
+
+    /// Save the lists to the workspace rooted at `root`.
+    pub fn save_to(&self, root: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(root), content)?;
+        Ok(())
+    }
+
+    fn path(root: &Path) -> PathBuf {
+        root.join(LISTS_FILE)
+    }
+
+    /// The IDs of a named list, or an error naming the available lists.
+    pub fn get(&self, name: &str) -> Result<&[u32]> {
+        self.lists.get(name).map(Vec::as_slice).ok_or_else(|| {
+            let available = if self.lists.is_empty() {
+                "none defined yet".to_string()
+            } else {
+                self.lists.keys().cloned().collect::<Vec<_>>().join(", ")
+            };
+            anyhow::anyhow!(
+                "no list named '{name}' (available: {available}); \
+                 create one with 'leetcode-cli list-mgmt create'"
+            )
+        })
+    }
+
+    /// Create or replace a named list. IDs are sorted and deduplicated.
+    pub fn set(&mut self, name: &str, mut ids: Vec<u32>) {
+        ids.sort_unstable();
+        ids.dedup();
+        self.lists.insert(name.to_string(), ids);
+    }
+
+    /// Remove a named list; returns whether it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.lists.remove(name).is_some()
+    }
+}
+
+/// Extract problem IDs from free-form text: one per line, comma-separated,
+/// or embedded in markdown — any run of digits counts.
+pub(crate) fn parse_ids(text: &str) -> Vec<u32> {
+    let mut ids = Vec::new();
+    let mut current = String::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_digit() {
+            current.push(c);
+        } else if !current.is_empty() {
+            if let Ok(id) = current.parse() {
+                ids.push(id);
+            }
+            current.clear();
+        }
+    }
+    ids
+}
+
+/// Parse a curated problem set in any of the common published formats:
+/// a JSON array of IDs, a JSON array of objects carrying an ID field
+/// (`frontendQuestionId`, `questionId`, `id`, ...), a JSON object wrapping
+/// such an array, or free-form text/markdown with numbers in it.
+pub(crate) fn parse_list_source(text: &str) -> Vec<u32> {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
+        let ids = json_ids(&value);
+        if !ids.is_empty() {
+            return ids;
+        }
+    }
+    parse_ids(text)
+}
+
+fn json_ids(value: &serde_json::Value) -> Vec<u32> {
+    match value {
+        serde_json::Value::Array(items) => items.iter().filter_map(item_id).collect(),
+        // A wrapper object: take the first value that yields IDs
+        serde_json::Value::Object(map) => map
+            .values()
+            .map(json_ids)
+            .find(|ids| !ids.is_empty())
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// The problem ID of one JSON array item: a bare number, a numeric string,
+/// or an object with a recognizable ID field.
+fn item_id(item: &serde_json::Value) -> Option<u32> {
+    match item {
+        serde_json::Value::Number(n) => n.as_u64().map(|n| n as u32),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        serde_json::Value::Object(map) => [
+            "frontendQuestionId",
+            "frontend_question_id",
+            "questionFrontendId",
+            "questionId",
+            "question_id",
+            "id",
+        ]
+        .iter()
+        .find_map(|key| item_id(map.get(*key)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_set_sorts_and_dedupes() {
+        let mut lists = ProblemLists::default();
+        lists.set("blind75", vec![217, 1, 217, 53]);
+        assert_eq!(lists.get("blind75").unwrap(), &[1, 53, 217]);
+    }
+
+    #[test]
+    fn test_get_unknown_names_available() {
+        let mut lists = ProblemLists::default();
+        lists.set("blind75", vec![1]);
+        let err = lists.get("neetcode150").unwrap_err().to_string();
+        assert!(err.contains("no list named 'neetcode150'"));
+        assert!(err.contains("blind75"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut lists = ProblemLists::default();
+        lists.set("blind75", vec![1]);
+        assert!(lists.remove("blind75"));
+        assert!(!lists.remove("blind75"));
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        assert_eq!(parse_ids("1, 53,217"), vec![1, 53, 217]);
+        assert_eq!(parse_ids("1\n53\n217\n"), vec![1, 53, 217]);
+        assert_eq!(parse_ids("- [ ] 1. Two Sum\n- [x] 53. Maximum Subarray"), vec![1, 53]);
+        assert!(parse_ids("no numbers here").is_empty());
+    }
+
+    #[test]
+    fn test_parse_list_source_json_numbers() {
+        assert_eq!(parse_list_source("[1, 53, 217]"), vec![1, 53, 217]);
+    }
+
+    #[test]
+    fn test_parse_list_source_json_objects() {
+        let json = r#"[
+            {"frontendQuestionId": "1", "title": "Two Sum"},
+            {"frontendQuestionId": "53", "title": "Maximum Subarray"}
+        ]"#;
+        assert_eq!(parse_list_source(json), vec![1, 53]);
+
+        let wrapped = r#"{"problems": [{"questionId": 217}, {"questionId": 238}]}"#;
+        assert_eq!(parse_list_source(wrapped), vec![217, 238]);
+    }
+
+    #[test]
+    fn test_parse_list_source_falls_back_to_text() {
+        assert_eq!(parse_list_source("1. Two Sum\n53. Max Subarray"), vec![1, 53]);
+    }
+
+    #[test]
+    fn test_load_save_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut lists = ProblemLists::load_from(temp_dir.path()).unwrap();
+        assert!(lists.lists.is_empty());
+
+        lists.set("blind75", vec![1, 53]);
+        lists.save_to(temp_dir.path()).unwrap();
+
+        let reloaded = ProblemLists::load_from(temp_dir.path()).unwrap();
+        assert_eq!(reloaded.get("blind75").unwrap(), &[1, 53]);
+    }
+}
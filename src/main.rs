@@ -1,18 +1,68 @@
-use std::path::PathBuf;
+use std::{io::IsTerminal, path::PathBuf};
 
-use anyhow::Result;
-use clap::{Parser, Subcommand};
-use leetcode_cli::{api::LeetCodeClient, commands, config::Config};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use leetcode_cli::{
+    api::{LeetCodeClient, ProblemFilter},
+    commands,
+    config::Config,
+};
 
 #[derive(Parser)]
 #[command(name = "leetcode-cli")]
 #[command(about = "A CLI tool for LeetCode practice")]
 #[command(version = "0.1.0")]
 struct Cli {
+    /// When to color output (also honors the NO_COLOR env var)
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+    /// Directory to run in (defaults to the configured workspace_path)
+    #[arg(long, global = true, value_name = "DIR")]
+    workspace: Option<PathBuf>,
+    /// Report where time went (network, cargo, polling) after the command
+    #[arg(long, global = true)]
+    timings: bool,
     #[command(subcommand)]
     command: Commands,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum ColorChoice {
+    /// Color only when stdout is a terminal and NO_COLOR is unset
+    Auto,
+    /// Always emit ANSI colors, even when piped
+    Always,
+    /// Never emit ANSI colors
+    Never,
+}
+
+/// Resolve a `--list` name to its problem IDs, if one was given.
+fn resolve_list_ids(list: Option<&str>) -> Result<Option<Vec<u32>>> {
+    match list {
+        Some(name) => Ok(Some(
+            leetcode_cli::lists::ProblemLists::load()?.get(name)?.to_vec(),
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Decide the `colored` override for a color choice: `Some(on/off)` forces
+/// it, `None` leaves the crate's own detection in place.
+fn color_override(choice: ColorChoice, no_color_set: bool, stdout_is_tty: bool) -> Option<bool> {
+    match choice {
+        ColorChoice::Always => Some(true),
+        ColorChoice::Never => Some(false),
+        ColorChoice::Auto if no_color_set || !stdout_is_tty => Some(false),
+        ColorChoice::Auto => None,
+    }
+}
+
+/// Pick the directory the CLI should run in: the `--workspace` flag wins
+/// over the configured `workspace_path`, `None` means stay where we are.
+fn workspace_dir(flag: Option<PathBuf>, configured: Option<PathBuf>) -> Option<PathBuf> {
+    flag.or(configured)
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Pick a random problem or specific problem by ID
@@ -26,11 +76,60 @@ enum Commands {
         /// Tag/Category filter
         #[arg(short, long)]
         tag: Option<String>,
+        /// Pick this many problems at once and queue them for practice
+        #[arg(short, long)]
+        count: Option<usize>,
+        /// Selection strategy (uniform, acceptance, frequency, weak-tags)
+        #[arg(short, long)]
+        strategy: Option<String>,
+        /// Filter by status (solved, attempting, unsolved)
+        #[arg(long)]
+        status: Option<String>,
+        /// Filter by paid-only status (default: free problems only)
+        #[arg(long)]
+        paid: Option<bool>,
+        /// Also consider premium problems (for premium accounts)
+        #[arg(long)]
+        include_paid: bool,
+        /// Only pick from this named list (see list-mgmt)
+        #[arg(long)]
+        list: Option<String>,
+        /// Only pick problems with an ID at or above this
+        #[arg(long)]
+        min_id: Option<u32>,
+        /// Only pick problems with an ID at or below this
+        #[arg(long)]
+        max_id: Option<u32>,
+        /// Template language (rust, typescript, javascript, python, cpp, go)
+        #[arg(short, long)]
+        lang: Option<String>,
+        /// Also generate a property-based test block (Rust, simple signatures)
+        #[arg(long)]
+        with_proptest: bool,
+    },
+    /// Show the practice queue filled by 'pick --count'
+    Queue {
+        /// Open the next remaining problem in the editor
+        #[arg(short, long)]
+        next: bool,
     },
     /// Run local tests
     Test {
         /// Problem ID
         id: u32,
+        /// Measure line coverage with cargo llvm-cov (Rust solutions only)
+        #[arg(long, conflicts_with_all = ["miri", "asan"])]
+        coverage: bool,
+        /// Run the tests under Miri to catch undefined behavior
+        #[arg(long, conflicts_with = "asan")]
+        miri: bool,
+        /// Run the tests with AddressSanitizer on nightly
+        #[arg(long)]
+        asan: bool,
+        /// Extra arguments forwarded to the test binary after `--`
+        /// (e.g. `-- test_case_2 --nocapture --test-threads=1`)
+        #[arg(last = true)]
+        args: Vec<String>,
     },
     /// Submit solution to LeetCode
     Submit {
@@ -39,6 +138,50 @@ enum Commands {
         /// Solution file path
         #[arg(short, long)]
         file: Option<PathBuf>,
+        /// Submit even if the solution uses crates the judge doesn't provide
+        #[arg(long)]
+        force: bool,
+        /// Print the submission ID and return without waiting for the judge
+        #[arg(long)]
+        no_wait: bool,
+        /// Run every example case through the local judge emulation first;
+        /// anything but AC aborts the submit
+        #[arg(long)]
+        all_tests_locally_first: bool,
+    },
+    /// Poll the result of an earlier submission (see 'submit --no-wait')
+    Check {
+        /// Submission ID printed by 'submit'
+        submission_id: i64,
+    },
+    /// Diff the current solution against an earlier attempt
+    Diff {
+        /// Problem ID
+        id: u32,
+        /// Baseline: 'last-accepted' (default) or a submission ID
+        #[arg(short, long)]
+        against: Option<String>,
+    },
+    /// Scaffold an alternate implementation next to the existing solution
+    Alt {
+        /// Problem ID
+        id: u32,
+        /// Name of the alternate (e.g. dp, brute_force)
+        #[arg(short, long)]
+        name: String,
+    },
+    /// Benchmark a solution (with --compare, against its alternates)
+    Bench {
+        /// Problem ID
+        id: u32,
+        /// Also benchmark every alternate implementation and compare
+        #[arg(short, long)]
+        compare: bool,
+    },
+    /// Estimate a solution's runtime complexity from timed runs
+    Complexity {
+        /// Problem ID
+        id: u32,
     },
     /// Login to LeetCode
     Login {
@@ -49,6 +192,8 @@ enum Commands {
         #[arg(short, long)]
         csrf: Option<String>,
     },
+    /// Remove stored credentials (the reverse of 'login')
+    Logout,
     /// List all problems
     List {
         /// Filter by difficulty
@@ -57,18 +202,343 @@ enum Commands {
         /// Filter by status (solved, attempting, unsolved)
         #[arg(short, long)]
         status: Option<String>,
+        /// Filter by paid-only status (true or false)
+        #[arg(long)]
+        paid: Option<bool>,
+        /// Only list problems with an ID at or above this
+        #[arg(long)]
+        min_id: Option<u32>,
+        /// Only list problems with an ID at or below this
+        #[arg(long)]
+        max_id: Option<u32>,
+        /// Columns to show (comma-separated: id,title,difficulty,acceptance,frequency,tags,paid,status)
+        #[arg(long)]
+        columns: Option<String>,
+        /// Only list problems in this named list (see list-mgmt)
+        #[arg(long)]
+        list: Option<String>,
+        /// Filter by topic tag (checks the first 50 matches)
+        #[arg(short, long)]
+        tag: Option<String>,
+        /// Pick problems from the results interactively and download them
+        #[arg(long)]
+        select: bool,
+        /// Show at most this many rows (fetches the list page by page)
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+    /// Sample the fastest accepted Rust solutions and diff them with mine
+    Fastest {
+        /// Problem ID
+        id: u32,
+    },
+    /// Retrieve submissions by ID
+    Submission {
+        #[command(subcommand)]
+        action: SubmissionAction,
+    },
+    /// Maintain named problem lists (Blind 75, NeetCode 150, ...)
+    ListMgmt {
+        #[command(subcommand)]
+        action: ListMgmtAction,
     },
     /// Show problem details
     Show {
         /// Problem ID
         id: u32,
     },
+    /// Show local and remote status for one problem
+    Info {
+        /// Problem ID
+        id: u32,
+    },
+    /// Like or dislike a problem
+    Rate {
+        /// Problem ID
+        id: u32,
+        /// Record a like
+        #[arg(long, conflicts_with = "dislike")]
+        like: bool,
+        /// Record a dislike
+        #[arg(long)]
+        dislike: bool,
+    },
+    /// Restore an interrupted solve, interview, or submit session
+    Resume,
+    /// Run a timed mock interview with hidden problems and a report
+    Interview {
+        /// Session length (e.g. 45m, 1h; default 45m)
+        #[arg(long)]
+        length: Option<String>,
+        /// One difficulty per round, comma-separated
+        #[arg(long, default_value = "medium,medium")]
+        difficulty: String,
+    },
+    /// Suggest the next problems to practice, weakest topics first
+    Recommend,
+    /// Render solve counts as an SVG badge or shields.io endpoint file
+    Badge {
+        /// Output file (default: stats.svg; a .json extension writes a
+        /// shields.io endpoint file)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Show goal progress and the current solve streak
+    Dashboard,
+    /// Remind about an unsolved daily challenge (one-shot, or daily with --at)
+    Remind {
+        /// Check daily at this time (HH:MM, UTC) instead of once
+        #[arg(long)]
+        at: Option<String>,
+    },
+    /// Show judge runtime/memory figures across accepted submissions
+    Perf {
+        /// Problem ID (omit for a workspace-wide summary)
+        id: Option<u32>,
+    },
+    /// Share a solution as a GitHub Gist or via the clipboard
+    Share {
+        /// Problem ID
+        id: u32,
+        /// Create a secret GitHub Gist and print its URL
+        #[arg(long)]
+        gist: bool,
+        /// Copy the cleaned solution code to the clipboard
+        #[arg(long)]
+        clipboard: bool,
+    },
+    /// List problems added to LeetCode since the last check
+    Whatsnew {
+        /// Download new free problems
+        #[arg(long)]
+        download: bool,
+        /// With --download, only grab problems carrying this topic tag
+        #[arg(short, long)]
+        tag: Option<String>,
+    },
+    /// Assemble a shareable Markdown write-up of a solved problem
+    Writeup {
+        /// Problem ID
+        id: u32,
+        /// Also post the write-up to LeetCode's solutions area
+        #[arg(long)]
+        post: bool,
+    },
+    /// Export local data (anki flashcards, or the progress history)
+    Export {
+        /// What to export: "anki" or "progress"
+        what: String,
+        /// Only export problems with this topic tag (anki only)
+        #[arg(short, long)]
+        tag: Option<String>,
+        /// Output file path (default: anki_deck.csv / progress.csv)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Data format for the progress export: csv or json
+        #[arg(short, long, default_value = "csv")]
+        format: String,
+    },
+    /// Export a solved problem as a standalone example crate
+    ExportExample {
+        /// Problem ID
+        id: u32,
+    },
+    /// Import an existing solutions directory into the workspace
+    Import {
+        /// Directory containing existing solution files
+        dir: PathBuf,
+    },
+    /// Generate a SOLUTIONS.md index of all downloaded problems
+    Index,
+    /// Search local solutions for a pattern
+    Grep {
+        /// Pattern to search for (case-insensitive substring)
+        pattern: String,
+        /// Also search markdown files in the notes/ directory
+        #[arg(short, long)]
+        notes: bool,
+    },
+    /// Timeboxed solve session: download, edit, countdown, then hints
+    Solve {
+        /// Problem ID
+        id: u32,
+        /// Timebox duration (e.g. 40m, 1h, 90s; default 40m)
+        #[arg(short, long)]
+        timebox: Option<String>,
+    },
+    /// Stash the current solution and re-attempt from a fresh template
+    Redo {
+        /// Problem ID
+        id: u32,
+        /// Timebox duration (e.g. 40m, 1h, 90s; default 40m)
+        #[arg(short, long)]
+        timebox: Option<String>,
+    },
+    /// Print the solution file path for a problem (for shell helpers)
+    Path {
+        /// Problem ID or slug (e.g. 1 or two-sum)
+        reference: String,
+        /// Download the problem first if it is not in the workspace
+        #[arg(short, long)]
+        create: bool,
+    },
+    /// Diagnose workspace issues (duplicates, stale metadata)
+    Doctor,
+    /// Backfill metadata for solutions downloaded before metadata existed
+    Migrate,
+    /// Move finished problems out of the active workspace into archive/
+    Archive {
+        /// Problem ID to archive
+        id: Option<u32>,
+        /// Archive every problem recorded as solved
+        #[arg(long)]
+        solved: bool,
+        /// With --solved, only archive problems finished longer ago (e.g. 60d)
+        #[arg(long)]
+        older_than: Option<String>,
+    },
+    /// Remove build artifacts and report space reclaimed
+    Clean {
+        /// Also remove generated files (SOLUTIONS.md, exported decks)
+        #[arg(short, long)]
+        all: bool,
+        /// Only remove artifacts older than this age (e.g. 90d, 12h)
+        #[arg(long)]
+        older_than: Option<String>,
+    },
+    /// Run the tests of every downloaded problem and print a summary
+    VerifyAll {
+        /// Number of test runs to execute in parallel
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Refresh problem metadata and statements without touching solutions
+    Update {
+        /// Problem ID (omit with --all)
+        id: Option<u32>,
+        /// Update every downloaded problem
+        #[arg(short, long)]
+        all: bool,
+    },
+    /// Send a raw GraphQL query through the authenticated client
+    Exec {
+        /// File containing the GraphQL query
+        #[arg(long)]
+        query: PathBuf,
+        /// Query variable as key=value (repeatable; JSON values pass through typed)
+        #[arg(long = "var")]
+        vars: Vec<String>,
+    },
+    /// Maintain the configuration file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Serve the Model Context Protocol over stdio for AI assistants
+    Mcp,
+    /// Serve core operations over JSON-RPC for editor integrations
+    Serve {
+        /// Speak JSON-RPC over stdin/stdout, one message per line
+        #[arg(long)]
+        stdio: bool,
+    },
+    /// Commit and push solutions to a git remote (or pull on another machine)
+    Sync {
+        /// Remote URL to configure as 'origin'
+        #[arg(short, long)]
+        remote: Option<String>,
+        /// Pull remote changes instead of pushing
+        #[arg(short, long)]
+        pull: bool,
+        /// Custom commit message
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SubmissionAction {
+    /// Fetch a submission's code and metadata
+    Get {
+        /// Submission ID
+        submission_id: i64,
+        /// Save the code here instead of printing it
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ListMgmtAction {
+    /// Create or replace a named list
+    Create {
+        /// List name, e.g. blind75
+        name: String,
+        /// Problem IDs (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        ids: Vec<u32>,
+        /// Import IDs from a file or URL (any text with numbers in it)
+        #[arg(long)]
+        from: Option<String>,
+    },
+    /// Import a curated set from a URL or file (JSON, markdown, plain text)
+    Import {
+        /// List name, e.g. neetcode150
+        name: String,
+        /// URL or file to import from
+        #[arg(long)]
+        from: String,
+    },
+    /// Show the names, sizes, and completion of all lists
+    List,
+    /// Show the IDs of one list
+    Show {
+        /// List name
+        name: String,
+    },
+    /// Delete a named list
+    Delete {
+        /// List name
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Upgrade the config file to the current schema version in place
+    Migrate,
+    /// Encrypt the stored credentials with a passphrase
+    Encrypt,
+    /// Decrypt the stored credentials back to plaintext
+    Decrypt,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+
+    if let Some(on) = color_override(
+        cli.color,
+        std::env::var_os("NO_COLOR").is_some(),
+        std::io::stdout().is_terminal(),
+    ) {
+        colored::control::set_override(on);
+    }
+
+    if cli.timings {
+        leetcode_cli::timings::enable();
+    }
+
     let config = Config::load()?;
+
+    // Solution files, metadata, and state files are all resolved relative to
+    // the current directory, so entering the workspace up front makes every
+    // command honor it no matter where the CLI was invoked from.
+    if let Some(dir) = workspace_dir(cli.workspace.clone(), config.workspace_path.clone()) {
+        std::env::set_current_dir(&dir)
+            .with_context(|| format!("cannot enter workspace {}", dir.display()))?;
+    }
+
     let client = LeetCodeClient::new(config).await?;
 
     match cli.command {
@@ -76,26 +546,256 @@ async fn main() -> Result<()> {
             id,
             difficulty,
             tag,
+            count,
+            strategy,
+            status,
+            paid,
+            include_paid,
+            list,
+            min_id,
+            max_id,
+            lang,
+            with_proptest,
         } => {
-            commands::pick::execute(&client, id, difficulty, tag).await?;
+            // Pick has always excluded paid problems unless asked otherwise
+            let paid = if include_paid { paid } else { paid.or(Some(false)) };
+            let list_ids = resolve_list_ids(list.as_deref())?;
+            let filter = ProblemFilter::new()
+                .difficulty(difficulty.as_deref())
+                .status(status.as_deref())
+                .paid(paid)
+                .id_range(min_id, max_id)
+                .id_set(list_ids.as_deref());
+            commands::pick::execute(
+                &client,
+                id,
+                filter,
+                tag,
+                count,
+                strategy,
+                lang,
+                with_proptest,
+            )
+            .await?;
+        }
+        Commands::Queue { next } => {
+            commands::queue::execute(&client, next).await?;
+        }
+        Commands::Test {
+            id,
+            coverage,
+            miri,
+            asan,
+            args,
+        } => {
+            commands::test::execute(id, coverage, miri, asan, args).await?;
         }
-        Commands::Test { id } => {
-            commands::test::execute(id).await?;
+        Commands::Submit {
+            id,
+            file,
+            force,
+            no_wait,
+            all_tests_locally_first,
+        } => {
+            commands::submit::execute(&client, id, file, force, no_wait, all_tests_locally_first)
+                .await?;
         }
-        Commands::Submit { id, file } => {
-            commands::submit::execute(&client, id, file).await?;
+        Commands::Check { submission_id } => {
+            commands::check::execute(&client, submission_id).await?;
+        }
+        Commands::Diff { id, against } => {
+            commands::diff::execute(&client, id, against).await?;
+        }
+        Commands::Alt { id, name } => {
+            commands::alt::execute(id, name).await?;
+        }
+        Commands::Bench { id, compare } => {
+            commands::bench::execute(id, compare).await?;
+        }
+        Commands::Complexity { id } => {
+            commands::complexity::execute(&client, id).await?;
         }
         Commands::Login { session, csrf } => {
             commands::login::execute(session, csrf).await?;
         }
-        Commands::List { difficulty, status } => {
-            commands::list::execute(&client, difficulty, status).await?;
+        Commands::Logout => {
+            commands::logout::execute().await?;
+        }
+        Commands::List {
+            difficulty,
+            status,
+            paid,
+            min_id,
+            max_id,
+            columns,
+            list,
+            tag,
+            select,
+            limit,
+        } => {
+            let list_ids = resolve_list_ids(list.as_deref())?;
+            let filter = ProblemFilter::new()
+                .difficulty(difficulty.as_deref())
+                .status(status.as_deref())
+                .paid(paid)
+                .id_range(min_id, max_id)
+                .id_set(list_ids.as_deref());
+            commands::list::execute(
+                &client,
+                &filter,
+                columns.as_deref(),
+                tag.as_deref(),
+                select,
+                limit,
+            )
+            .await?;
+        }
+        Commands::Fastest { id } => {
+            commands::fastest::execute(&client, id).await?;
         }
+        Commands::Submission { action } => match action {
+            SubmissionAction::Get {
+                submission_id,
+                output,
+            } => {
+                commands::submission::get(&client, submission_id, output).await?;
+            }
+        },
+        Commands::ListMgmt { action } => match action {
+            ListMgmtAction::Create { name, ids, from } => {
+                commands::list_mgmt::create(&name, &ids, from.as_deref()).await?;
+            }
+            ListMgmtAction::Import { name, from } => {
+                commands::list_mgmt::import(&name, &from).await?;
+            }
+            ListMgmtAction::List => {
+                commands::list_mgmt::list().await?;
+            }
+            ListMgmtAction::Show { name } => {
+                commands::list_mgmt::show(&name).await?;
+            }
+            ListMgmtAction::Delete { name } => {
+                commands::list_mgmt::delete(&name).await?;
+            }
+        },
         Commands::Show { id } => {
             commands::show::execute(&client, id).await?;
         }
+        Commands::Info { id } => {
+            commands::info::execute(&client, id).await?;
+        }
+        Commands::Rate { id, like, dislike } => {
+            commands::rate::execute(&client, id, like, dislike).await?;
+        }
+        Commands::Resume => {
+            commands::resume::execute(&client).await?;
+        }
+        Commands::Interview { length, difficulty } => {
+            commands::interview::execute(&client, length, difficulty).await?;
+        }
+        Commands::Recommend => {
+            commands::recommend::execute(&client).await?;
+        }
+        Commands::Badge { output } => {
+            commands::badge::execute(output).await?;
+        }
+        Commands::Dashboard => {
+            commands::dashboard::execute().await?;
+        }
+        Commands::Remind { at } => {
+            commands::remind::execute(&client, at).await?;
+        }
+        Commands::Perf { id } => {
+            commands::perf::execute(id).await?;
+        }
+        Commands::Writeup { id, post } => {
+            commands::writeup::execute(&client, id, post).await?;
+        }
+        Commands::Share {
+            id,
+            gist,
+            clipboard,
+        } => {
+            commands::share::execute(id, gist, clipboard).await?;
+        }
+        Commands::Whatsnew { download, tag } => {
+            commands::whatsnew::execute(&client, download, tag).await?;
+        }
+        Commands::Export {
+            what,
+            tag,
+            output,
+            format,
+        } => {
+            commands::export::execute(&client, &what, tag, output, &format).await?;
+        }
+        Commands::ExportExample { id } => {
+            commands::export_example::execute(id).await?;
+        }
+        Commands::Import { dir } => {
+            commands::import::execute(&client, dir).await?;
+        }
+        Commands::Index => {
+            commands::index::execute(&client).await?;
+        }
+        Commands::Grep { pattern, notes } => {
+            commands::grep::execute(pattern, notes).await?;
+        }
+        Commands::Solve { id, timebox } => {
+            commands::solve::execute(&client, id, timebox).await?;
+        }
+        Commands::Redo { id, timebox } => {
+            commands::redo::execute(&client, id, timebox).await?;
+        }
+        Commands::Path { reference, create } => {
+            commands::path::execute(&client, reference, create).await?;
+        }
+        Commands::Doctor => {
+            commands::doctor::execute().await?;
+        }
+        Commands::Migrate => {
+            commands::migrate::execute(&client).await?;
+        }
+        Commands::Archive {
+            id,
+            solved,
+            older_than,
+        } => {
+            commands::archive::execute(id, solved, older_than).await?;
+        }
+        Commands::Clean { all, older_than } => {
+            commands::clean::execute(all, older_than).await?;
+        }
+        Commands::VerifyAll { jobs } => {
+            commands::verify::execute(jobs).await?;
+        }
+        Commands::Update { id, all } => {
+            commands::update::execute(&client, id, all).await?;
+        }
+        Commands::Exec { query, vars } => {
+            commands::exec::execute(&client, &query, &vars).await?;
+        }
+        Commands::Config { action } => match action {
+            ConfigAction::Migrate => commands::config::migrate().await?,
+            ConfigAction::Encrypt => commands::config::encrypt().await?,
+            ConfigAction::Decrypt => commands::config::decrypt().await?,
+        },
+        Commands::Mcp => {
+            leetcode_cli::mcp::serve(&client).await?;
+        }
+        Commands::Serve { stdio } => {
+            commands::serve::execute(&client, stdio).await?;
+        }
+        Commands::Sync {
+            remote,
+            pull,
+            message,
+        } => {
+            commands::sync::execute(remote, pull, message).await?;
+        }
     }
 
+    leetcode_cli::timings::report();
     Ok(())
 }
 
@@ -118,14 +818,36 @@ mod tests {
             id: Some(1),
             difficulty: Some("easy".to_string()),
             tag: Some("array".to_string()),
+            count: None,
+            strategy: None,
+            status: None,
+            paid: None,
+            include_paid: false,
+            list: None,
+            min_id: None,
+            max_id: None,
+            lang: None,
+            with_proptest: false,
         };
         // Just ensure it compiles and runs
         drop(pick);
 
-        let test = Commands::Test { id: 1 };
+        let test = Commands::Test {
+            id: 1,
+            coverage: false,
+            miri: false,
+            asan: false,
+            args: Vec::new(),
+        };
         drop(test);
 
-        let submit = Commands::Submit { id: 1, file: None };
+        let submit = Commands::Submit {
+            id: 1,
+            file: None,
+            force: false,
+            no_wait: false,
+            all_tests_locally_first: false,
+        };
         drop(submit);
 
         let login = Commands::Login {
@@ -137,6 +859,14 @@ mod tests {
         let list = Commands::List {
             difficulty: None,
             status: None,
+            paid: None,
+            min_id: None,
+            max_id: None,
+            columns: None,
+            list: None,
+            tag: None,
+            select: false,
+            limit: None,
         };
         drop(list);
 
@@ -151,16 +881,31 @@ mod tests {
             id: Some(42),
             difficulty: Some("hard".to_string()),
             tag: Some("dynamic-programming".to_string()),
+            count: None,
+            strategy: None,
+            status: None,
+            paid: None,
+            include_paid: false,
+            list: None,
+            min_id: None,
+            max_id: None,
+            lang: None,
+            with_proptest: false,
         };
         match pick_full {
             Commands::Pick {
                 id,
                 difficulty,
                 tag,
+                count,
+                strategy,
+                ..
             } => {
                 assert_eq!(id, Some(42));
                 assert_eq!(difficulty, Some("hard".to_string()));
                 assert_eq!(tag, Some("dynamic-programming".to_string()));
+                assert!(count.is_none());
+                assert!(strategy.is_none());
             }
             _ => panic!("Expected Pick command"),
         }
@@ -170,16 +915,33 @@ mod tests {
             id: None,
             difficulty: None,
             tag: None,
+            count: Some(3),
+            strategy: Some("acceptance".to_string()),
+            status: None,
+            paid: None,
+            include_paid: false,
+            list: None,
+            min_id: None,
+            max_id: None,
+            lang: Some("typescript".to_string()),
+            with_proptest: false,
         };
         match pick_random {
             Commands::Pick {
                 id,
                 difficulty,
                 tag,
+                count,
+                strategy,
+                lang,
+                ..
             } => {
                 assert!(id.is_none());
                 assert!(difficulty.is_none());
                 assert!(tag.is_none());
+                assert_eq!(count, Some(3));
+                assert_eq!(strategy, Some("acceptance".to_string()));
+                assert_eq!(lang, Some("typescript".to_string()));
             }
             _ => panic!("Expected Pick command"),
         }
@@ -187,9 +949,15 @@ mod tests {
 
     #[test]
     fn test_test_command() {
-        let test = Commands::Test { id: 123 };
+        let test = Commands::Test {
+            id: 123,
+            coverage: false,
+            miri: false,
+            asan: false,
+            args: Vec::new(),
+        };
         match test {
-            Commands::Test { id } => assert_eq!(id, 123),
+            Commands::Test { id, .. } => assert_eq!(id, 123),
             _ => panic!("Expected Test command"),
         }
     }
@@ -200,9 +968,12 @@ mod tests {
         let submit_with_file = Commands::Submit {
             id: 1,
             file: Some(PathBuf::from("src/solutions/p0001_two_sum.rs")),
+            force: false,
+            no_wait: false,
+            all_tests_locally_first: false,
         };
         match submit_with_file {
-            Commands::Submit { id, file } => {
+            Commands::Submit { id, file, .. } => {
                 assert_eq!(id, 1);
                 assert_eq!(file, Some(PathBuf::from("src/solutions/p0001_two_sum.rs")));
             }
@@ -210,9 +981,15 @@ mod tests {
         }
 
         // Test submit without file path
-        let submit_without_file = Commands::Submit { id: 2, file: None };
+        let submit_without_file = Commands::Submit {
+            id: 2,
+            file: None,
+            force: false,
+            no_wait: false,
+            all_tests_locally_first: false,
+        };
         match submit_without_file {
-            Commands::Submit { id, file } => {
+            Commands::Submit { id, file, .. } => {
                 assert_eq!(id, 2);
                 assert!(file.is_none());
             }
@@ -255,11 +1032,33 @@ mod tests {
         let list_filtered = Commands::List {
             difficulty: Some("medium".to_string()),
             status: Some("solved".to_string()),
+            paid: Some(false),
+            min_id: Some(1),
+            max_id: Some(500),
+            columns: None,
+            list: None,
+            tag: None,
+            select: false,
+            limit: None,
         };
         match list_filtered {
-            Commands::List { difficulty, status } => {
+            Commands::List {
+                difficulty,
+                status,
+                paid,
+                min_id,
+                max_id,
+                columns: None,
+                list: None,
+                tag: None,
+                select: false,
+                limit: None,
+            } => {
                 assert_eq!(difficulty, Some("medium".to_string()));
                 assert_eq!(status, Some("solved".to_string()));
+                assert_eq!(paid, Some(false));
+                assert_eq!(min_id, Some(1));
+                assert_eq!(max_id, Some(500));
             }
             _ => panic!("Expected List command"),
         }
@@ -268,9 +1067,19 @@ mod tests {
         let list_all = Commands::List {
             difficulty: None,
             status: None,
+            paid: None,
+            min_id: None,
+            max_id: None,
+            columns: None,
+            list: None,
+            tag: None,
+            select: false,
+            limit: None,
         };
         match list_all {
-            Commands::List { difficulty, status } => {
+            Commands::List {
+                difficulty, status, ..
+            } => {
                 assert!(difficulty.is_none());
                 assert!(status.is_none());
             }
@@ -286,4 +1095,117 @@ mod tests {
             _ => panic!("Expected Show command"),
         }
     }
+
+    #[test]
+    fn test_export_command_variants() {
+        let export = Commands::Export {
+            what: "anki".to_string(),
+            tag: Some("dynamic-programming".to_string()),
+            output: None,
+            format: "csv".to_string(),
+        };
+        match export {
+            Commands::Export {
+                what,
+                tag,
+                output,
+                format,
+            } => {
+                assert_eq!(what, "anki");
+                assert_eq!(tag, Some("dynamic-programming".to_string()));
+                assert!(output.is_none());
+                assert_eq!(format, "csv");
+            }
+            _ => panic!("Expected Export command"),
+        }
+    }
+
+    #[test]
+    fn test_path_command_variants() {
+        let path = Commands::Path {
+            reference: "two-sum".to_string(),
+            create: true,
+        };
+        match path {
+            Commands::Path { reference, create } => {
+                assert_eq!(reference, "two-sum");
+                assert!(create);
+            }
+            _ => panic!("Expected Path command"),
+        }
+    }
+
+    #[test]
+    fn test_grep_command_variants() {
+        let grep = Commands::Grep {
+            pattern: "binary search".to_string(),
+            notes: true,
+        };
+        match grep {
+            Commands::Grep { pattern, notes } => {
+                assert_eq!(pattern, "binary search");
+                assert!(notes);
+            }
+            _ => panic!("Expected Grep command"),
+        }
+    }
+
+    #[test]
+    fn test_solve_command_variants() {
+        // Test solve with a timebox
+        let solve_timeboxed = Commands::Solve {
+            id: 1,
+            timebox: Some("25m".to_string()),
+        };
+        match solve_timeboxed {
+            Commands::Solve { id, timebox } => {
+                assert_eq!(id, 1);
+                assert_eq!(timebox, Some("25m".to_string()));
+            }
+            _ => panic!("Expected Solve command"),
+        }
+
+        // Test solve with the default timebox
+        let solve_default = Commands::Solve {
+            id: 2,
+            timebox: None,
+        };
+        match solve_default {
+            Commands::Solve { id, timebox } => {
+                assert_eq!(id, 2);
+                assert!(timebox.is_none());
+            }
+            _ => panic!("Expected Solve command"),
+        }
+    }
+
+    #[test]
+    fn test_workspace_dir_flag_wins() {
+        // The flag overrides the configured path; with neither we stay put
+        assert_eq!(
+            workspace_dir(Some(PathBuf::from("/a")), Some(PathBuf::from("/b"))),
+            Some(PathBuf::from("/a"))
+        );
+        assert_eq!(
+            workspace_dir(None, Some(PathBuf::from("/b"))),
+            Some(PathBuf::from("/b"))
+        );
+        assert_eq!(workspace_dir(None, None), None);
+    }
+
+    #[test]
+    fn test_color_override_always_and_never() {
+        // Explicit choices win regardless of env or tty
+        assert_eq!(color_override(ColorChoice::Always, true, false), Some(true));
+        assert_eq!(color_override(ColorChoice::Never, false, true), Some(false));
+    }
+
+    #[test]
+    fn test_color_override_auto() {
+        // Auto disables color for NO_COLOR or piped stdout, otherwise
+        // defers to the colored crate's own detection
+        assert_eq!(color_override(ColorChoice::Auto, true, true), Some(false));
+        assert_eq!(color_override(ColorChoice::Auto, false, false), Some(false));
+        assert_eq!(color_override(ColorChoice::Auto, false, true), None);
+    }
 }
@@ -0,0 +1,184 @@
+//! Model Context Protocol (MCP) server
+//!
+//! Exposes a controlled tool surface — `get_problem`, `run_tests`, and
+//! `submit_solution` — over MCP's stdio JSON-RPC transport, so coding
+//! assistants can fetch statements and check solutions without free-form
+//! shell access. The tools map onto the same method implementations that
+//! back `serve --stdio`.
+
+use anyhow::Result;
+use serde_json::{Value, json};
+
+use crate::api::LeetCodeClient;
+
+/// The MCP protocol revision this server speaks.
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Serve MCP requests over stdin/stdout until EOF
+pub async fn serve(client: &LeetCodeClient) -> Result<()> {
+    let stdin = std::io::stdin();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if std::io::BufRead::read_line(&mut stdin.lock(), &mut line)? == 0 {
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_message(client, &line).await {
+            println!("{response}");
+        }
+    }
+}
+
+/// Handle one message; notifications (no `id`) produce no response.
+async fn handle_message(client: &LeetCodeClient, line: &str) -> Option<Value> {
+    let message: Value = match serde_json::from_str(line) {
+        Ok(message) => message,
+        Err(e) => {
+            return Some(error_response(
+                Value::Null,
+                -32700,
+                &format!("invalid JSON: {e}"),
+            ));
+        }
+    };
+    let id = message.get("id").cloned()?;
+    let method = message.get("method").and_then(Value::as_str).unwrap_or("");
+    let params = message.get("params").cloned().unwrap_or_else(|| json!({}));
+
+    let result = match method {
+        "initialize" => Ok(initialize_result()),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({"tools": tool_definitions()})),
+        "tools/call" => tools_call(client, &params).await,
+        _ => Err((METHOD_NOT_FOUND, format!("unknown method '{method}'"))),
+    };
+    Some(match result {
+        Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+        Err((code, message)) => error_response(id, code, &message),
+    })
+}
+
+/// The `initialize` handshake result.
+fn initialize_result() -> Value {
+    json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "capabilities": {"tools": {}},
+        "serverInfo": {
+            "name": "leetcode-cli",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    })
+}
+
+/// The tool definitions advertised by `tools/list`.
+fn tool_definitions() -> Vec<Value> {
+    let id_schema = |description: &str| {
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "description": description},
+            },
+            "required": ["id"],
+        })
+    };
+    vec![
+        json!({
+            "name": "get_problem",
+            "description": "Fetch a problem's statement, hints, and topic tags by its \
+                            frontend ID",
+            "inputSchema": id_schema("Frontend problem ID, e.g. 1 for Two Sum"),
+        }),
+        json!({
+            "name": "run_tests",
+            "description": "Run the local tests of a downloaded problem and return the \
+                            pass/fail verdict with output",
+            "inputSchema": id_schema("Frontend ID of a downloaded problem"),
+        }),
+        json!({
+            "name": "submit_solution",
+            "description": "Submit the local solution to LeetCode and return the judge's \
+                            verdict",
+            "inputSchema": id_schema("Frontend ID of a downloaded problem"),
+        }),
+    ]
+}
+
+/// Run one `tools/call` request, wrapping the method result as text
+/// content per the MCP tool-result shape.
+async fn tools_call(client: &LeetCodeClient, params: &Value) -> Result<Value, (i64, String)> {
+    let name = params.get("name").and_then(Value::as_str).unwrap_or("");
+    let arguments = params
+        .get("arguments")
+        .cloned()
+        .unwrap_or_else(|| json!({}));
+    let method = match name {
+        "get_problem" => "show",
+        "run_tests" => "test",
+        "submit_solution" => "submit",
+        _ => return Err((INVALID_PARAMS, format!("unknown tool '{name}'"))),
+    };
+    Ok(
+        match crate::commands::serve::dispatch(client, method, &arguments).await {
+            Ok(result) => json!({
+                "content": [{
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result).unwrap_or_default(),
+                }],
+            }),
+            // Tool failures are reported in-band so the assistant can react
+            Err((_, message)) => json!({
+                "content": [{"type": "text", "text": message}],
+                "isError": true,
+            }),
+        },
+    )
+}
+
+/// Build a JSON-RPC error response.
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message},
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_result_shape() {
+        let result = initialize_result();
+        assert_eq!(result["protocolVersion"], json!(PROTOCOL_VERSION));
+        assert_eq!(result["serverInfo"]["name"], json!("leetcode-cli"));
+        assert!(result["capabilities"]["tools"].is_object());
+    }
+
+    #[test]
+    fn test_tool_definitions() {
+        let tools = tool_definitions();
+        let names: Vec<&str> = tools
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["get_problem", "run_tests", "submit_solution"]);
+        for tool in &tools {
+            assert_eq!(tool["inputSchema"]["required"], json!(["id"]));
+            assert!(tool["description"].is_string());
+        }
+    }
+
+    #[test]
+    fn test_error_response_shape() {
+        let response = error_response(json!(1), METHOD_NOT_FOUND, "unknown method 'resources/list'");
+        assert_eq!(response["jsonrpc"], json!("2.0"));
+        assert_eq!(response["error"]["code"], json!(METHOD_NOT_FOUND));
+    }
+}
@@ -0,0 +1,379 @@
+//! Per-problem workspace metadata
+//!
+//! Each downloaded problem gets a TOML metadata file under
+//! `src/solutions/meta/` recording its identity (IDs, slug, title),
+//! difficulty, tags, language, and download timestamp. Commands read this
+//! instead of relying on fragile file-name prefix matching.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+const META_DIR: &str = "src/solutions/meta";
+
+/// Metadata describing a downloaded problem.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProblemMeta {
+    /// Internal LeetCode question ID (used by the submit API)
+    pub id: u32,
+    /// Frontend question ID (the number shown on leetcode.com)
+    pub frontend_id: u32,
+    pub slug: String,
+    pub title: String,
+    pub difficulty: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Unix timestamp of when the problem was downloaded
+    pub downloaded_at: u64,
+    pub language: String,
+    /// Solution module name, if downloaded with a custom file template
+    #[serde(default)]
+    pub module: Option<String>,
+    /// Local judge time limit in milliseconds, seeded from the judge
+    /// defaults for the language at download time; edit to tighten
+    #[serde(default)]
+    pub time_limit_ms: Option<u64>,
+    /// Local judge memory limit in megabytes
+    #[serde(default)]
+    pub memory_limit_mb: Option<u64>,
+}
+
+impl ProblemMeta {
+    /// Path of the metadata file for a given frontend problem ID.
+    pub fn path(frontend_id: u32) -> PathBuf {
+        Self::path_in(Path::new(""), frontend_id)
+    }
+
+    /// Path of the metadata file under the workspace rooted at `root`.
+    pub fn path_in(root: &Path, frontend_id: u32) -> PathBuf {
+        root.join(META_DIR).join(format!("p{frontend_id:04}.toml"))
+    }
+
+    /// Load the metadata for a problem, or `None` if it was downloaded
+    /// before metadata files existed.
+    pub fn load(frontend_id: u32) -> Result<Option<Self>> {
+        Self::load_from(Path::new(""), frontend_id)
+    }
+
+    /// Load the metadata for a problem from the workspace rooted at `root`.
+    pub fn load_from(root: &Path, frontend_id: u32) -> Result<Option<Self>> {
+        let path = Self::path_in(root, frontend_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(Some(toml::from_str(&content)?))
+    }
+
+    /// Save the metadata file, creating the meta directory if needed.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(Path::new(""))
+    }
+
+    /// Save the metadata file under the workspace rooted at `root`.
+    pub fn save_to(&self, root: &Path) -> Result<()> {
+        std::fs::create_dir_all(root.join(META_DIR))?;
+        let content = toml::to_string_pretty(self)?;
+        std::fs::write(Self::path_in(root, self.frontend_id), content)?;
+        Ok(())
+    }
+
+    /// The module name of the solution file, e.g. `p0001_two_sum`.
+    pub fn module_name(&self) -> String {
+        self.module.clone().unwrap_or_else(|| {
+            format!("p{:04}_{}", self.frontend_id, self.slug.replace('-', "_"))
+        })
+    }
+
+    /// The path of the solution file, e.g. `src/solutions/p0001_two_sum.rs`
+    /// for Rust problems, `sql/p0595_big_countries/solution.sql` for
+    /// database problems, or `shell/p0195_tenth_line/solution.sh` for shell
+    /// problems.
+    pub fn solution_path(&self) -> PathBuf {
+        match self.language.as_str() {
+            "sql" => PathBuf::from("sql")
+                .join(self.module_name())
+                .join("solution.sql"),
+            "bash" => PathBuf::from("shell")
+                .join(self.module_name())
+                .join("solution.sh"),
+            "typescript" => PathBuf::from("ts")
+                .join(self.module_name())
+                .join("solution.ts"),
+            "javascript" => PathBuf::from("js")
+                .join(self.module_name())
+                .join("solution.js"),
+            "python" => PathBuf::from("py")
+                .join(self.module_name())
+                .join("solution.py"),
+            "cpp" => PathBuf::from("cpp")
+                .join(self.module_name())
+                .join("solution.cpp"),
+            "go" => PathBuf::from("go")
+                .join(self.module_name())
+                .join("solution.go"),
+            _ => PathBuf::from("src/solutions").join(format!("{}.rs", self.module_name())),
+        }
+    }
+
+    /// Load the metadata of every downloaded problem, sorted by frontend ID.
+    pub fn load_all() -> Result<Vec<Self>> {
+        Self::load_all_from(Path::new(""))
+    }
+
+    /// Load the metadata of every downloaded problem in the workspace
+    /// rooted at `root`, sorted by frontend ID.
+    pub fn load_all_from(root: &Path) -> Result<Vec<Self>> {
+        let dir = root.join(META_DIR);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut metas = Vec::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|e| e != "toml") {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)?;
+            metas.push(toml::from_str(&content)?);
+        }
+        metas.sort_by_key(|m: &Self| m.frontend_id);
+        Ok(metas)
+    }
+
+    /// Path of the statement snapshot for a given frontend problem ID.
+    ///
+    /// The snapshot holds the problem description as downloaded, so later
+    /// fetches can detect statement changes.
+    pub fn description_path(frontend_id: u32) -> PathBuf {
+        Self::description_path_in(Path::new(""), frontend_id)
+    }
+
+    /// Path of the statement snapshot under the workspace rooted at `root`.
+    pub fn description_path_in(root: &Path, frontend_id: u32) -> PathBuf {
+        root.join(META_DIR).join(format!("p{frontend_id:04}.md"))
+    }
+
+    /// Load the statement snapshot for a problem, if one was saved.
+    pub fn load_description(frontend_id: u32) -> Result<Option<String>> {
+        Self::load_description_from(Path::new(""), frontend_id)
+    }
+
+    /// Load the statement snapshot from the workspace rooted at `root`.
+    pub fn load_description_from(root: &Path, frontend_id: u32) -> Result<Option<String>> {
+        let path = Self::description_path_in(root, frontend_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(std::fs::read_to_string(&path)?))
+    }
+
+    /// Save the statement snapshot for a problem.
+    pub fn save_description(frontend_id: u32, description: &str) -> Result<()> {
+        Self::save_description_to(Path::new(""), frontend_id, description)
+    }
+
+    /// Save the statement snapshot under the workspace rooted at `root`.
+    pub fn save_description_to(root: &Path, frontend_id: u32, description: &str) -> Result<()> {
+        std::fs::create_dir_all(root.join(META_DIR))?;
+        std::fs::write(Self::description_path_in(root, frontend_id), description)?;
+        Ok(())
+    }
+
+    /// Current time as a unix timestamp, for `downloaded_at`.
+    pub fn now() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn make_meta() -> ProblemMeta {
+        ProblemMeta {
+            id: 1,
+            frontend_id: 1,
+            slug: "two-sum".to_string(),
+            title: "Two Sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: vec!["Array".to_string(), "Hash Table".to_string()],
+            downloaded_at: 1700000000,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_module_name_prefers_stored_module() {
+        let meta = ProblemMeta {
+            module: Some("easy_1_two_sum".to_string()),
+            ..make_meta()
+        };
+        assert_eq!(meta.module_name(), "easy_1_two_sum");
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("src/solutions/easy_1_two_sum.rs")
+        );
+    }
+
+    #[test]
+    fn test_module_name_and_solution_path() {
+        let meta = make_meta();
+        assert_eq!(meta.module_name(), "p0001_two_sum");
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("src/solutions/p0001_two_sum.rs")
+        );
+    }
+
+    #[test]
+    fn test_solution_path_sql_language() {
+        let meta = ProblemMeta {
+            language: "sql".to_string(),
+            ..make_meta()
+        };
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("sql/p0001_two_sum/solution.sql")
+        );
+    }
+
+    #[test]
+    fn test_solution_path_typescript_language() {
+        let meta = ProblemMeta {
+            language: "typescript".to_string(),
+            ..make_meta()
+        };
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("ts/p0001_two_sum/solution.ts")
+        );
+
+        let meta = ProblemMeta {
+            language: "javascript".to_string(),
+            ..make_meta()
+        };
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("js/p0001_two_sum/solution.js")
+        );
+    }
+
+    #[test]
+    fn test_solution_path_python_language() {
+        let meta = ProblemMeta {
+            language: "python".to_string(),
+            ..make_meta()
+        };
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("py/p0001_two_sum/solution.py")
+        );
+    }
+
+    #[test]
+    fn test_solution_path_bash_language() {
+        let meta = ProblemMeta {
+            language: "bash".to_string(),
+            ..make_meta()
+        };
+        assert_eq!(
+            meta.solution_path(),
+            PathBuf::from("shell/p0001_two_sum/solution.sh")
+        );
+    }
+
+    #[test]
+    fn test_meta_path() {
+        assert_eq!(
+            ProblemMeta::path(42),
+            PathBuf::from("src/solutions/meta/p0042.toml")
+        );
+    }
+
+    #[test]
+    fn test_save_load_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let meta = make_meta();
+        meta.save_to(temp_dir.path()).unwrap();
+
+        let loaded = ProblemMeta::load_from(temp_dir.path(), 1).unwrap().unwrap();
+        assert_eq!(loaded.slug, "two-sum");
+        assert_eq!(loaded.title, "Two Sum");
+        assert_eq!(loaded.tags.len(), 2);
+        assert_eq!(loaded.downloaded_at, 1700000000);
+    }
+
+    #[test]
+    fn test_limit_overrides_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        ProblemMeta {
+            time_limit_ms: Some(1500),
+            memory_limit_mb: Some(512),
+            ..make_meta()
+        }
+        .save_to(temp_dir.path())
+        .unwrap();
+
+        let loaded = ProblemMeta::load_from(temp_dir.path(), 1).unwrap().unwrap();
+        assert_eq!(loaded.time_limit_ms, Some(1500));
+        assert_eq!(loaded.memory_limit_mb, Some(512));
+    }
+
+    #[test]
+    fn test_load_meta_without_limit_fields() {
+        // Metadata written before limits existed must still parse
+        let meta: ProblemMeta = toml::from_str(
+            "id = 1\nfrontend_id = 1\nslug = \"two-sum\"\ntitle = \"Two Sum\"\n\
+             difficulty = \"Easy\"\ndownloaded_at = 0\nlanguage = \"rust\"\n",
+        )
+        .unwrap();
+        assert_eq!(meta.time_limit_ms, None);
+        assert_eq!(meta.memory_limit_mb, None);
+    }
+
+    #[test]
+    fn test_load_all_sorted() {
+        let temp_dir = TempDir::new().unwrap();
+
+        ProblemMeta {
+            id: 42,
+            frontend_id: 42,
+            slug: "trapping-rain-water".to_string(),
+            ..make_meta()
+        }
+        .save_to(temp_dir.path())
+        .unwrap();
+        make_meta().save_to(temp_dir.path()).unwrap();
+
+        let metas = ProblemMeta::load_all_from(temp_dir.path()).unwrap();
+        assert_eq!(metas.len(), 2);
+        assert_eq!(metas[0].frontend_id, 1);
+        assert_eq!(metas[1].frontend_id, 42);
+    }
+
+    #[test]
+    fn test_load_all_empty_without_meta_dir() {
+        let temp_dir = TempDir::new().unwrap();
+
+        assert!(ProblemMeta::load_all_from(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_missing_returns_none() {
+        let temp_dir = TempDir::new().unwrap();
+
+        assert!(ProblemMeta::load_from(temp_dir.path(), 999).unwrap().is_none());
+    }
+}
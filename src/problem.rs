@@ -1,15 +1,27 @@
 use scraper::{ElementRef, Html, Node};
 use serde::{Deserialize, Serialize};
 
+// LeetCode adds, renames, and nulls fields without notice, so everything
+// short of a problem's identity falls back to its default instead of
+// failing the whole response.
 #[derive(Debug, Clone, Deserialize)]
 #[allow(dead_code)]
 pub struct ProblemList {
+    #[serde(default)]
     pub user_name: String,
+    #[serde(default)]
     pub num_solved: i32,
+    #[serde(default)]
     pub num_total: i32,
+    #[serde(default)]
     pub ac_easy: i32,
+    #[serde(default)]
     pub ac_medium: i32,
+    #[serde(default)]
     pub ac_hard: i32,
+    /// Whether the account has a premium subscription (absent when logged out)
+    #[serde(default)]
+    pub is_paid: Option<bool>,
     pub stat_status_pairs: Vec<Problem>,
 }
 
@@ -18,10 +30,15 @@ pub struct ProblemList {
 pub struct Problem {
     pub stat: Stat,
     pub difficulty: Difficulty,
+    #[serde(default)]
     pub paid_only: bool,
+    #[serde(default)]
     pub is_favor: bool,
+    #[serde(default)]
     pub frequency: i32,
+    #[serde(default)]
     pub progress: i32,
+    #[serde(default)]
     pub status: Option<String>,
 }
 
@@ -30,15 +47,21 @@ pub struct Problem {
 #[allow(dead_code)]
 pub struct Stat {
     pub question_id: u32,
-    #[serde(deserialize_with = "string_or_bool_option")]
+    #[serde(default, deserialize_with = "string_or_bool_option")]
     pub question__article__live: Option<String>,
+    #[serde(default)]
     pub question__article__slug: Option<String>,
+    #[serde(default)]
     pub question__title: Option<String>,
     pub question__title_slug: String,
+    #[serde(default)]
     pub question__hide: bool,
+    #[serde(default)]
     pub total_acs: i64,
+    #[serde(default)]
     pub total_submitted: i64,
     pub frontend_question_id: u32,
+    #[serde(default)]
     pub is_new_question: bool,
 }
 
@@ -132,10 +155,13 @@ impl TryFrom<i32> for DifficultyLevel {
 pub struct ProblemDetail {
     #[serde(rename = "questionId")]
     pub question_id: String,
+    #[serde(default)]
     pub title: String,
     #[serde(rename = "titleSlug")]
     pub title_slug: String,
+    #[serde(default)]
     pub content: String,
+    #[serde(default)]
     pub difficulty: String,
     #[serde(rename = "exampleTestcases")]
     pub example_testcases: Option<String>,
@@ -148,6 +174,28 @@ pub struct ProblemDetail {
     pub hints: Option<Vec<String>>,
     #[serde(rename = "topicTags")]
     pub topic_tags: Option<Vec<TopicTag>>,
+    #[serde(default)]
+    pub likes: Option<i64>,
+    #[serde(default)]
+    pub dislikes: Option<i64>,
+    /// JSON-encoded stats blob from GraphQL (total accepted/submissions)
+    #[serde(default)]
+    pub stats: Option<String>,
+    /// The signed-in user's reaction: liked (true), disliked (false), or
+    /// none recorded; absent when signed out
+    #[serde(rename = "isLiked", default)]
+    pub is_liked: Option<bool>,
+}
+
+/// Community stats decoded from [`ProblemDetail::stats`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProblemStats {
+    #[serde(rename = "totalAccepted")]
+    pub total_accepted: String,
+    #[serde(rename = "totalSubmission")]
+    pub total_submission: String,
+    #[serde(rename = "acRate")]
+    pub ac_rate: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -204,22 +252,130 @@ pub struct Argument {
     pub name: String,
 }
 
+impl TestConfig {
+    /// The target method name as it appears in the Rust snippet
+    /// (LeetCode metadata uses camelCase).
+    pub fn rust_method_name(&self) -> String {
+        to_snake_case(&self.method_name)
+    }
+}
+
+/// Convert a camelCase identifier to the snake_case the Rust snippet uses.
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 #[allow(dead_code)]
 impl ProblemDetail {
-    pub fn get_rust_snippet(&self) -> Option<String> {
+    /// Get the starter snippet for a given language slug.
+    pub fn get_snippet(&self, lang_slug: &str) -> Option<String> {
         self.code_snippets
             .as_ref()?
             .iter()
-            .find(|s| s.lang_slug == "rust")
+            .find(|s| s.lang_slug == lang_slug)
             .map(|s| s.code.clone())
     }
 
+    pub fn get_rust_snippet(&self) -> Option<String> {
+        self.get_snippet("rust")
+    }
+
     pub fn parse_metadata(&self) -> Option<ProblemMetadata> {
         self.meta_data
             .as_ref()
             .and_then(|m| serde_json::from_str(m).ok())
     }
 
+    /// Get the SQL snippet for database problems (MySQL preferred).
+    pub fn get_sql_snippet(&self) -> Option<String> {
+        let snippets = self.code_snippets.as_ref()?;
+        for slug in ["mysql", "postgresql", "oraclesql", "mssql"] {
+            if let Some(snippet) = snippets.iter().find(|s| s.lang_slug == slug) {
+                return Some(snippet.code.clone());
+            }
+        }
+        None
+    }
+
+    /// Get the bash snippet for shell problems.
+    pub fn get_bash_snippet(&self) -> Option<String> {
+        self.get_snippet("bash")
+    }
+
+    /// Whether this is a shell-category problem: the question metadata
+    /// marks it as such, or it only ships a bash snippet.
+    pub fn is_shell_problem(&self) -> bool {
+        if let Some(ref meta) = self.meta_data
+            && let Ok(value) = serde_json::from_str::<serde_json::Value>(meta)
+            && value.get("shell").and_then(|s| s.as_bool()) == Some(true)
+        {
+            return true;
+        }
+        self.get_bash_snippet().is_some() && self.get_rust_snippet().is_none()
+    }
+
+    /// Whether this is a concurrency-category problem, per its topic tags.
+    pub fn is_concurrency_problem(&self) -> bool {
+        self.topic_tags
+            .as_ref()
+            .is_some_and(|tags| tags.iter().any(|t| t.slug == "concurrency"))
+    }
+
+    /// Whether this is a database-category problem: the question metadata
+    /// marks it as such, or it only ships SQL snippets.
+    pub fn is_database_problem(&self) -> bool {
+        if let Some(ref meta) = self.meta_data
+            && let Ok(value) = serde_json::from_str::<serde_json::Value>(meta)
+            && value.get("database").and_then(|d| d.as_bool()) == Some(true)
+        {
+            return true;
+        }
+        self.get_sql_snippet().is_some() && self.get_rust_snippet().is_none()
+    }
+
+    /// Schema/seed DDL statements for database problems, from the "mysql"
+    /// array in the question metadata.
+    pub fn extract_sql_schema(&self) -> Vec<String> {
+        self.meta_data
+            .as_ref()
+            .and_then(|m| serde_json::from_str::<serde_json::Value>(m).ok())
+            .and_then(|v| v.get("mysql").cloned())
+            .and_then(|v| v.as_array().cloned())
+            .map(|statements| {
+                statements
+                    .iter()
+                    .filter_map(|s| s.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Decode the JSON stats blob, if present and well-formed.
+    pub fn parse_stats(&self) -> Option<ProblemStats> {
+        self.stats.as_ref().and_then(|s| serde_json::from_str(s).ok())
+    }
+
+    /// Fraction of likes among all votes, e.g. 0.95, or `None` if the
+    /// problem has no votes or the fields weren't fetched.
+    pub fn like_ratio(&self) -> Option<f64> {
+        let likes = self.likes?;
+        let dislikes = self.dislikes?;
+        let total = likes + dislikes;
+        if total <= 0 {
+            return None;
+        }
+        Some(likes as f64 / total as f64)
+    }
+
     pub fn parse_test_cases(&self) -> Vec<TestCase> {
         let mut test_cases = Vec::new();
 
@@ -511,6 +667,10 @@ mod tests {
             ]),
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         let snippet = detail.get_rust_snippet();
@@ -518,6 +678,204 @@ mod tests {
         assert!(snippet.unwrap().contains("impl Solution"));
     }
 
+    #[test]
+    fn test_problem_detail_like_ratio() {
+        let mut detail = ProblemDetail {
+            question_id: "1".to_string(),
+            title: "Two Sum".to_string(),
+            title_slug: "two-sum".to_string(),
+            content: String::new(),
+            difficulty: "Easy".to_string(),
+            example_testcases: None,
+            sample_test_case: None,
+            meta_data: None,
+            code_snippets: None,
+            hints: None,
+            topic_tags: None,
+            likes: Some(900),
+            dislikes: Some(100),
+            stats: None,
+            is_liked: None,
+        };
+        assert_eq!(detail.like_ratio(), Some(0.9));
+
+        // No votes at all
+        detail.likes = Some(0);
+        detail.dislikes = Some(0);
+        assert!(detail.like_ratio().is_none());
+
+        // Fields not fetched
+        detail.likes = None;
+        assert!(detail.like_ratio().is_none());
+    }
+
+    fn make_sql_detail() -> ProblemDetail {
+        ProblemDetail {
+            question_id: "595".to_string(),
+            title: "Big Countries".to_string(),
+            title_slug: "big-countries".to_string(),
+            content: String::new(),
+            difficulty: "Easy".to_string(),
+            example_testcases: None,
+            sample_test_case: None,
+            meta_data: Some(
+                r#"{"mysql": ["CREATE TABLE World (name VARCHAR(255))"], "database": true}"#
+                    .to_string(),
+            ),
+            code_snippets: Some(vec![CodeSnippet {
+                lang: "MySQL".to_string(),
+                lang_slug: "mysql".to_string(),
+                code: "SELECT name FROM World".to_string(),
+            }]),
+            hints: None,
+            topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
+        }
+    }
+
+    #[test]
+    fn test_get_sql_snippet() {
+        let detail = make_sql_detail();
+        assert_eq!(
+            detail.get_sql_snippet(),
+            Some("SELECT name FROM World".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_database_problem() {
+        assert!(make_sql_detail().is_database_problem());
+
+        // Metadata flag alone is enough, even without snippets
+        let mut detail = make_sql_detail();
+        detail.code_snippets = None;
+        assert!(detail.is_database_problem());
+
+        // A Rust problem is not a database problem
+        let mut rust_detail = make_sql_detail();
+        rust_detail.meta_data = None;
+        rust_detail.code_snippets = Some(vec![CodeSnippet {
+            lang: "Rust".to_string(),
+            lang_slug: "rust".to_string(),
+            code: "impl Solution {}".to_string(),
+        }]);
+        assert!(!rust_detail.is_database_problem());
+    }
+
+    fn make_shell_detail() -> ProblemDetail {
+        ProblemDetail {
+            meta_data: Some(r#"{"shell": true}"#.to_string()),
+            code_snippets: Some(vec![CodeSnippet {
+                lang: "Bash".to_string(),
+                lang_slug: "bash".to_string(),
+                code: "# Read from the file file.txt and output the tenth line to stdout."
+                    .to_string(),
+            }]),
+            ..make_sql_detail()
+        }
+    }
+
+    #[test]
+    fn test_get_bash_snippet() {
+        let detail = make_shell_detail();
+        assert!(detail.get_bash_snippet().unwrap().starts_with("# Read"));
+        assert!(make_sql_detail().get_bash_snippet().is_none());
+    }
+
+    #[test]
+    fn test_is_concurrency_problem() {
+        let mut detail = make_sql_detail();
+        assert!(!detail.is_concurrency_problem());
+
+        detail.topic_tags = Some(vec![TopicTag {
+            name: "Concurrency".to_string(),
+            slug: "concurrency".to_string(),
+        }]);
+        assert!(detail.is_concurrency_problem());
+    }
+
+    #[test]
+    fn test_is_shell_problem() {
+        assert!(make_shell_detail().is_shell_problem());
+
+        // Metadata flag alone is enough, even without snippets
+        let mut detail = make_shell_detail();
+        detail.code_snippets = None;
+        assert!(detail.is_shell_problem());
+
+        // A bash snippet without a rust one is also enough
+        let mut no_meta = make_shell_detail();
+        no_meta.meta_data = None;
+        assert!(no_meta.is_shell_problem());
+
+        // A database problem is not a shell problem
+        assert!(!make_sql_detail().is_shell_problem());
+    }
+
+    #[test]
+    fn test_extract_sql_schema() {
+        let detail = make_sql_detail();
+        let schema = detail.extract_sql_schema();
+        assert_eq!(schema, vec!["CREATE TABLE World (name VARCHAR(255))"]);
+
+        let mut no_meta = make_sql_detail();
+        no_meta.meta_data = None;
+        assert!(no_meta.extract_sql_schema().is_empty());
+    }
+
+    #[test]
+    fn test_problem_detail_parse_stats() {
+        let detail = ProblemDetail {
+            question_id: "1".to_string(),
+            title: "Two Sum".to_string(),
+            title_slug: "two-sum".to_string(),
+            content: String::new(),
+            difficulty: "Easy".to_string(),
+            example_testcases: None,
+            sample_test_case: None,
+            meta_data: None,
+            code_snippets: None,
+            hints: None,
+            topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: Some(
+                r#"{"totalAccepted": "14.2M", "totalSubmission": "26.3M", "acRate": "54.1%"}"#
+                    .to_string(),
+            ),
+            is_liked: None,
+        };
+        let stats = detail.parse_stats().unwrap();
+        assert_eq!(stats.total_accepted, "14.2M");
+        assert_eq!(stats.total_submission, "26.3M");
+        assert_eq!(stats.ac_rate, "54.1%");
+    }
+
+    #[test]
+    fn test_problem_detail_parse_stats_malformed() {
+        let detail = ProblemDetail {
+            question_id: "1".to_string(),
+            title: "Two Sum".to_string(),
+            title_slug: "two-sum".to_string(),
+            content: String::new(),
+            difficulty: "Easy".to_string(),
+            example_testcases: None,
+            sample_test_case: None,
+            meta_data: None,
+            code_snippets: None,
+            hints: None,
+            topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: Some("not json".to_string()),
+            is_liked: None,
+        };
+        assert!(detail.parse_stats().is_none());
+    }
+
     #[test]
     fn test_problem_detail_get_rust_snippet_none() {
         let detail = ProblemDetail {
@@ -536,6 +894,10 @@ mod tests {
             }]),
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         assert!(detail.get_rust_snippet().is_none());
@@ -555,6 +917,10 @@ mod tests {
             code_snippets: None,
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         assert!(detail.get_rust_snippet().is_none());
@@ -574,6 +940,10 @@ mod tests {
             code_snippets: None,
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         let metadata = detail.parse_metadata();
@@ -595,6 +965,10 @@ mod tests {
             code_snippets: None,
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         assert!(detail.parse_metadata().is_none());
@@ -614,6 +988,10 @@ mod tests {
             code_snippets: None,
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         let test_cases = detail.parse_test_cases();
@@ -638,6 +1016,10 @@ mod tests {
             code_snippets: None,
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         let test_cases = detail.parse_test_cases();
@@ -658,6 +1040,10 @@ mod tests {
             code_snippets: None,
             hints: None,
             topic_tags: None,
+            likes: None,
+            dislikes: None,
+            stats: None,
+            is_liked: None,
         };
 
         let cleaned = detail.clean_content();
@@ -725,6 +1111,35 @@ mod tests {
         assert_eq!(stat.question__article__live, None);
     }
 
+    #[test]
+    fn test_problem_deserializes_with_missing_fields() {
+        // Only the identity fields are required; everything else defaults
+        let json = r#"{
+            "stat": {
+                "question_id": 1,
+                "question__title_slug": "two-sum",
+                "frontend_question_id": 1
+            },
+            "difficulty": {"level": 1}
+        }"#;
+        let problem: Problem = serde_json::from_str(json).unwrap();
+        assert_eq!(problem.stat.frontend_question_id, 1);
+        assert_eq!(problem.stat.question_title(), "two sum");
+        assert!(!problem.paid_only);
+        assert_eq!(problem.frequency, 0);
+        assert!(problem.status.is_none());
+    }
+
+    #[test]
+    fn test_problem_detail_deserializes_with_missing_fields() {
+        let json = r#"{"questionId": "1", "titleSlug": "two-sum"}"#;
+        let detail: ProblemDetail = serde_json::from_str(json).unwrap();
+        assert_eq!(detail.title_slug, "two-sum");
+        assert!(detail.title.is_empty());
+        assert!(detail.content.is_empty());
+        assert!(detail.code_snippets.is_none());
+    }
+
     #[test]
     fn test_html_to_markdown_basic() {
         let html = "<p>Hello <strong>world</strong></p>";
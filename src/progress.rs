@@ -0,0 +1,518 @@
+//! Local progress database
+//!
+//! Tracks per-problem progress (solved status and where the solution came
+//! from) in a `progress.json` file at the workspace root, so commands can
+//! reason about local state without hitting the LeetCode API.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+const PROGRESS_FILE: &str = "progress.json";
+
+/// Solve status of a problem in the local progress database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SolveStatus {
+    Solved,
+    Attempting,
+}
+
+/// Per-problem progress record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProblemProgress {
+    pub slug: String,
+    pub status: SolveStatus,
+    /// Where this record came from, e.g. "submit" or "import"
+    #[serde(default)]
+    pub source: String,
+    /// Workspace-relative paths of accepted-solution snapshots, oldest
+    /// first, as saved by `submit` under `accepted/`.
+    #[serde(default)]
+    pub accepted_snapshots: Vec<String>,
+    /// Wall time of the most recent local test run, in milliseconds.
+    #[serde(default)]
+    pub last_test_wall_ms: Option<u64>,
+    /// Peak RSS observed during the most recent local test run, in
+    /// kilobytes.
+    #[serde(default)]
+    pub last_test_peak_rss_kb: Option<u64>,
+    /// Unix timestamp of when the problem was first recorded as solved;
+    /// absent for records predating the field and for unsolved problems.
+    #[serde(default)]
+    pub solved_at: Option<u64>,
+    /// Judge measurements of every accepted submission, oldest first.
+    #[serde(default)]
+    pub perf_history: Vec<PerfSample>,
+    /// Local test runs that ended in failure.
+    #[serde(default)]
+    pub failed_test_runs: u32,
+    /// Submissions sent to the judge, accepted or not.
+    #[serde(default)]
+    pub submissions: u32,
+    /// Submissions the judge rejected (wrong answer, TLE, ...).
+    #[serde(default)]
+    pub rejected_submissions: u32,
+}
+
+/// Runtime and memory figures of one accepted submission.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerfSample {
+    /// Unix timestamp of the submission
+    pub at: u64,
+    /// Judge runtime, e.g. "4 ms"
+    pub runtime: String,
+    /// Judge memory, e.g. "2.1 MB"
+    pub memory: String,
+    /// Runtime percentile beaten, 0-100; the judge omits it sometimes
+    #[serde(default)]
+    pub runtime_percentile: Option<f64>,
+    /// Memory percentile beaten, 0-100
+    #[serde(default)]
+    pub memory_percentile: Option<f64>,
+}
+
+/// The local progress database, keyed by frontend problem ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    pub problems: BTreeMap<u32, ProblemProgress>,
+}
+
+impl Progress {
+    /// Load the progress database from the current directory, or an empty
+    /// one if the file doesn't exist yet.
+    pub fn load() -> Result<Self> {
+        Self::load_from(Path::new(""))
+    }
+
+    /// Load the progress database from the workspace rooted at `root`.
+    pub fn load_from(root: &Path) -> Result<Self> {
+        let path = Self::path(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the progress database to the current directory.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(Path::new(""))
+    }
+
+    /// Save the progress database to the workspace rooted at `root`.
+    pub fn save_to(&self, root: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(root), content)?;
+        Ok(())
+    }
+
+    fn path(root: &Path) -> PathBuf {
+        root.join(PROGRESS_FILE)
+    }
+
+    /// Record a problem with the given status, overwriting any older record
+    /// unless it would downgrade a solved problem to attempting.
+    pub fn record(&mut self, id: u32, slug: &str, status: SolveStatus, source: &str) {
+        if let Some(existing) = self.problems.get(&id)
+            && existing.status == SolveStatus::Solved
+            && status == SolveStatus::Attempting
+        {
+            return;
+        }
+        // Snapshots and measurements survive re-recording; they describe
+        // past runs, not the latest status
+        let prior = self.problems.get(&id);
+        let accepted_snapshots = prior
+            .map(|p| p.accepted_snapshots.clone())
+            .unwrap_or_default();
+        let last_test_wall_ms = prior.and_then(|p| p.last_test_wall_ms);
+        let last_test_peak_rss_kb = prior.and_then(|p| p.last_test_peak_rss_kb);
+        let perf_history = prior.map(|p| p.perf_history.clone()).unwrap_or_default();
+        let failed_test_runs = prior.map(|p| p.failed_test_runs).unwrap_or_default();
+        let submissions = prior.map(|p| p.submissions).unwrap_or_default();
+        let rejected_submissions = prior.map(|p| p.rejected_submissions).unwrap_or_default();
+        // The first solve timestamp is what goals and streaks count, so it
+        // survives re-submits
+        let solved_at = match prior.and_then(|p| p.solved_at) {
+            Some(ts) => Some(ts),
+            None if status == SolveStatus::Solved => Some(now_ts()),
+            None => None,
+        };
+        self.problems.insert(
+            id,
+            ProblemProgress {
+                slug: slug.to_string(),
+                status,
+                source: source.to_string(),
+                accepted_snapshots,
+                last_test_wall_ms,
+                last_test_peak_rss_kb,
+                solved_at,
+                perf_history,
+                failed_test_runs,
+                submissions,
+                rejected_submissions,
+            },
+        );
+    }
+
+    /// Record the wall time and peak RSS of a local test run, creating an
+    /// attempting record first if the problem has none yet so measurements
+    /// taken before the first submit aren't lost.
+    pub fn record_test_run(&mut self, id: u32, slug: &str, wall_ms: u64, peak_rss_kb: Option<u64>) {
+        if !self.problems.contains_key(&id) {
+            self.record(id, slug, SolveStatus::Attempting, "test");
+        }
+        if let Some(entry) = self.problems.get_mut(&id) {
+            entry.last_test_wall_ms = Some(wall_ms);
+            if peak_rss_kb.is_some() {
+                entry.last_test_peak_rss_kb = peak_rss_kb;
+            }
+        }
+    }
+
+    /// Count a failed local test run toward the problem's attempt history,
+    /// creating an attempting record first if the problem has none yet.
+    pub fn record_test_failure(&mut self, id: u32, slug: &str) {
+        if !self.problems.contains_key(&id) {
+            self.record(id, slug, SolveStatus::Attempting, "test");
+        }
+        if let Some(entry) = self.problems.get_mut(&id) {
+            entry.failed_test_runs += 1;
+        }
+    }
+
+    /// Count a submission and its verdict toward the problem's attempt
+    /// history, creating an attempting record first if the problem has none
+    /// yet.
+    pub fn record_submission(&mut self, id: u32, slug: &str, accepted: bool) {
+        if !self.problems.contains_key(&id) {
+            self.record(id, slug, SolveStatus::Attempting, "submit");
+        }
+        if let Some(entry) = self.problems.get_mut(&id) {
+            entry.submissions += 1;
+            if !accepted {
+                entry.rejected_submissions += 1;
+            }
+        }
+    }
+
+    /// Total attempts recorded for a problem: failed local test runs plus
+    /// every submission.
+    pub fn attempts(&self, id: u32) -> u32 {
+        self.problems
+            .get(&id)
+            .map(|p| p.failed_test_runs + p.submissions)
+            .unwrap_or(0)
+    }
+
+    /// Whether a problem was solved on the first try: one accepted
+    /// submission, no rejections, and no failed local test runs. False for
+    /// problems that aren't solved or predate attempt tracking.
+    pub fn solved_first_try(&self, id: u32) -> bool {
+        self.problems.get(&id).is_some_and(|p| {
+            p.status == SolveStatus::Solved
+                && p.submissions == 1
+                && p.rejected_submissions == 0
+                && p.failed_test_runs == 0
+        })
+    }
+
+    /// Append an accepted-solution snapshot path to a problem's record.
+    /// No-op if the problem was never recorded.
+    pub fn add_snapshot(&mut self, id: u32, path: &str) {
+        if let Some(entry) = self.problems.get_mut(&id) {
+            entry.accepted_snapshots.push(path.to_string());
+        }
+    }
+
+    /// Append the judge measurements of an accepted submission to a
+    /// problem's record. No-op if the problem was never recorded.
+    pub fn record_perf(&mut self, id: u32, sample: PerfSample) {
+        if let Some(entry) = self.problems.get_mut(&id) {
+            entry.perf_history.push(sample);
+        }
+    }
+
+    /// Whether the problem is recorded as solved locally.
+    pub fn is_solved(&self, id: u32) -> bool {
+        self.problems
+            .get(&id)
+            .is_some_and(|p| p.status == SolveStatus::Solved)
+    }
+
+    /// IDs of problems first solved at or after `from` (Unix timestamp).
+    pub fn solved_since(&self, from: u64) -> Vec<u32> {
+        self.problems
+            .iter()
+            .filter(|(_, p)| p.solved_at.is_some_and(|ts| ts >= from))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// How many problems were first solved during the UTC day containing
+    /// `now`.
+    pub fn solved_today(&self, now: u64) -> usize {
+        let today = now / SECS_PER_DAY;
+        self.problems
+            .values()
+            .filter(|p| p.solved_at.is_some_and(|ts| ts / SECS_PER_DAY == today))
+            .count()
+    }
+
+    /// The current solve streak in UTC days: consecutive days with at least
+    /// one first solve, ending today or yesterday. A streak ending yesterday
+    /// still counts — it isn't broken until today passes without a solve.
+    pub fn streak_days(&self, now: u64) -> u32 {
+        let days: std::collections::BTreeSet<u64> = self
+            .problems
+            .values()
+            .filter_map(|p| p.solved_at)
+            .map(|ts| ts / SECS_PER_DAY)
+            .collect();
+        let today = now / SECS_PER_DAY;
+        let mut day = if days.contains(&today) {
+            today
+        } else if today > 0 && days.contains(&(today - 1)) {
+            today - 1
+        } else {
+            return 0;
+        };
+        let mut streak = 1;
+        while day > 0 && days.contains(&(day - 1)) {
+            day -= 1;
+            streak += 1;
+        }
+        streak
+    }
+}
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// The current Unix timestamp in seconds.
+pub(crate) fn now_ts() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_record_and_query() {
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", SolveStatus::Solved, "import");
+        assert!(progress.is_solved(1));
+        assert!(!progress.is_solved(2));
+        assert_eq!(progress.problems[&1].slug, "two-sum");
+        assert_eq!(progress.problems[&1].source, "import");
+    }
+
+    #[test]
+    fn test_add_snapshot_survives_re_record() {
+        let mut progress = Progress::default();
+        progress.add_snapshot(1, "accepted/p0001_two_sum/1700000000_4ms.rs");
+        assert!(progress.problems.is_empty());
+
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        progress.add_snapshot(1, "accepted/p0001_two_sum/1700000000_4ms.rs");
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        progress.add_snapshot(1, "accepted/p0001_two_sum/1700000100_0ms.rs");
+        assert_eq!(
+            progress.problems[&1].accepted_snapshots,
+            vec![
+                "accepted/p0001_two_sum/1700000000_4ms.rs",
+                "accepted/p0001_two_sum/1700000100_0ms.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_record_test_run() {
+        let mut progress = Progress::default();
+        // Creates an attempting record when the problem is new
+        progress.record_test_run(1, "two-sum", 2400, Some(140_000));
+        assert!(!progress.is_solved(1));
+        assert_eq!(progress.problems[&1].source, "test");
+        assert_eq!(progress.problems[&1].last_test_wall_ms, Some(2400));
+        assert_eq!(progress.problems[&1].last_test_peak_rss_kb, Some(140_000));
+
+        // A missing RSS sample keeps the previous one
+        progress.record_test_run(1, "two-sum", 1800, None);
+        assert_eq!(progress.problems[&1].last_test_wall_ms, Some(1800));
+        assert_eq!(progress.problems[&1].last_test_peak_rss_kb, Some(140_000));
+
+        // Measurements survive re-recording the solve status
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert_eq!(progress.problems[&1].last_test_wall_ms, Some(1800));
+    }
+
+    #[test]
+    fn test_record_perf_survives_re_record() {
+        let mut progress = Progress::default();
+        progress.record_perf(
+            1,
+            PerfSample {
+                at: 1_700_000_000,
+                runtime: "4 ms".to_string(),
+                memory: "2.1 MB".to_string(),
+                runtime_percentile: Some(95.2),
+                memory_percentile: Some(80.1),
+            },
+        );
+        assert!(progress.problems.is_empty());
+
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        progress.record_perf(
+            1,
+            PerfSample {
+                at: 1_700_000_000,
+                runtime: "4 ms".to_string(),
+                memory: "2.1 MB".to_string(),
+                runtime_percentile: Some(95.2),
+                memory_percentile: Some(80.1),
+            },
+        );
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert_eq!(progress.problems[&1].perf_history.len(), 1);
+        assert_eq!(progress.problems[&1].perf_history[0].runtime, "4 ms");
+    }
+
+    #[test]
+    fn test_attempt_counters_survive_re_record() {
+        let mut progress = Progress::default();
+        // Creates an attempting record when the problem is new
+        progress.record_test_failure(1, "two-sum");
+        assert!(!progress.is_solved(1));
+        assert_eq!(progress.problems[&1].failed_test_runs, 1);
+
+        progress.record_submission(1, "two-sum", false);
+        progress.record_submission(1, "two-sum", true);
+        assert_eq!(progress.problems[&1].submissions, 2);
+        assert_eq!(progress.problems[&1].rejected_submissions, 1);
+        assert_eq!(progress.attempts(1), 3);
+
+        // Counters survive re-recording the solve status
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert_eq!(progress.attempts(1), 3);
+        assert_eq!(progress.problems[&1].rejected_submissions, 1);
+    }
+
+    #[test]
+    fn test_solved_first_try() {
+        let mut progress = Progress::default();
+        progress.record_submission(1, "two-sum", true);
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert!(progress.solved_first_try(1));
+
+        // A failed local test run before the solve disqualifies it
+        progress.record_test_failure(2, "add-two-numbers");
+        progress.record_submission(2, "add-two-numbers", true);
+        progress.record(2, "add-two-numbers", SolveStatus::Solved, "submit");
+        assert!(!progress.solved_first_try(2));
+
+        // Unsolved and unknown problems are never first-try
+        progress.record_submission(3, "longest-substring", false);
+        assert!(!progress.solved_first_try(3));
+        assert!(!progress.solved_first_try(999));
+    }
+
+    #[test]
+    fn test_record_never_downgrades_solved() {
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        progress.record(1, "two-sum", SolveStatus::Attempting, "import");
+        assert!(progress.is_solved(1));
+    }
+
+    #[test]
+    fn test_record_upgrades_attempting() {
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", SolveStatus::Attempting, "pick");
+        assert!(!progress.is_solved(1));
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert!(progress.is_solved(1));
+    }
+
+    /// A progress DB with one problem first solved at each given timestamp.
+    fn progress_with_solves(timestamps: &[u64]) -> Progress {
+        let mut progress = Progress::default();
+        for (i, ts) in timestamps.iter().enumerate() {
+            let id = i as u32 + 1;
+            progress.record(id, "slug", SolveStatus::Solved, "submit");
+            progress.problems.get_mut(&id).unwrap().solved_at = Some(*ts);
+        }
+        progress
+    }
+
+    #[test]
+    fn test_solved_at_set_once() {
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", SolveStatus::Attempting, "pick");
+        assert_eq!(progress.problems[&1].solved_at, None);
+
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        let first = progress.problems[&1].solved_at.unwrap();
+        assert!(first > 0);
+
+        // A re-submit keeps the first solve timestamp
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        assert_eq!(progress.problems[&1].solved_at, Some(first));
+    }
+
+    #[test]
+    fn test_solved_today_and_since() {
+        let day = 20_000 * SECS_PER_DAY;
+        let progress =
+            progress_with_solves(&[day + 100, day + 200, day - SECS_PER_DAY, day - 10 * SECS_PER_DAY]);
+        assert_eq!(progress.solved_today(day + 3600), 2);
+        assert_eq!(progress.solved_since(day - SECS_PER_DAY).len(), 3);
+        assert_eq!(Progress::default().solved_today(day), 0);
+    }
+
+    #[test]
+    fn test_streak_days() {
+        let day = 20_000 * SECS_PER_DAY;
+        let now = day + 3600;
+
+        // Three consecutive days ending today
+        let progress = progress_with_solves(&[day, day - SECS_PER_DAY, day - 2 * SECS_PER_DAY]);
+        assert_eq!(progress.streak_days(now), 3);
+
+        // A streak ending yesterday still counts
+        let progress = progress_with_solves(&[day - SECS_PER_DAY, day - 2 * SECS_PER_DAY]);
+        assert_eq!(progress.streak_days(now), 2);
+
+        // A gap before yesterday breaks it
+        let progress = progress_with_solves(&[day - 2 * SECS_PER_DAY, day - 3 * SECS_PER_DAY]);
+        assert_eq!(progress.streak_days(now), 0);
+
+        assert_eq!(Progress::default().streak_days(now), 0);
+    }
+
+    #[test]
+    fn test_load_save_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut progress = Progress::load_from(temp_dir.path()).unwrap();
+        assert!(progress.problems.is_empty());
+
+        progress.record(42, "trapping-rain-water", SolveStatus::Solved, "import");
+        progress.save_to(temp_dir.path()).unwrap();
+
+        let reloaded = Progress::load_from(temp_dir.path()).unwrap();
+        assert!(reloaded.is_solved(42));
+        assert_eq!(reloaded.problems[&42].slug, "trapping-rain-water");
+    }
+}
@@ -0,0 +1,132 @@
+//! Practice queue
+//!
+//! Stores the problems picked for a practice session in a `queue.json` file
+//! at the workspace root. `pick --count N` fills the queue and
+//! `leetcode-cli queue` works through it.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+const QUEUE_FILE: &str = "queue.json";
+
+/// One queued problem.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueItem {
+    pub id: u32,
+    pub slug: String,
+    pub title: String,
+}
+
+/// The practice queue, in pick order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PracticeQueue {
+    /// Unix timestamp of when the queue was created.
+    #[serde(default)]
+    pub created: u64,
+    #[serde(default)]
+    pub items: Vec<QueueItem>,
+}
+
+impl PracticeQueue {
+    /// Load the queue from the current directory, or an empty one if the
+    /// file doesn't exist yet.
+    pub fn load() -> Result<Self> {
+        Self::load_from(Path::new(""))
+    }
+
+    /// Load the queue from the workspace rooted at `root`.
+    pub fn load_from(root: &Path) -> Result<Self> {
+        let path = Self::path(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the queue to the current directory.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(Path::new(""))
+    }
+
+    /// Save the queue to the workspace rooted at `root`.
+    pub fn save_to(&self, root: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(root), content)?;
+        Ok(())
+    }
+
+    fn path(root: &Path) -> PathBuf {
+        root.join(QUEUE_FILE)
+    }
+
+    /// Append an item unless the problem is already queued.
+    pub fn push(&mut self, id: u32, slug: &str, title: &str) {
+        if self.items.iter().any(|item| item.id == id) {
+            return;
+        }
+        self.items.push(QueueItem {
+            id,
+            slug: slug.to_string(),
+            title: title.to_string(),
+        });
+    }
+
+    /// Items not yet solved according to the progress database.
+    pub fn remaining(&self, progress: &crate::progress::Progress) -> Vec<&QueueItem> {
+        self.items
+            .iter()
+            .filter(|item| !progress.is_solved(item.id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::progress::{Progress, SolveStatus};
+
+    #[test]
+    fn test_push_deduplicates() {
+        let mut queue = PracticeQueue::default();
+        queue.push(1, "two-sum", "Two Sum");
+        queue.push(1, "two-sum", "Two Sum");
+        queue.push(2, "add-two-numbers", "Add Two Numbers");
+        assert_eq!(queue.items.len(), 2);
+    }
+
+    #[test]
+    fn test_remaining_skips_solved() {
+        let mut queue = PracticeQueue::default();
+        queue.push(1, "two-sum", "Two Sum");
+        queue.push(2, "add-two-numbers", "Add Two Numbers");
+
+        let mut progress = Progress::default();
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+
+        let remaining = queue.remaining(&progress);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, 2);
+    }
+
+    #[test]
+    fn test_load_save_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut queue = PracticeQueue::load_from(temp_dir.path()).unwrap();
+        assert!(queue.items.is_empty());
+
+        queue.created = 1_700_000_000;
+        queue.push(42, "trapping-rain-water", "Trapping Rain Water");
+        queue.save_to(temp_dir.path()).unwrap();
+
+        let reloaded = PracticeQueue::load_from(temp_dir.path()).unwrap();
+        assert_eq!(reloaded.created, 1_700_000_000);
+        assert_eq!(reloaded.items.len(), 1);
+        assert_eq!(reloaded.items[0].slug, "trapping-rain-water");
+    }
+}
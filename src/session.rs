@@ -0,0 +1,143 @@
+//! Active-session state
+//!
+//! Persists what the user is in the middle of — the current problem, the
+//! running timebox, and submissions uploaded but not yet polled — in a
+//! `session.json` file at the workspace root. If the terminal dies
+//! mid-session, `resume` reads this back and restores where things stood,
+//! including the remaining time on the clock.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+const SESSION_FILE: &str = "session.json";
+
+/// The persisted state of an in-flight session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    /// What kind of session this is: "solve", "interview", or "submit"
+    pub mode: String,
+    /// The problem being worked on
+    pub problem_id: u32,
+    /// Unix timestamp the timebox ends at; zero when there is no timer
+    #[serde(default)]
+    pub deadline: u64,
+    /// Submission IDs uploaded but not yet polled to a verdict
+    #[serde(default)]
+    pub pending_submissions: Vec<i64>,
+}
+
+impl Session {
+    /// Start a session record for a problem with a timebox ending at
+    /// `deadline` (zero for no timer).
+    pub fn start(mode: &str, problem_id: u32, deadline: u64) -> Self {
+        Self {
+            mode: mode.to_string(),
+            problem_id,
+            deadline,
+            pending_submissions: Vec::new(),
+        }
+    }
+
+    /// Load the active session from the current directory, if any.
+    pub fn load() -> Result<Option<Self>> {
+        Self::load_from(Path::new(""))
+    }
+
+    /// Load the active session from the workspace rooted at `root`.
+    pub fn load_from(root: &Path) -> Result<Option<Self>> {
+        let path = Self::path(root);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Save the session to the current directory.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(Path::new(""))
+    }
+
+    /// Save the session to the workspace rooted at `root`.
+    pub fn save_to(&self, root: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(root), content)?;
+        Ok(())
+    }
+
+    /// Clear the active session in the current directory, if any.
+    pub fn clear() -> Result<()> {
+        Self::clear_in(Path::new(""))
+    }
+
+    /// Clear the active session in the workspace rooted at `root`, if any.
+    pub fn clear_in(root: &Path) -> Result<()> {
+        let path = Self::path(root);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    fn path(root: &Path) -> PathBuf {
+        root.join(SESSION_FILE)
+    }
+
+    /// Seconds left on the timebox at `now`, or `None` when there is no
+    /// timer or it has already expired.
+    pub fn remaining_secs(&self, now: u64) -> Option<u64> {
+        if self.deadline > now {
+            Some(self.deadline - now)
+        } else {
+            None
+        }
+    }
+
+    /// Note a submission that was uploaded but not polled to a verdict.
+    pub fn add_pending_submission(&mut self, submission_id: i64) {
+        if !self.pending_submissions.contains(&submission_id) {
+            self.pending_submissions.push(submission_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_load_save_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        assert!(Session::load_from(dir.path()).unwrap().is_none());
+
+        let mut session = Session::start("solve", 42, 1_700_000_000);
+        session.add_pending_submission(9001);
+        session.add_pending_submission(9001);
+        session.save_to(dir.path()).unwrap();
+
+        let loaded = Session::load_from(dir.path()).unwrap().unwrap();
+        assert_eq!(loaded.mode, "solve");
+        assert_eq!(loaded.problem_id, 42);
+        assert_eq!(loaded.deadline, 1_700_000_000);
+        assert_eq!(loaded.pending_submissions, vec![9001]);
+
+        Session::clear_in(dir.path()).unwrap();
+        assert!(Session::load_from(dir.path()).unwrap().is_none());
+        // Clearing twice is fine
+        Session::clear_in(dir.path()).unwrap();
+    }
+
+    #[test]
+    fn test_remaining_secs() {
+        let session = Session::start("solve", 1, 1000);
+        assert_eq!(session.remaining_secs(400), Some(600));
+        assert_eq!(session.remaining_secs(1000), None);
+        assert_eq!(session.remaining_secs(2000), None);
+        // No timer at all
+        assert_eq!(Session::start("submit", 1, 0).remaining_secs(0), None);
+    }
+}
@@ -0,0 +1,263 @@
+//! Terminal-width-aware table rendering.
+//!
+//! Shared by the commands that print columnar output. Cell widths are
+//! measured by display width rather than `char` count, so CJK titles
+//! (leetcode.cn) keep their alignment, and one designated column shrinks
+//! with grapheme-aware ellipsis truncation when the terminal is narrow.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Gap between columns, in spaces.
+const COLUMN_GAP: usize = 2;
+
+/// Narrowest a flexible column is allowed to shrink to.
+const MIN_FLEX_WIDTH: usize = 8;
+
+/// One table cell: the plain text used for width math, and the painted
+/// (possibly ANSI-colored) text actually printed. `format!("{:<width$}")`
+/// misaligns colored strings because it counts escape bytes, so padding is
+/// always computed from the plain text.
+pub struct Cell {
+    plain: String,
+    painted: String,
+}
+
+impl Cell {
+    pub fn new(text: impl Into<String>) -> Self {
+        let plain = text.into();
+        let painted = plain.clone();
+        Self { plain, painted }
+    }
+
+    /// A cell whose printed form carries ANSI colors.
+    pub fn painted(plain: impl Into<String>, painted: impl Into<String>) -> Self {
+        Self {
+            plain: plain.into(),
+            painted: painted.into(),
+        }
+    }
+}
+
+/// A simple left-aligned table with one optional flexible column.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<Cell>>,
+    flexible: Option<usize>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+            flexible: None,
+        }
+    }
+
+    /// Mark the column at `index` as the one that shrinks (with ellipsis
+    /// truncation) when the table would overflow the terminal.
+    pub fn flexible(mut self, index: usize) -> Self {
+        self.flexible = Some(index);
+        self
+    }
+
+    pub fn add_row(&mut self, cells: Vec<Cell>) {
+        debug_assert_eq!(cells.len(), self.headers.len());
+        self.rows.push(cells);
+    }
+
+    /// Render to a string constrained to `max_width` columns.
+    pub fn render(&self, max_width: usize) -> String {
+        let widths = self.column_widths(max_width);
+        let mut out = String::new();
+
+        out.push_str(&render_line(
+            &self
+                .headers
+                .iter()
+                .map(|h| (h.as_str(), h.as_str()))
+                .collect::<Vec<_>>(),
+            &widths,
+        ));
+        out.push('\n');
+        let total = widths.iter().sum::<usize>() + COLUMN_GAP * widths.len().saturating_sub(1);
+        out.push_str(&"-".repeat(total));
+        out.push('\n');
+
+        for row in &self.rows {
+            let cells: Vec<(&str, &str)> = row
+                .iter()
+                .map(|c| (c.plain.as_str(), c.painted.as_str()))
+                .collect();
+            out.push_str(&render_line(&cells, &widths));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Print to stdout, sized to the detected terminal width.
+    pub fn print(&self) {
+        print!("{}", self.render(detect_width()));
+    }
+
+    /// Natural column widths, with the flexible column shrunk to fit
+    /// `max_width` if needed.
+    fn column_widths(&self, max_width: usize) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.width()).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(cell.plain.width());
+            }
+        }
+
+        if let Some(flex) = self.flexible {
+            let total = widths.iter().sum::<usize>() + COLUMN_GAP * widths.len().saturating_sub(1);
+            if total > max_width {
+                let excess = total - max_width;
+                widths[flex] = widths[flex]
+                    .saturating_sub(excess)
+                    .max(MIN_FLEX_WIDTH.max(self.headers[flex].width()));
+            }
+        }
+        widths
+    }
+}
+
+/// Render one row: painted text, padded by plain-text display width, with
+/// over-wide cells truncated to their column.
+fn render_line(cells: &[(&str, &str)], widths: &[usize]) -> String {
+    let mut parts = Vec::with_capacity(cells.len());
+    for (i, ((plain, painted), &width)) in cells.iter().zip(widths).enumerate() {
+        let (text, text_width) = if plain.width() > width {
+            let truncated = truncate_to_width(plain, width);
+            let w = truncated.width();
+            (truncated, w)
+        } else {
+            (painted.to_string(), plain.width())
+        };
+        let pad = if i + 1 == cells.len() {
+            0 // no trailing spaces on the last column
+        } else {
+            width - text_width
+        };
+        parts.push(format!("{}{}", text, " ".repeat(pad)));
+    }
+    parts.join(&" ".repeat(COLUMN_GAP)).trim_end().to_string()
+}
+
+/// Truncate to at most `max` display columns, appending an ellipsis when
+/// anything was cut. Splits on grapheme clusters so combining marks and
+/// wide characters are never broken in half.
+pub fn truncate_to_width(s: &str, max: usize) -> String {
+    if s.width() <= max {
+        return s.to_string();
+    }
+    let budget = max.saturating_sub(1); // room for the ellipsis
+    let mut out = String::new();
+    let mut used = 0;
+    for grapheme in s.graphemes(true) {
+        let w = grapheme.width();
+        if used + w > budget {
+            break;
+        }
+        out.push_str(grapheme);
+        used += w;
+    }
+    out.push('…');
+    out
+}
+
+/// Terminal width in columns: the attached terminal if any, else the
+/// COLUMNS convention, else 80.
+fn detect_width() -> usize {
+    if let Some((terminal_size::Width(w), _)) = terminal_size::terminal_size() {
+        return w as usize;
+    }
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(80)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_to_width_ascii() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("a long title here", 8), "a long …");
+    }
+
+    #[test]
+    fn test_truncate_to_width_cjk() {
+        // Each CJK character is two columns wide; the cut must never
+        // leave half a character behind
+        assert_eq!(truncate_to_width("两数之和", 8), "两数之和");
+        assert_eq!(truncate_to_width("两数之和", 6), "两数…");
+        assert_eq!(truncate_to_width("两数之和", 5), "两数…");
+    }
+
+    #[test]
+    fn test_truncate_keeps_combining_marks_together() {
+        // "é" as e + combining accent is one grapheme
+        let s = "cafe\u{301} time";
+        let cut = truncate_to_width(s, 5);
+        assert_eq!(cut, "cafe\u{301}…");
+    }
+
+    #[test]
+    fn test_table_alignment_with_wide_chars() {
+        let mut table = Table::new(&["ID", "Title", "Status"]);
+        table.add_row(vec![
+            Cell::new("1"),
+            Cell::new("Two Sum"),
+            Cell::new("Solved"),
+        ]);
+        table.add_row(vec![
+            Cell::new("2"),
+            Cell::new("两数之和"),
+            Cell::new("New"),
+        ]);
+        let rendered = table.render(80);
+        let lines: Vec<&str> = rendered.lines().collect();
+        // "两数之和" occupies 8 columns despite being 4 chars; the
+        // status column must start at the same display column in both
+        // rows (byte offsets differ, display widths must not)
+        let before_status = &lines[2][..lines[2].find("Solved").unwrap()];
+        let before_new = &lines[3][..lines[3].find("New").unwrap()];
+        assert_eq!(before_status.width(), before_new.width());
+    }
+
+    #[test]
+    fn test_table_flexible_column_shrinks() {
+        let mut table = Table::new(&["ID", "Title", "Status"]).flexible(1);
+        table.add_row(vec![
+            Cell::new("1"),
+            Cell::new("a very long problem title that will not fit"),
+            Cell::new("New"),
+        ]);
+        let rendered = table.render(30);
+        for line in rendered.lines() {
+            assert!(line.width() <= 30, "line too wide: {line:?}");
+        }
+        assert!(rendered.contains('…'));
+    }
+
+    #[test]
+    fn test_table_painted_cells_pad_by_plain_width() {
+        let mut table = Table::new(&["ID", "Status"]);
+        table.add_row(vec![
+            Cell::new("1"),
+            Cell::painted("Solved", "\x1b[32mSolved\x1b[0m".to_string()),
+        ]);
+        table.add_row(vec![Cell::new("2"), Cell::new("New")]);
+        let rendered = table.render(80);
+        // The ANSI escapes survive in the output but don't affect padding
+        assert!(rendered.contains("\x1b[32m"));
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert!(lines[2].starts_with("1   "));
+        assert!(lines[3].starts_with("2   "));
+    }
+}
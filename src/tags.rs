@@ -0,0 +1,170 @@
+//! Local topic-tag index
+//!
+//! The `/api/problems/all/` payload carries no topic tags, so tag filtering
+//! used to need one GraphQL call per candidate problem. This module caches
+//! a bulk tag→problems mapping (fetched page by page from
+//! `problemsetQuestionList`) in a `tags.json` file at the workspace root,
+//! giving `pick --tag` and `list --tag` instant offline filtering. The
+//! fetch is resumable: each ingested page is persisted with its offset, so
+//! an interrupted run picks up where it left off.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+const TAGS_FILE: &str = "tags.json";
+
+/// The cached tag→problems mapping, keyed by tag slug.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagCache {
+    /// Offset to resume the bulk fetch from — the number of questions
+    /// already ingested.
+    #[serde(default)]
+    pub next_skip: u64,
+    /// Whether the bulk fetch has seen every problem.
+    #[serde(default)]
+    pub complete: bool,
+    /// Tag slug → frontend problem IDs carrying it, sorted once complete.
+    #[serde(default)]
+    pub tags: BTreeMap<String, Vec<u32>>,
+}
+
+impl TagCache {
+    /// Load the tag cache from the current directory, or an empty one if
+    /// the file doesn't exist yet.
+    pub fn load() -> Result<Self> {
+        Self::load_from(Path::new(""))
+    }
+
+    /// Load the tag cache from the workspace rooted at `root`.
+    pub fn load_from(root: &Path) -> Result<Self> {
+        let path = Self::path(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the tag cache to the current directory.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(Path::new(""))
+    }
+
+    /// Save the tag cache to the workspace rooted at `root`.
+    pub fn save_to(&self, root: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(root), content)?;
+        Ok(())
+    }
+
+    fn path(root: &Path) -> PathBuf {
+        root.join(TAGS_FILE)
+    }
+
+    /// Ingest one page of `(frontend_id, tag slugs)` pairs, advancing the
+    /// resume offset.
+    pub fn ingest(&mut self, page: &[(u32, Vec<String>)]) {
+        for (id, slugs) in page {
+            for slug in slugs {
+                self.tags.entry(slug.clone()).or_default().push(*id);
+            }
+        }
+        self.next_skip += page.len() as u64;
+    }
+
+    /// Mark the fetch complete, sorting and deduplicating every tag's IDs
+    /// (resumed fetches can replay part of a page).
+    pub fn finish(&mut self) {
+        for ids in self.tags.values_mut() {
+            ids.sort_unstable();
+            ids.dedup();
+        }
+        self.complete = true;
+    }
+
+    /// The problem IDs carrying a tag, matched by slug or display name
+    /// ("Dynamic Programming" and "dynamic-programming" both work).
+    /// `None` if the cache is incomplete or the tag is unknown.
+    pub fn problems_with(&self, tag: &str) -> Option<&[u32]> {
+        if !self.complete {
+            return None;
+        }
+        let slug = normalize_tag(tag);
+        self.tags.get(&slug).map(Vec::as_slice)
+    }
+}
+
+/// A tag as typed by the user, normalized to its slug form.
+pub(crate) fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase().replace(' ', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn filled_cache() -> TagCache {
+        let mut cache = TagCache::default();
+        cache.ingest(&[
+            (1, vec!["array".to_string(), "hash-table".to_string()]),
+            (53, vec!["array".to_string(), "dynamic-programming".to_string()]),
+        ]);
+        cache.finish();
+        cache
+    }
+
+    #[test]
+    fn test_ingest_and_lookup() {
+        let cache = filled_cache();
+        assert_eq!(cache.next_skip, 2);
+        assert_eq!(cache.problems_with("array"), Some(&[1, 53][..]));
+        assert_eq!(cache.problems_with("Hash Table"), Some(&[1][..]));
+        assert_eq!(cache.problems_with("graph"), None);
+    }
+
+    #[test]
+    fn test_incomplete_cache_answers_nothing() {
+        let mut cache = TagCache::default();
+        cache.ingest(&[(1, vec!["array".to_string()])]);
+        assert_eq!(cache.problems_with("array"), None);
+    }
+
+    #[test]
+    fn test_finish_dedupes_replayed_page() {
+        let mut cache = TagCache::default();
+        let page = [(1, vec!["array".to_string()])];
+        cache.ingest(&page);
+        cache.ingest(&page);
+        cache.finish();
+        assert_eq!(cache.problems_with("array"), Some(&[1][..]));
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("Dynamic Programming"), "dynamic-programming");
+        assert_eq!(normalize_tag(" array "), "array");
+    }
+
+    #[test]
+    fn test_load_save_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut cache = TagCache::load_from(temp_dir.path()).unwrap();
+        assert!(cache.tags.is_empty());
+
+        cache.ingest(&[(1, vec!["array".to_string()])]);
+        cache.finish();
+        cache.save_to(temp_dir.path()).unwrap();
+
+        let reloaded = TagCache::load_from(temp_dir.path()).unwrap();
+        assert!(reloaded.complete);
+        assert_eq!(reloaded.problems_with("array"), Some(&[1][..]));
+    }
+}
@@ -0,0 +1,147 @@
+//! Opt-in command timing diagnostics
+//!
+//! With the global `--timings` flag, instrumented phases (problem-list
+//! fetch, GraphQL calls, cargo invocations, judge polling) record how long
+//! they took, and a summary is printed when the command finishes. Disabled,
+//! the instrumentation is a single atomic load per phase.
+
+use std::{
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use colored::Colorize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+fn records() -> &'static Mutex<Vec<(String, Duration)>> {
+    static RECORDS: OnceLock<Mutex<Vec<(String, Duration)>>> = OnceLock::new();
+    RECORDS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Turn timing collection on for the rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Start timing a phase; the elapsed time is recorded when the returned
+/// guard drops. A no-op unless [`enable`] was called.
+pub fn start(label: &str) -> Timer {
+    Timer {
+        label: label.to_string(),
+        started: Instant::now(),
+    }
+}
+
+/// A running phase timer; records its label and elapsed time on drop.
+pub struct Timer {
+    label: String,
+    started: Instant,
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+        if let Ok(mut records) = records().lock() {
+            records.push((self.label.clone(), self.started.elapsed()));
+        }
+    }
+}
+
+/// The recorded phases aggregated by label: `(label, count, total)`,
+/// slowest first.
+fn summary() -> Vec<(String, u32, Duration)> {
+    let records = match records().lock() {
+        Ok(records) => records.clone(),
+        Err(_) => return Vec::new(),
+    };
+    let mut by_label: std::collections::BTreeMap<String, (u32, Duration)> = Default::default();
+    for (label, elapsed) in records {
+        let entry = by_label.entry(label).or_default();
+        entry.0 += 1;
+        entry.1 += elapsed;
+    }
+    let mut rows: Vec<(String, u32, Duration)> = by_label
+        .into_iter()
+        .map(|(label, (count, total))| (label, count, total))
+        .collect();
+    rows.sort_by_key(|row| std::cmp::Reverse(row.2));
+    rows
+}
+
+/// Print the timing summary, if `--timings` was given and anything was
+/// recorded.
+pub fn report() {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let rows = summary();
+    if rows.is_empty() {
+        return;
+    }
+    println!();
+    println!("{}", "Timings:".cyan());
+    for (label, count, total) in rows {
+        let times = if count > 1 {
+            format!(" ×{count}")
+        } else {
+            String::new()
+        };
+        println!("  {label}{times}: {}", format_elapsed(total));
+    }
+}
+
+/// A duration as "318 ms" below a second, "2.4 s" above.
+fn format_elapsed(elapsed: Duration) -> String {
+    if elapsed < Duration::from_secs(1) {
+        format!("{} ms", elapsed.as_millis())
+    } else {
+        format!("{:.1} s", elapsed.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_millis(318)), "318 ms");
+        assert_eq!(format_elapsed(Duration::from_millis(2400)), "2.4 s");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_summary_aggregates_by_label() {
+        records().lock().unwrap().clear();
+        ENABLED.store(true, Ordering::Relaxed);
+
+        {
+            let _graphql = start("graphql");
+            let _also_graphql = start("graphql");
+            let _cargo = start("cargo test");
+        }
+        let rows = summary();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().any(|(label, count, _)| label == "graphql" && *count == 2));
+        assert!(rows.iter().any(|(label, count, _)| label == "cargo test" && *count == 1));
+
+        ENABLED.store(false, Ordering::Relaxed);
+        records().lock().unwrap().clear();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_disabled_records_nothing() {
+        records().lock().unwrap().clear();
+        ENABLED.store(false, Ordering::Relaxed);
+
+        drop(start("graphql"));
+        assert!(summary().is_empty());
+    }
+}
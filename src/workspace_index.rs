@@ -0,0 +1,230 @@
+//! Workspace index file
+//!
+//! Maintains a machine-readable `index.json` at the workspace root —
+//! problem identity, solution path, language, solve status, and
+//! timestamps — regenerated whenever download, submit, or archive change
+//! the workspace, so external dashboards, editors, and the TUI can
+//! enumerate it without re-scanning directories.
+
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    meta::ProblemMeta,
+    progress::{Progress, SolveStatus},
+};
+
+const INDEX_FILE: &str = "index.json";
+
+/// One workspace problem as recorded in `index.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Frontend problem ID
+    pub id: u32,
+    pub slug: String,
+    /// Workspace-relative path of the solution file
+    pub path: String,
+    pub language: String,
+    /// "solved", "attempting", or "downloaded"
+    pub status: String,
+    /// Unix timestamp of the download, 0 for pre-metadata solutions
+    pub downloaded_at: u64,
+    /// Unix timestamp of the first accepted submission, if recorded
+    #[serde(default)]
+    pub solved_at: Option<u64>,
+    /// Whether the solution lives under `archive/` rather than the
+    /// active workspace
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// The workspace index, as written to `index.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkspaceIndex {
+    /// Unix timestamp of when the index was generated
+    pub generated_at: u64,
+    /// Every workspace problem, active and archived, sorted by ID
+    pub problems: Vec<IndexEntry>,
+}
+
+impl WorkspaceIndex {
+    /// Build the index by scanning the workspace rooted at `root`.
+    pub fn build(root: &Path) -> Result<Self> {
+        let progress = Progress::load_from(root)?;
+        let mut problems = Vec::new();
+
+        for solution in crate::commands::list_local_solutions_in(root)? {
+            let path = solution.path.strip_prefix(root).unwrap_or(&solution.path);
+            problems.push(entry_for(
+                root,
+                &progress,
+                solution.id,
+                &solution.slug,
+                &path.to_string_lossy(),
+                false,
+            ));
+        }
+
+        if let Ok(entries) = std::fs::read_dir(root.join("archive")) {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let Some((id, slug)) = crate::commands::parse_solution_file_name(&name) else {
+                    continue;
+                };
+                problems.push(entry_for(
+                    root,
+                    &progress,
+                    id,
+                    &slug,
+                    &format!("archive/{name}"),
+                    true,
+                ));
+            }
+        }
+
+        problems.sort_by_key(|p| (p.id, p.archived));
+        Ok(Self {
+            generated_at: ProblemMeta::now(),
+            problems,
+        })
+    }
+
+    /// Load the index file, or `None` if it hasn't been generated yet.
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(INDEX_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Save the index to `index.json` under `root`.
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(root.join(INDEX_FILE), content)?;
+        Ok(())
+    }
+}
+
+/// Rebuild and write `index.json` in the current directory. Called after
+/// anything that changes which problems the workspace holds.
+pub fn refresh() -> Result<()> {
+    refresh_in(Path::new(""))
+}
+
+/// Rebuild and write `index.json` under the workspace rooted at `root`.
+pub fn refresh_in(root: &Path) -> Result<()> {
+    WorkspaceIndex::build(root)?.save(root)
+}
+
+/// One index entry, combining file identity with metadata and progress.
+fn entry_for(
+    root: &Path,
+    progress: &Progress,
+    id: u32,
+    slug: &str,
+    path: &str,
+    archived: bool,
+) -> IndexEntry {
+    let meta = ProblemMeta::load_from(root, id).ok().flatten();
+    let record = progress.problems.get(&id);
+    let status = match record.map(|r| r.status) {
+        Some(SolveStatus::Solved) => "solved",
+        Some(SolveStatus::Attempting) => "attempting",
+        None => "downloaded",
+    };
+    IndexEntry {
+        id,
+        slug: meta
+            .as_ref()
+            .map(|m| m.slug.clone())
+            .unwrap_or_else(|| slug.to_string()),
+        path: path.to_string(),
+        language: meta
+            .as_ref()
+            .map(|m| m.language.clone())
+            .unwrap_or_else(|| "rust".to_string()),
+        status: status.to_string(),
+        downloaded_at: meta.map(|m| m.downloaded_at).unwrap_or(0),
+        solved_at: record.and_then(|r| r.solved_at),
+        archived,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn make_workspace() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let solutions = dir.path().join("src/solutions");
+        std::fs::create_dir_all(&solutions).unwrap();
+        std::fs::write(solutions.join("p0001_two_sum.rs"), "pub struct Solution;").unwrap();
+        ProblemMeta {
+            id: 1,
+            frontend_id: 1,
+            slug: "two-sum".to_string(),
+            title: "Two Sum".to_string(),
+            difficulty: "Easy".to_string(),
+            tags: Vec::new(),
+            downloaded_at: 1_700_000_000,
+            language: "rust".to_string(),
+            module: None,
+            time_limit_ms: None,
+            memory_limit_mb: None,
+        }
+        .save_to(dir.path())
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_build_indexes_active_solutions() {
+        let dir = make_workspace();
+        let index = WorkspaceIndex::build(dir.path()).unwrap();
+
+        assert_eq!(index.problems.len(), 1);
+        let entry = &index.problems[0];
+        assert_eq!(entry.id, 1);
+        assert_eq!(entry.slug, "two-sum");
+        assert_eq!(entry.path, "src/solutions/p0001_two_sum.rs");
+        assert_eq!(entry.language, "rust");
+        assert_eq!(entry.status, "downloaded");
+        assert!(!entry.archived);
+    }
+
+    #[test]
+    fn test_build_includes_archived_and_status() {
+        let dir = make_workspace();
+        let archive = dir.path().join("archive");
+        std::fs::create_dir_all(&archive).unwrap();
+        std::fs::write(archive.join("p0002_add_two_numbers.rs"), "pub struct Solution;")
+            .unwrap();
+
+        let mut progress = Progress::load_from(dir.path()).unwrap();
+        progress.record(1, "two-sum", SolveStatus::Solved, "submit");
+        progress.save_to(dir.path()).unwrap();
+
+        let index = WorkspaceIndex::build(dir.path()).unwrap();
+        assert_eq!(index.problems.len(), 2);
+        assert_eq!(index.problems[0].status, "solved");
+        assert!(index.problems[1].archived);
+        assert_eq!(index.problems[1].path, "archive/p0002_add_two_numbers.rs");
+        assert_eq!(index.problems[1].slug, "add-two-numbers");
+    }
+
+    #[test]
+    fn test_refresh_roundtrips_through_file() {
+        let dir = make_workspace();
+        refresh_in(dir.path()).unwrap();
+
+        let index = WorkspaceIndex::load(dir.path()).unwrap().unwrap();
+        assert_eq!(index.problems.len(), 1);
+        assert!(index.generated_at > 0);
+    }
+}